@@ -6,28 +6,34 @@
 use crate::diagram::FloralDiagram;
 use crate::petal::PetalParams;
 use crate::pistil::PistilParams;
-use crate::receptacle::ReceptacleParams;
+use crate::receptacle::{hypanthium_control_points, ReceptacleParams};
 use crate::stamen::StamenParams;
 use crate::{Mat3, Mat4, Mesh, Quat, Vec2, Vec3};
 use floraison_core::math::bezier::{cubic_bezier_2d, cubic_bezier_derivative_2d};
+use floraison_core::math::vector::rotation_between;
+use std::ops::Range;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Type of floral component
+///
+/// Discriminants are stable, so `component as u8` can label vertices
+/// (e.g. for picking in a viewer).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(u8)]
 pub enum ComponentType {
     /// Receptacle (flower base)
-    Receptacle,
+    Receptacle = 0,
     /// Pistil (female reproductive structure)
-    Pistil,
+    Pistil = 1,
     /// Stamen (male reproductive structure)
-    Stamen,
+    Stamen = 2,
     /// Petal
-    Petal,
+    Petal = 3,
     /// Sepal (outer protective leaves)
-    Sepal,
+    Sepal = 4,
 }
 
 /// Placement of a component in 2D floral diagram space
@@ -102,6 +108,26 @@ impl Transform3D {
         }
     }
 
+    /// Create a transform that orients `up_axis` of a mesh along `up`
+    ///
+    /// Handles antiparallel and degenerate directions without producing NaNs
+    /// (see [`rotation_between`]). Useful for placing flowers on arbitrary
+    /// surfaces, where `up` is the surface normal and `up_axis` is the mesh's
+    /// local up (usually `Vec3::Y`).
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_components::assembly::Transform3D;
+    /// use floraison_components::Vec3;
+    ///
+    /// // Hang a flower upside down
+    /// let t = Transform3D::facing(Vec3::new(0.0, 5.0, 0.0), -Vec3::Y, Vec3::Y);
+    /// assert!((t.rotation * Vec3::Y + Vec3::Y).length() < 1e-5);
+    /// ```
+    pub fn facing(position: Vec3, up: Vec3, up_axis: Vec3) -> Self {
+        Self::with_rotation(position, rotation_between(up_axis, up))
+    }
+
     /// Convert to a 4x4 transformation matrix
     ///
     /// Combines translation, rotation, and scale into a single matrix
@@ -115,7 +141,8 @@ impl Transform3D {
 ///
 /// The mapper uses the receptacle's Bézier profile curve to compute
 /// the radius at any given height, and calculates proper surface normals
-/// for orienting components.
+/// for orienting components. When the receptacle has a hypanthium, the
+/// profile continues up the cup, so the top of the mapped range is its rim.
 pub struct ReceptacleMapper {
     /// Bézier control points defining the receptacle profile
     /// (radius, height) pairs from bottom to top
@@ -124,7 +151,13 @@ pub struct ReceptacleMapper {
     p2: Vec2,
     p3: Vec2,
 
-    /// Total height of receptacle
+    /// Bézier control points of the hypanthium cup above `p3`, if any
+    hypanthium: Option<[Vec2; 4]>,
+
+    /// Height of the receptacle body (where the hypanthium starts)
+    body_height: f32,
+
+    /// Total height of receptacle, including the hypanthium
     height: f32,
 }
 
@@ -145,12 +178,31 @@ impl ReceptacleMapper {
 
         let p3 = Vec2::new(params.top_radius, params.height);
 
+        let hypanthium = hypanthium_control_points(params);
+        let height = params.height + hypanthium.map_or(0.0, |_| params.hypanthium_height);
+
         Self {
             p0,
             p1,
             p2,
             p3,
-            height: params.height,
+            hypanthium,
+            body_height: params.height,
+            height,
+        }
+    }
+
+    /// Profile segment and Bézier parameter covering a given height
+    fn profile_segment(&self, height: f32) -> ([Vec2; 4], f32) {
+        match self.hypanthium {
+            Some(cup) if height > self.body_height => {
+                let t = (height - self.body_height) / (self.height - self.body_height);
+                (cup, t.clamp(0.0, 1.0))
+            }
+            _ => {
+                let t = (height / self.body_height).clamp(0.0, 1.0);
+                ([self.p0, self.p1, self.p2, self.p3], t)
+            }
         }
     }
 
@@ -162,11 +214,11 @@ impl ReceptacleMapper {
     /// # Returns
     /// Radius at that height
     pub fn radius_at_height(&self, height: f32) -> f32 {
-        // Normalize height to [0, 1] for Bézier parameter
-        let t = (height / self.height).clamp(0.0, 1.0);
+        // Pick the body or hypanthium curve, with height normalized to [0, 1]
+        let ([p0, p1, p2, p3], t) = self.profile_segment(height);
 
         // Evaluate Bézier curve at t
-        let point = cubic_bezier_2d(self.p0, self.p1, self.p2, self.p3, t);
+        let point = cubic_bezier_2d(p0, p1, p2, p3, t);
 
         // Return x coordinate (radius)
         point.x
@@ -182,11 +234,11 @@ impl ReceptacleMapper {
     /// # Returns
     /// Tangent vector in 3D (not normalized)
     pub fn tangent_at_height(&self, height: f32) -> Vec3 {
-        // Normalize height to [0, 1]
-        let t = (height / self.height).clamp(0.0, 1.0);
+        // Pick the body or hypanthium curve, with height normalized to [0, 1]
+        let ([p0, p1, p2, p3], t) = self.profile_segment(height);
 
         // Get 2D derivative (dx/dt, dy/dt)
-        let derivative = cubic_bezier_derivative_2d(self.p0, self.p1, self.p2, self.p3, t);
+        let derivative = cubic_bezier_derivative_2d(p0, p1, p2, p3, t);
 
         // Convert to 3D tangent
         // In cylindrical coordinates, the tangent in the (r, y) plane
@@ -209,9 +261,10 @@ impl ReceptacleMapper {
         if placement.component_type == ComponentType::Pistil && placement.radius < 0.001 {
             let position = Vec3::new(0.0, height, 0.0);
 
-            // Pistil points straight up (identity rotation)
-            // Local Y-axis aligned with global Y-axis
-            let rotation = Quat::IDENTITY;
+            // Pistil points straight up: local Y-axis aligned with global Y-axis.
+            // Spin it about Y so a curved style bends toward the placement angle
+            // (local +X maps to (cos θ, 0, sin θ), as for off-center components)
+            let rotation = Quat::from_rotation_y(-placement.angle);
 
             return Transform3D::with_scale(position, rotation, placement.scale);
         }
@@ -310,7 +363,9 @@ impl FloralDiagram {
     /// Generate all component placements from this diagram
     ///
     /// Converts the abstract whorl specifications into concrete placements
-    /// that can be mapped to 3D positions.
+    /// that can be mapped to 3D positions. Per-whorl angle and scale
+    /// overrides are honored, with scale overrides multiplying any size jitter.
+    /// Vogel disc whorls give each component its own radius and height.
     ///
     /// # Returns
     /// Vector of placements for all components in the flower
@@ -319,94 +374,37 @@ impl FloralDiagram {
         let mut component_index: u64 = 0;
 
         // Check if jitter is enabled (any param > 0)
-        let jitter_enabled =
-            self.position_jitter > 0.0 || self.angle_jitter > 0.0 || self.size_jitter > 0.0;
-
-        // Add pistils
-        for whorl in &self.pistil_whorls {
-            let angles = whorl.calculate_angles();
-            for angle in angles {
-                let (radius, jitter_angle, scale) = if jitter_enabled {
-                    self.apply_jitter(whorl.radius, angle, component_index)
-                } else {
-                    (whorl.radius, angle, 1.0)
-                };
-
-                placements.push(ComponentPlacement {
-                    component_type: ComponentType::Pistil,
-                    radius,
-                    angle: jitter_angle,
-                    height: whorl.height,
-                    scale,
-                    tilt_angle: whorl.tilt_angle,
-                });
-                component_index += 1;
-            }
-        }
-
-        // Add stamens
-        for whorl in &self.stamen_whorls {
-            let angles = whorl.calculate_angles();
-            for angle in angles {
-                let (radius, jitter_angle, scale) = if jitter_enabled {
-                    self.apply_jitter(whorl.radius, angle, component_index)
-                } else {
-                    (whorl.radius, angle, 1.0)
-                };
-
-                placements.push(ComponentPlacement {
-                    component_type: ComponentType::Stamen,
-                    radius,
-                    angle: jitter_angle,
-                    height: whorl.height,
-                    scale,
-                    tilt_angle: whorl.tilt_angle,
-                });
-                component_index += 1;
-            }
-        }
-
-        // Add petals
-        for whorl in &self.petal_whorls {
-            let angles = whorl.calculate_angles();
-            for angle in angles {
-                let (radius, jitter_angle, scale) = if jitter_enabled {
-                    self.apply_jitter(whorl.radius, angle, component_index)
-                } else {
-                    (whorl.radius, angle, 1.0)
-                };
-
-                placements.push(ComponentPlacement {
-                    component_type: ComponentType::Petal,
-                    radius,
-                    angle: jitter_angle,
-                    height: whorl.height,
-                    scale,
-                    tilt_angle: whorl.tilt_angle,
-                });
-                component_index += 1;
-            }
-        }
-
-        // Add sepals
-        for whorl in &self.sepal_whorls {
-            let angles = whorl.calculate_angles();
-            for angle in angles {
-                let (radius, jitter_angle, scale) = if jitter_enabled {
-                    self.apply_jitter(whorl.radius, angle, component_index)
-                } else {
-                    (whorl.radius, angle, 1.0)
-                };
-
-                placements.push(ComponentPlacement {
-                    component_type: ComponentType::Sepal,
-                    radius,
-                    angle: jitter_angle,
-                    height: whorl.height,
-                    scale,
-                    tilt_angle: whorl.tilt_angle,
-                });
-                component_index += 1;
+        let jitter_enabled = self.has_jitter();
+
+        // Add pistils, stamens, petals, then sepals
+        for (whorls, component_type) in [
+            (&self.pistil_whorls, ComponentType::Pistil),
+            (&self.stamen_whorls, ComponentType::Stamen),
+            (&self.petal_whorls, ComponentType::Petal),
+            (&self.sepal_whorls, ComponentType::Sepal),
+        ] {
+            for whorl in whorls {
+                let angles = whorl.calculate_angles();
+                let positions = whorl.calculate_positions();
+                for (i, (angle, (base_radius, height))) in
+                    angles.into_iter().zip(positions).enumerate()
+                {
+                    let (radius, jitter_angle, scale) = if jitter_enabled {
+                        self.apply_jitter(base_radius, angle, component_index)
+                    } else {
+                        (base_radius, angle, 1.0)
+                    };
+
+                    placements.push(ComponentPlacement {
+                        component_type,
+                        radius,
+                        angle: jitter_angle,
+                        height,
+                        scale: scale * whorl.component_scale(i),
+                        tilt_angle: whorl.tilt_angle,
+                    });
+                    component_index += 1;
+                }
             }
         }
 
@@ -491,6 +489,20 @@ impl FlowerParams {
                 ruffle_freq: 0.0,
                 ruffle_amp: 0.0,
                 lateral_curve: 0.0,
+                droop: 0.0,
+                adaptive_error: None,
+                midrib_depth: 0.0,
+                serration_freq: 0.0,
+                serration_depth: 0.0,
+                asymmetry: 0.0,
+                tip_notch_depth: 0.0,
+                tip_lobes: 1,
+                tip_lobe_depth: 0.5,
+                color_tip: None,
+                cup: 0.0,
+                reflex: 0.0,
+                double_sided: true,
+                thickness: 0.0,
                 resolution: 20,   // Higher resolution for smooth curves
                 color: Vec3::ONE, // White petals
             },
@@ -514,6 +526,20 @@ impl FlowerParams {
                 ruffle_freq: 3.0, // 3 waves along edges
                 ruffle_amp: 0.15, // Visible ruffle
                 lateral_curve: 0.0,
+                droop: 0.0,
+                adaptive_error: None,
+                midrib_depth: 0.0,
+                serration_freq: 0.0,
+                serration_depth: 0.0,
+                asymmetry: 0.0,
+                tip_notch_depth: 0.0,
+                tip_lobes: 1,
+                tip_lobe_depth: 0.5,
+                color_tip: None,
+                cup: 0.0,
+                reflex: 0.0,
+                double_sided: true,
+                thickness: 0.0,
                 resolution: 24,   // High resolution for ruffle detail
                 color: Vec3::ONE, // White petals
             },
@@ -530,6 +556,76 @@ impl FlowerParams {
             petal: PetalParams::narrow(),
         }
     }
+
+    /// Create parameters for a tulip with cupped tepals and a 3-lobed stigma
+    pub fn tulip() -> Self {
+        Self {
+            diagram: FloralDiagram::tulip(),
+            receptacle: ReceptacleParams::default(),
+            pistil: PistilParams {
+                stigma_lobes: 3,
+                ..PistilParams::short()
+            },
+            stamen: StamenParams::short(),
+            petal: PetalParams {
+                length: 3.0,
+                width: 1.8,
+                tip_sharpness: 0.3,
+                base_width: 0.6,
+                curl: 0.6, // Tepals rise to form the cup
+                cup: 0.4,  // Bowl-shaped cross-section
+                resolution: 20,
+                color: Vec3::new(0.85, 0.15, 0.2), // Red
+                ..PetalParams::default()
+            },
+        }
+    }
+
+    /// Create parameters for an orchid with an enlarged labellum
+    pub fn orchid() -> Self {
+        Self {
+            diagram: FloralDiagram::orchid(),
+            receptacle: ReceptacleParams::default(),
+            pistil: PistilParams::short(),
+            stamen: StamenParams::short(),
+            petal: PetalParams {
+                length: 2.5,
+                width: 1.4,
+                tip_sharpness: 0.5,
+                base_width: 0.4,
+                curl: -0.2,
+                twist: 10.0,
+                ruffle_freq: 2.0,
+                ruffle_amp: 0.08,
+                resolution: 20,
+                color: Vec3::new(0.8, 0.5, 0.85), // Orchid purple
+                color_tip: Some(Vec3::new(0.95, 0.85, 0.95)),
+                ..PetalParams::default()
+            },
+        }
+    }
+
+    /// Create parameters for a poppy with broad, crumpled petals
+    pub fn poppy() -> Self {
+        Self {
+            diagram: FloralDiagram::poppy(),
+            receptacle: ReceptacleParams::default(),
+            pistil: PistilParams {
+                stigma_lobes: 8, // Radiating stigma disc
+                ..PistilParams::short()
+            },
+            stamen: StamenParams::slender(),
+            petal: PetalParams {
+                cup: 0.3,
+                curl: 0.3,
+                ruffle_freq: 4.0,
+                ruffle_amp: 0.1,
+                resolution: 24,
+                color: Vec3::new(0.9, 0.3, 0.1), // Poppy orange-red
+                ..PetalParams::wide()
+            },
+        }
+    }
 }
 
 /// Generate a complete flower mesh from parameters
@@ -556,11 +652,103 @@ impl FlowerParams {
 /// assert!(flower.vertex_count() > 0);
 /// ```
 pub fn generate_flower(params: &FlowerParams) -> Mesh {
+    generate_flower_with_ranges(params).0
+}
+
+/// Generate a complete flower mesh with a shifted jitter seed
+///
+/// Adds `seed_offset` into the diagram's `jitter_seed` before generating
+/// placements, so that e.g. every flower of an inflorescence can jitter
+/// differently while staying reproducible. The offset is spread by a large
+/// odd multiplier first: the jitter RNG is seeded per component as
+/// `seed + index`, and plain consecutive offsets would only shift the same
+/// sequence by one component.
+///
+/// # Arguments
+/// * `params` - Complete flower parameters
+/// * `seed_offset` - Offset added to `params.diagram.jitter_seed` (0 = unchanged)
+///
+/// # Returns
+/// A single mesh containing all components
+///
+/// # Example
+/// ```
+/// use floraison_components::assembly::{generate_flower, generate_flower_seeded, FlowerParams};
+///
+/// let mut params = FlowerParams::lily();
+/// params.diagram.angle_jitter = 10.0;
+///
+/// let first = generate_flower_seeded(&params, 1);
+/// let second = generate_flower_seeded(&params, 2);
+/// assert_ne!(first.positions, second.positions);
+/// assert_eq!(generate_flower_seeded(&params, 0).positions, generate_flower(&params).positions);
+/// ```
+pub fn generate_flower_seeded(params: &FlowerParams, seed_offset: u64) -> Mesh {
+    // Fractional part of the golden ratio in 64-bit fixed point
+    const SEED_SPREAD: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    let mut seeded = params.clone();
+    seeded.diagram.jitter_seed = seeded
+        .diagram
+        .jitter_seed
+        .wrapping_add(seed_offset.wrapping_mul(SEED_SPREAD));
+    generate_flower(&seeded)
+}
+
+/// Generate a complete flower mesh along with per-component index ranges
+///
+/// Identical to [`generate_flower`], but additionally reports which slice of the
+/// merged index buffer belongs to each component instance. Ranges are in index
+/// buffer units (three per triangle) and are listed in merge order, so a renderer
+/// can recolor or outline e.g. all stamens without splitting the mesh into
+/// separate draw calls. Ranges stay valid for a given set of parameters because
+/// components are always merged in the same order; pass one through
+/// [`Mesh::triangle_span`] to copy the component out with
+/// [`Mesh::extract_triangles`].
+///
+/// # Arguments
+/// * `params` - Complete flower parameters
+///
+/// # Returns
+/// Tuple of (merged mesh, list of (component type, index range))
+///
+/// # Example
+/// ```
+/// use floraison_components::assembly::{
+///     generate_flower_with_ranges, ComponentType, FlowerParams,
+/// };
+/// use floraison_components::Mesh;
+///
+/// let (flower, ranges) = generate_flower_with_ranges(&FlowerParams::lily());
+///
+/// let stamen_count = ranges
+///     .iter()
+///     .filter(|(ty, _)| *ty == ComponentType::Stamen)
+///     .count();
+/// assert_eq!(stamen_count, 6);
+/// assert_eq!(ranges.last().unwrap().1.end as usize, flower.indices.len());
+///
+/// // Copy out just the pistil
+/// let (_, pistil_range) = ranges
+///     .iter()
+///     .find(|(ty, _)| *ty == ComponentType::Pistil)
+///     .unwrap();
+/// let pistil = flower.extract_triangles(Mesh::triangle_span(pistil_range.clone()));
+/// assert_eq!(pistil.triangle_count() * 3, pistil_range.len());
+/// ```
+pub fn generate_flower_with_ranges(
+    params: &FlowerParams,
+) -> (Mesh, Vec<(ComponentType, Range<u32>)>) {
     let mut final_mesh = Mesh::default();
+    let mut ranges = Vec::new();
 
     // Generate receptacle
     let receptacle = crate::receptacle::generate(&params.receptacle);
     final_mesh.merge(&receptacle);
+    ranges.push((
+        ComponentType::Receptacle,
+        0..final_mesh.indices.len() as u32,
+    ));
 
     // Create mapper for positioning components on receptacle surface
     let mapper = ReceptacleMapper::from_params(&params.receptacle);
@@ -583,16 +771,15 @@ pub fn generate_flower(params: &FlowerParams) -> Mesh {
             ComponentType::Petal | ComponentType::Sepal => &petal_template,
         };
 
-        // Clone template and transform to position
-        let mut instance = template.clone();
+        // Merge the template, transformed into position, recording the index range it occupies
         let transform = mapper.map_to_3d(&placement);
-        instance.transform(&transform.to_matrix());
-
-        // Merge into final mesh
-        final_mesh.merge(&instance);
+        let start = final_mesh.indices.len() as u32;
+        final_mesh.merge_transformed(template, &transform.to_matrix());
+        let end = final_mesh.indices.len() as u32;
+        ranges.push((placement.component_type, start..end));
     }
 
-    final_mesh
+    (final_mesh, ranges)
 }
 
 #[cfg(test)]
@@ -608,6 +795,22 @@ mod tests {
         assert_eq!(transform.scale, Vec3::ONE);
     }
 
+    #[test]
+    fn test_transform3d_facing() {
+        let position = Vec3::new(1.0, 2.0, 3.0);
+        let normal = Vec3::new(0.0, 0.0, 2.0);
+        let t = Transform3D::facing(position, normal, Vec3::Y);
+
+        assert_eq!(t.position, position);
+        assert_eq!(t.scale, Vec3::ONE);
+        assert!((t.rotation * Vec3::Y - Vec3::Z).length() < 1e-5);
+
+        // Antiparallel case stays finite
+        let flipped = Transform3D::facing(position, -Vec3::Y, Vec3::Y);
+        assert!(flipped.to_matrix().is_finite());
+        assert!((flipped.rotation * Vec3::Y + Vec3::Y).length() < 1e-5);
+    }
+
     #[test]
     fn test_transform3d_to_matrix() {
         let transform = Transform3D::new(Vec3::new(1.0, 2.0, 3.0));
@@ -629,6 +832,8 @@ mod tests {
             bulge_position: 0.5,
             segments: 16,
             profile_samples: 8,
+            hypanthium_height: 0.0,
+            hypanthium_radius: 0.0,
             color: Vec3::ONE,
         };
 
@@ -650,6 +855,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_receptacle_mapper_hypanthium_rim() {
+        let params = ReceptacleParams::hypanthium();
+        let mapper = ReceptacleMapper::from_params(&params);
+
+        let placement = ComponentPlacement {
+            component_type: ComponentType::Petal,
+            radius: 1.0,
+            angle: 0.0,
+            height: 1.0,
+            scale: 1.0,
+            tilt_angle: 0.0,
+        };
+        let transform = mapper.map_to_3d(&placement);
+
+        // Petals at the top seat on the hypanthium rim, not the old top
+        let rim_height = params.height + params.hypanthium_height;
+        assert!((transform.position.y - rim_height).abs() < 1e-5);
+        assert!((transform.position.x - params.hypanthium_radius).abs() < 1e-5);
+        assert!((transform.position.x - params.top_radius).abs() > 0.1);
+
+        // The body below the cup maps exactly as without a hypanthium
+        let body = ReceptacleMapper::from_params(&ReceptacleParams {
+            hypanthium_height: 0.0,
+            ..params.clone()
+        });
+        assert_eq!(mapper.radius_at_height(0.3), body.radius_at_height(0.3));
+        assert_eq!(mapper.radius_at_height(params.height), params.top_radius);
+    }
+
     #[test]
     fn test_receptacle_mapper_3d_position() {
         let params = ReceptacleParams {
@@ -660,6 +895,8 @@ mod tests {
             bulge_position: 0.5,
             segments: 16,
             profile_samples: 8,
+            hypanthium_height: 0.0,
+            hypanthium_radius: 0.0,
             color: Vec3::ONE,
         };
 
@@ -683,6 +920,29 @@ mod tests {
         assert!((transform.position.z).abs() < 0.1);
     }
 
+    #[test]
+    fn test_center_pistil_follows_placement_angle() {
+        let mapper = ReceptacleMapper::from_params(&ReceptacleParams::default());
+        let placement = |angle: f32| ComponentPlacement {
+            component_type: ComponentType::Pistil,
+            radius: 0.0,
+            angle,
+            height: 0.5,
+            scale: 1.0,
+            tilt_angle: 0.0,
+        };
+
+        // Unrotated placement keeps the pistil upright and unspun
+        let upright = mapper.map_to_3d(&placement(0.0));
+        assert_eq!(upright.rotation, Quat::IDENTITY);
+
+        // Curved styles bend along local +X, which follows the placement angle
+        let turned = mapper.map_to_3d(&placement(PI / 2.0));
+        assert!((turned.position.x).abs() < 1e-6 && turned.position.z.abs() < 1e-6);
+        assert!((turned.rotation * Vec3::Y - Vec3::Y).length() < 1e-5);
+        assert!((turned.rotation * Vec3::X - Vec3::Z).length() < 1e-5);
+    }
+
     #[test]
     fn test_floral_diagram_generate_placements() {
         let diagram = FloralDiagram::lily();
@@ -710,6 +970,100 @@ mod tests {
         assert_eq!(petals, 6);
     }
 
+    #[test]
+    fn test_vogel_disc_placements() {
+        use crate::diagram::{ArrangementPattern, ComponentWhorl};
+
+        let mut diagram = FloralDiagram::five_petal();
+        diagram.stamen_whorls = vec![ComponentWhorl {
+            count: 89,
+            radius: 0.9,
+            height: 0.5,
+            pattern: ArrangementPattern::VogelDisc {
+                inner_radius: 0.0,
+                inner_height: 0.8,
+            },
+            rotation_offset: 0.0,
+            tilt_angle: 0.0,
+            angle_overrides: None,
+            scale_overrides: None,
+        }];
+
+        let stamens: Vec<_> = diagram
+            .generate_placements()
+            .into_iter()
+            .filter(|p| p.component_type == ComponentType::Stamen)
+            .collect();
+        assert_eq!(stamens.len(), 89);
+
+        // Radii follow r = R·√(i / (n - 1)), heights fall toward the rim
+        for (i, stamen) in stamens.iter().enumerate() {
+            let expected = 0.9 * (i as f32 / 88.0).sqrt();
+            assert!((stamen.radius - expected).abs() < 1e-5);
+        }
+        assert!(stamens[0].height > stamens[88].height);
+
+        // Distinct heights land at distinct points on the receptacle
+        let mapper = ReceptacleMapper::from_params(&ReceptacleParams::default());
+        let inner = mapper.map_to_3d(&stamens[1]).position;
+        let outer = mapper.map_to_3d(&stamens[88]).position;
+        assert!(inner.y > outer.y);
+    }
+
+    #[test]
+    fn test_zygomorphic_placements() {
+        use crate::diagram::{ArrangementPattern, ComponentWhorl};
+
+        // Pea-like corolla: banner on top, two wings, two keel petals below
+        let angles = vec![
+            PI / 2.0,
+            PI / 6.0,
+            5.0 * PI / 6.0,
+            -PI / 3.0,
+            -2.0 * PI / 3.0,
+        ];
+        let scales = vec![1.6, 1.0, 1.0, 0.7, 0.7];
+        let mut diagram = FloralDiagram::five_petal();
+        diagram.petal_whorls = vec![ComponentWhorl {
+            count: 5,
+            radius: 1.2,
+            height: 0.6,
+            pattern: ArrangementPattern::EvenlySpaced,
+            rotation_offset: 0.0,
+            tilt_angle: 0.0,
+            angle_overrides: Some(angles.clone()),
+            scale_overrides: Some(scales.clone()),
+        }];
+
+        let petals: Vec<_> = diagram
+            .generate_placements()
+            .into_iter()
+            .filter(|p| p.component_type == ComponentType::Petal)
+            .collect();
+
+        assert_eq!(petals.len(), 5);
+        for ((petal, angle), scale) in petals.iter().zip(&angles).zip(&scales) {
+            assert_eq!(petal.angle, *angle);
+            assert_eq!(petal.scale, *scale);
+        }
+
+        // The banner is the largest petal
+        let banner = petals
+            .iter()
+            .max_by(|a, b| a.scale.total_cmp(&b.scale))
+            .unwrap();
+        assert_eq!(banner.angle, PI / 2.0);
+
+        // Size jitter still applies on top of the overrides
+        diagram.size_jitter = 0.2;
+        let jittered: Vec<_> = diagram
+            .generate_placements()
+            .into_iter()
+            .filter(|p| p.component_type == ComponentType::Petal)
+            .collect();
+        assert!(jittered[0].scale > 1.6 * 0.8 && jittered[0].scale < 1.6 * 1.2);
+    }
+
     #[test]
     fn test_placement_angles_evenly_spaced() {
         let diagram = FloralDiagram::lily();
@@ -741,6 +1095,8 @@ mod tests {
             bulge_position: 0.5,
             segments: 16,
             profile_samples: 8,
+            hypanthium_height: 0.0,
+            hypanthium_radius: 0.0,
             color: Vec3::ONE,
         };
 
@@ -787,6 +1143,8 @@ mod tests {
             bulge_position: 0.5,
             segments: 16,
             profile_samples: 8,
+            hypanthium_height: 0.0,
+            hypanthium_radius: 0.0,
             color: Vec3::ONE,
         };
 
@@ -888,11 +1246,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_generate_flower_family_presets() {
+        // (preset, sepals + petals, stamens, pistils)
+        let presets = [
+            (FlowerParams::tulip(), 6, 6, 1),
+            (FlowerParams::orchid(), 6, 1, 1),
+            (FlowerParams::poppy(), 4, 60, 1),
+        ];
+
+        for (params, perianth, stamens, pistils) in presets {
+            let (flower, ranges) = generate_flower_with_ranges(&params);
+            assert!(flower.validate().is_ok());
+
+            let count = |types: &[ComponentType]| {
+                ranges.iter().filter(|(ty, _)| types.contains(ty)).count()
+            };
+            assert_eq!(
+                count(&[ComponentType::Petal, ComponentType::Sepal]),
+                perianth
+            );
+            assert_eq!(count(&[ComponentType::Stamen]), stamens);
+            assert_eq!(count(&[ComponentType::Pistil]), pistils);
+        }
+    }
+
+    #[test]
+    fn test_generate_flower_seeded() {
+        let mut params = FlowerParams::five_petal();
+        params.diagram.position_jitter = 0.1;
+        params.diagram.angle_jitter = 8.0;
+        params.diagram.size_jitter = 0.1;
+
+        let a = generate_flower_seeded(&params, 3);
+        let b = generate_flower_seeded(&params, 4);
+        let again = generate_flower_seeded(&params, 3);
+
+        assert_eq!(a.vertex_count(), b.vertex_count());
+        assert_ne!(a.positions, b.positions);
+        assert_eq!(a.positions, again.positions);
+
+        // Without jitter the offset has no effect
+        let plain = FlowerParams::five_petal();
+        assert_eq!(
+            generate_flower_seeded(&plain, 3).positions,
+            generate_flower_seeded(&plain, 4).positions
+        );
+    }
+
     #[test]
     fn test_flower_params_presets() {
         // Test that all presets can be created
         let _lily = FlowerParams::lily();
         let _five = FlowerParams::five_petal();
+        let _tulip = FlowerParams::tulip();
+        let _orchid = FlowerParams::orchid();
+        let _poppy = FlowerParams::poppy();
         let _daisy = FlowerParams::daisy();
 
         // Each should have appropriate component counts
@@ -902,6 +1311,35 @@ mod tests {
         assert_eq!(lily_diagram.total_pistil_count(), 1);
     }
 
+    #[test]
+    fn test_range_receptacle_extraction() {
+        let params = FlowerParams::lily();
+        let (flower, ranges) = generate_flower_with_ranges(&params);
+
+        let (ty, range) = &ranges[0];
+        assert_eq!(*ty, ComponentType::Receptacle);
+
+        let extracted = flower.extract_triangles(Mesh::triangle_span(range.clone()));
+        let receptacle = crate::receptacle::generate(&params.receptacle);
+        assert_eq!(extracted.vertex_count(), receptacle.vertex_count());
+        assert_eq!(extracted.triangle_count(), receptacle.triangle_count());
+    }
+
+    #[test]
+    fn test_triangle_spans_cover_mesh() {
+        let (flower, ranges) = generate_flower_with_ranges(&FlowerParams::daisy());
+        let spans: Vec<_> = ranges
+            .into_iter()
+            .map(|(ty, range)| (ty, Mesh::triangle_span(range)))
+            .collect();
+
+        assert_eq!(spans[0].1.start, 0);
+        for pair in spans.windows(2) {
+            assert_eq!(pair[0].1.end, pair[1].1.start);
+        }
+        assert_eq!(spans.last().unwrap().1.end, flower.triangle_count());
+    }
+
     #[test]
     fn test_flower_components_positioned() {
         let params = FlowerParams::lily();
@@ -929,4 +1367,36 @@ mod tests {
         assert!(max_y > 1.0, "Flower should have height");
         assert!(max_radius > 1.0, "Flower should have width");
     }
+
+    #[test]
+    fn test_generate_flower_with_ranges() {
+        let params = FlowerParams::lily();
+        let (flower, ranges) = generate_flower_with_ranges(&params);
+
+        // Receptacle first, exactly as generated alone, then one range per placement
+        assert_eq!(ranges[0].0, ComponentType::Receptacle);
+        let receptacle = crate::receptacle::generate(&params.receptacle);
+        let first = ranges[0].1.start as usize..ranges[0].1.end as usize;
+        assert_eq!(flower.indices[first], receptacle.indices);
+        assert_eq!(ranges.len(), 1 + params.diagram.generate_placements().len());
+
+        // Ranges are contiguous, non-empty, triangle-aligned and cover the whole buffer
+        let mut expected_start = 0;
+        for (ty, range) in &ranges {
+            assert_eq!(range.start, expected_start, "{:?} range not contiguous", ty);
+            assert!(
+                range.end > range.start,
+                "{:?} range should not be empty",
+                ty
+            );
+            assert_eq!((range.end - range.start) % 3, 0);
+            expected_start = range.end;
+        }
+        assert_eq!(expected_start as usize, flower.indices.len());
+
+        let count = |t: ComponentType| ranges.iter().filter(|(ty, _)| *ty == t).count();
+        assert_eq!(count(ComponentType::Pistil), 1);
+        assert_eq!(count(ComponentType::Stamen), 6);
+        assert_eq!(count(ComponentType::Petal), 6);
+    }
 }
@@ -6,6 +6,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use floraison_core::math::phyllotaxis::vogel_spiral;
+
 /// Arrangement pattern for components in a whorl (concentric ring)
 ///
 /// Defines how components are distributed around a circle.
@@ -23,6 +25,19 @@ pub enum ArrangementPattern {
     /// Components arranged with specified angular offset between each
     /// Useful for creating alternating patterns
     CustomOffset(f32),
+
+    /// Components packed over an annulus with a Vogel (sunflower) spiral
+    ///
+    /// Spreads the whole whorl from (`inner_radius`, `inner_height`) out to
+    /// the whorl's own `radius` and `height`, following the √ packing law so
+    /// every component covers roughly the same area. Useful for dense
+    /// androecia (buttercup, poppy) in a single whorl spec.
+    VogelDisc {
+        /// Radius of the first (innermost) component
+        inner_radius: f32,
+        /// Height of the first (innermost) component
+        inner_height: f32,
+    },
 }
 
 /// Definition of a component whorl (concentric ring of components)
@@ -42,6 +57,8 @@ pub enum ArrangementPattern {
 ///     pattern: ArrangementPattern::EvenlySpaced,
 ///     rotation_offset: 0.0,
 ///     tilt_angle: 0.0,
+///     angle_overrides: None,
+///     scale_overrides: None,
 /// };
 /// ```
 #[derive(Debug, Clone)]
@@ -74,13 +91,42 @@ pub struct ComponentWhorl {
     /// Petals and sepals typically use 0.0 (perpendicular to surface).
     #[cfg_attr(feature = "serde", serde(default))]
     pub tilt_angle: f32,
+
+    /// Explicit angles in radians, one per component (None = use `pattern`)
+    ///
+    /// Replaces the pattern for irregular (zygomorphic) flowers such as
+    /// snapdragons or peas. `rotation_offset` is still added to each angle.
+    /// Ignored unless it has exactly `count` entries.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub angle_overrides: Option<Vec<f32>>,
+
+    /// Per-component scale multipliers, one per component (None = all 1.0)
+    ///
+    /// Lets components of one whorl differ in size, e.g. the banner, wings
+    /// and keel of a pea flower. Combined with any size jitter. Components
+    /// without an entry use 1.0; extra entries are ignored.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub scale_overrides: Option<Vec<f32>>,
 }
 
 impl ComponentWhorl {
     /// Calculate the angular positions for all components in this whorl
     ///
     /// Returns a vector of angles in radians, one for each component.
+    /// Uses `angle_overrides` instead of the pattern when it has exactly one
+    /// entry per component; mismatched overrides are ignored.
     pub fn calculate_angles(&self) -> Vec<f32> {
+        if let Some(overrides) = self
+            .angle_overrides
+            .as_ref()
+            .filter(|overrides| overrides.len() == self.count)
+        {
+            return overrides
+                .iter()
+                .map(|angle| self.rotation_offset + angle)
+                .collect();
+        }
+
         let mut angles = Vec::with_capacity(self.count);
 
         match self.pattern {
@@ -102,10 +148,83 @@ impl ComponentWhorl {
                     angles.push(self.rotation_offset + i as f32 * offset);
                 }
             }
+            ArrangementPattern::VogelDisc { .. } => {
+                for i in 0..self.count {
+                    let point = vogel_spiral(i, self.count, 1.0);
+                    angles.push(self.rotation_offset + point.y.atan2(point.x));
+                }
+            }
         }
 
         angles
     }
+
+    /// Calculate the (radius, height) of every component in this whorl
+    ///
+    /// Ring patterns place all components at the whorl's `radius` and
+    /// `height`. [`ArrangementPattern::VogelDisc`] maps each component's
+    /// normalized Vogel spiral radius `t = √(i / (count - 1))` onto the
+    /// annulus, so that `radius² = inner² + (outer² - inner²)·t²`, and
+    /// interpolates the height linearly with the resulting radius.
+    ///
+    /// # Returns
+    /// Vector of (radius, height) pairs, one for each component
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_components::diagram::{ArrangementPattern, ComponentWhorl};
+    ///
+    /// let whorl = ComponentWhorl {
+    ///     count: 50,
+    ///     radius: 0.8,
+    ///     height: 0.4,
+    ///     pattern: ArrangementPattern::VogelDisc { inner_radius: 0.0, inner_height: 0.8 },
+    ///     rotation_offset: 0.0,
+    ///     tilt_angle: 0.0,
+    ///     angle_overrides: None,
+    ///     scale_overrides: None,
+    /// };
+    ///
+    /// let positions = whorl.calculate_positions();
+    /// assert_eq!(positions.len(), 50);
+    /// assert!((positions[49].0 - 0.8).abs() < 1e-5);
+    /// assert!((positions[49].1 - 0.4).abs() < 1e-5);
+    /// ```
+    pub fn calculate_positions(&self) -> Vec<(f32, f32)> {
+        match self.pattern {
+            ArrangementPattern::VogelDisc {
+                inner_radius,
+                inner_height,
+            } => (0..self.count)
+                .map(|i| {
+                    let t = vogel_spiral(i, self.count, 1.0).length();
+                    let inner_sq = inner_radius * inner_radius;
+                    let radius = (inner_sq + (self.radius * self.radius - inner_sq) * t * t).sqrt();
+
+                    let span = self.radius - inner_radius;
+                    let s = if span.abs() > f32::EPSILON {
+                        (radius - inner_radius) / span
+                    } else {
+                        t
+                    };
+                    (radius, inner_height + (self.height - inner_height) * s)
+                })
+                .collect(),
+            _ => vec![(self.radius, self.height); self.count],
+        }
+    }
+
+    /// Scale multiplier for the component at `index`
+    ///
+    /// # Returns
+    /// The `scale_overrides` entry, or 1.0 without overrides or past their end
+    pub fn component_scale(&self, index: usize) -> f32 {
+        self.scale_overrides
+            .as_ref()
+            .and_then(|scales| scales.get(index))
+            .copied()
+            .unwrap_or(1.0)
+    }
 }
 
 /// Complete floral diagram defining all component arrangements
@@ -182,6 +301,8 @@ impl FloralDiagram {
                 pattern: ArrangementPattern::EvenlySpaced,
                 rotation_offset: 0.0,
                 tilt_angle: 0.0,
+                angle_overrides: None,
+                scale_overrides: None,
             }],
             stamen_whorls: vec![ComponentWhorl {
                 count: 6,
@@ -190,6 +311,8 @@ impl FloralDiagram {
                 pattern: ArrangementPattern::EvenlySpaced,
                 rotation_offset: PI / 6.0, // Offset by 30° to alternate
                 tilt_angle: PI / 2.0,      // Lilies: stamens spread horizontally
+                angle_overrides: None,
+                scale_overrides: None,
             }],
             pistil_whorls: vec![ComponentWhorl {
                 count: 1,
@@ -198,6 +321,8 @@ impl FloralDiagram {
                 pattern: ArrangementPattern::EvenlySpaced,
                 rotation_offset: 0.0,
                 tilt_angle: 0.0,
+                angle_overrides: None,
+                scale_overrides: None,
             }],
             sepal_whorls: vec![],
             position_jitter: 0.0,
@@ -225,6 +350,8 @@ impl FloralDiagram {
                 pattern: ArrangementPattern::EvenlySpaced,
                 rotation_offset: 0.0,
                 tilt_angle: 0.0,
+                angle_overrides: None,
+                scale_overrides: None,
             }],
             stamen_whorls: vec![
                 ComponentWhorl {
@@ -234,6 +361,8 @@ impl FloralDiagram {
                     pattern: ArrangementPattern::EvenlySpaced,
                     rotation_offset: 0.0,
                     tilt_angle: PI / 3.0, // ~60° spread
+                    angle_overrides: None,
+                    scale_overrides: None,
                 },
                 ComponentWhorl {
                     count: 5,
@@ -242,6 +371,8 @@ impl FloralDiagram {
                     pattern: ArrangementPattern::EvenlySpaced,
                     rotation_offset: PI / 5.0,
                     tilt_angle: PI / 4.0, // ~45° spread
+                    angle_overrides: None,
+                    scale_overrides: None,
                 },
             ],
             pistil_whorls: vec![ComponentWhorl {
@@ -251,6 +382,8 @@ impl FloralDiagram {
                 pattern: ArrangementPattern::EvenlySpaced,
                 rotation_offset: 0.0,
                 tilt_angle: 0.0,
+                angle_overrides: None,
+                scale_overrides: None,
             }],
             sepal_whorls: vec![],
             position_jitter: 0.0,
@@ -278,6 +411,8 @@ impl FloralDiagram {
                 pattern: ArrangementPattern::GoldenSpiral,
                 rotation_offset: 0.0,
                 tilt_angle: 0.0,
+                angle_overrides: None,
+                scale_overrides: None,
             }],
             stamen_whorls: vec![ComponentWhorl {
                 count: 34,
@@ -286,6 +421,8 @@ impl FloralDiagram {
                 pattern: ArrangementPattern::GoldenSpiral,
                 rotation_offset: 0.5,
                 tilt_angle: PI / 6.0, // ~30° slightly upward
+                angle_overrides: None,
+                scale_overrides: None,
             }],
             pistil_whorls: vec![ComponentWhorl {
                 count: 13,
@@ -294,6 +431,8 @@ impl FloralDiagram {
                 pattern: ArrangementPattern::GoldenSpiral,
                 rotation_offset: 1.0,
                 tilt_angle: 0.0,
+                angle_overrides: None,
+                scale_overrides: None,
             }],
             sepal_whorls: vec![],
             position_jitter: 0.0,
@@ -321,6 +460,8 @@ impl FloralDiagram {
                 pattern: ArrangementPattern::EvenlySpaced,
                 rotation_offset: PI / 4.0, // 45° offset for cross pattern
                 tilt_angle: 0.0,
+                angle_overrides: None,
+                scale_overrides: None,
             }],
             stamen_whorls: vec![ComponentWhorl {
                 count: 4,
@@ -329,6 +470,8 @@ impl FloralDiagram {
                 pattern: ArrangementPattern::EvenlySpaced,
                 rotation_offset: 0.0,
                 tilt_angle: PI / 4.0, // ~45° spread
+                angle_overrides: None,
+                scale_overrides: None,
             }],
             pistil_whorls: vec![ComponentWhorl {
                 count: 1,
@@ -337,6 +480,204 @@ impl FloralDiagram {
                 pattern: ArrangementPattern::EvenlySpaced,
                 rotation_offset: 0.0,
                 tilt_angle: 0.0,
+                angle_overrides: None,
+                scale_overrides: None,
+            }],
+            sepal_whorls: vec![],
+            position_jitter: 0.0,
+            angle_jitter: 0.0,
+            size_jitter: 0.0,
+            jitter_seed: 42,
+        }
+    }
+
+    /// Create a tulip flower diagram
+    ///
+    /// Characteristics (floral formula P3+3 A3+3 G(3)):
+    /// - 6 tepals in 2 whorls of 3, the inner whorl alternating with the outer
+    /// - 6 stamens in 2 whorls of 3
+    /// - 1 central pistil
+    pub fn tulip() -> Self {
+        use std::f32::consts::PI;
+        Self {
+            receptacle_height: 1.0,
+            receptacle_radius: 0.3,
+            petal_whorls: vec![
+                ComponentWhorl {
+                    count: 3,
+                    radius: 1.0,
+                    height: 0.85,
+                    pattern: ArrangementPattern::EvenlySpaced,
+                    rotation_offset: 0.0,
+                    tilt_angle: 0.0,
+                    angle_overrides: None,
+                    scale_overrides: None,
+                },
+                ComponentWhorl {
+                    count: 3,
+                    radius: 0.9,
+                    height: 0.8,
+                    pattern: ArrangementPattern::EvenlySpaced,
+                    rotation_offset: PI / 3.0, // Inner tepals sit between outer ones
+                    tilt_angle: 0.0,
+                    angle_overrides: None,
+                    scale_overrides: None,
+                },
+            ],
+            stamen_whorls: vec![
+                ComponentWhorl {
+                    count: 3,
+                    radius: 0.6,
+                    height: 0.6,
+                    pattern: ArrangementPattern::EvenlySpaced,
+                    rotation_offset: 0.0,
+                    tilt_angle: PI / 12.0, // Nearly upright inside the cup
+                    angle_overrides: None,
+                    scale_overrides: None,
+                },
+                ComponentWhorl {
+                    count: 3,
+                    radius: 0.5,
+                    height: 0.55,
+                    pattern: ArrangementPattern::EvenlySpaced,
+                    rotation_offset: PI / 3.0,
+                    tilt_angle: PI / 12.0,
+                    angle_overrides: None,
+                    scale_overrides: None,
+                },
+            ],
+            pistil_whorls: vec![ComponentWhorl {
+                count: 1,
+                radius: 0.0,
+                height: 0.5,
+                pattern: ArrangementPattern::EvenlySpaced,
+                rotation_offset: 0.0,
+                tilt_angle: 0.0,
+                angle_overrides: None,
+                scale_overrides: None,
+            }],
+            sepal_whorls: vec![],
+            position_jitter: 0.0,
+            angle_jitter: 0.0,
+            size_jitter: 0.0,
+            jitter_seed: 42,
+        }
+    }
+
+    /// Create an orchid flower diagram
+    ///
+    /// Characteristics (zygomorphic, K3 C3 with a labellum):
+    /// - 3 sepals: one dorsal (at +Z) and two lateral
+    /// - 3 petals: two lateral petals and an enlarged labellum (lip) at -Z
+    /// - 1 stamen and 1 pistil, standing in for the fused column
+    pub fn orchid() -> Self {
+        use std::f32::consts::PI;
+        Self {
+            receptacle_height: 0.6,
+            receptacle_radius: 0.25,
+            petal_whorls: vec![ComponentWhorl {
+                count: 3,
+                radius: 0.9,
+                height: 0.8,
+                pattern: ArrangementPattern::EvenlySpaced,
+                rotation_offset: 0.0,
+                tilt_angle: 0.0,
+                // Lateral petals flank the dorsal sepal, labellum hangs opposite
+                angle_overrides: Some(vec![PI / 6.0, 5.0 * PI / 6.0, 3.0 * PI / 2.0]),
+                scale_overrides: Some(vec![0.9, 0.9, 1.4]),
+            }],
+            stamen_whorls: vec![ComponentWhorl {
+                count: 1,
+                radius: 0.2,
+                height: 0.6,
+                pattern: ArrangementPattern::EvenlySpaced,
+                rotation_offset: PI / 2.0, // Column leans toward the dorsal side
+                tilt_angle: PI / 12.0,
+                angle_overrides: None,
+                scale_overrides: Some(vec![0.5]),
+            }],
+            pistil_whorls: vec![ComponentWhorl {
+                count: 1,
+                radius: 0.0,
+                height: 0.5,
+                pattern: ArrangementPattern::EvenlySpaced,
+                rotation_offset: PI / 2.0,
+                tilt_angle: 0.0,
+                angle_overrides: None,
+                scale_overrides: Some(vec![0.5]),
+            }],
+            sepal_whorls: vec![ComponentWhorl {
+                count: 3,
+                radius: 1.0,
+                height: 0.9,
+                pattern: ArrangementPattern::EvenlySpaced,
+                rotation_offset: PI / 2.0, // Dorsal sepal on top
+                tilt_angle: 0.0,
+                angle_overrides: None,
+                scale_overrides: None,
+            }],
+            position_jitter: 0.0,
+            angle_jitter: 0.0,
+            size_jitter: 0.0,
+            jitter_seed: 42,
+        }
+    }
+
+    /// Create a poppy flower diagram
+    ///
+    /// Characteristics (floral formula C2+2 A∞ G(∞)):
+    /// - 4 petals in 2 crossed pairs
+    /// - 60 stamens packed in a Vogel spiral band around the ovary
+    /// - 1 central pistil (capsule with a radiating stigma disc)
+    pub fn poppy() -> Self {
+        use std::f32::consts::PI;
+        Self {
+            receptacle_height: 0.5,
+            receptacle_radius: 0.4,
+            petal_whorls: vec![
+                ComponentWhorl {
+                    count: 2,
+                    radius: 1.2,
+                    height: 0.7,
+                    pattern: ArrangementPattern::EvenlySpaced,
+                    rotation_offset: 0.0,
+                    tilt_angle: 0.0,
+                    angle_overrides: None,
+                    scale_overrides: None,
+                },
+                ComponentWhorl {
+                    count: 2,
+                    radius: 1.1,
+                    height: 0.65,
+                    pattern: ArrangementPattern::EvenlySpaced,
+                    rotation_offset: PI / 2.0, // Inner pair crosses the outer pair
+                    tilt_angle: 0.0,
+                    angle_overrides: None,
+                    scale_overrides: None,
+                },
+            ],
+            stamen_whorls: vec![ComponentWhorl {
+                count: 60,
+                radius: 0.8,
+                height: 0.6,
+                pattern: ArrangementPattern::VogelDisc {
+                    inner_radius: 0.5,
+                    inner_height: 0.5,
+                },
+                rotation_offset: 0.0,
+                tilt_angle: PI / 6.0,
+                angle_overrides: None,
+                scale_overrides: None,
+            }],
+            pistil_whorls: vec![ComponentWhorl {
+                count: 1,
+                radius: 0.0,
+                height: 0.4,
+                pattern: ArrangementPattern::EvenlySpaced,
+                rotation_offset: 0.0,
+                tilt_angle: 0.0,
+                angle_overrides: None,
+                scale_overrides: None,
             }],
             sepal_whorls: vec![],
             position_jitter: 0.0,
@@ -346,6 +687,11 @@ impl FloralDiagram {
         }
     }
 
+    /// Whether any position, angle or size jitter is enabled
+    pub fn has_jitter(&self) -> bool {
+        self.position_jitter > 0.0 || self.angle_jitter > 0.0 || self.size_jitter > 0.0
+    }
+
     /// Get the total number of petals in this diagram
     pub fn total_petal_count(&self) -> usize {
         self.petal_whorls.iter().map(|w| w.count).sum()
@@ -360,6 +706,11 @@ impl FloralDiagram {
     pub fn total_pistil_count(&self) -> usize {
         self.pistil_whorls.iter().map(|w| w.count).sum()
     }
+
+    /// Get the total number of sepals in this diagram
+    pub fn total_sepal_count(&self) -> usize {
+        self.sepal_whorls.iter().map(|w| w.count).sum()
+    }
 }
 
 #[cfg(test)]
@@ -375,6 +726,8 @@ mod tests {
             pattern: ArrangementPattern::EvenlySpaced,
             rotation_offset: 0.0,
             tilt_angle: 0.0,
+            angle_overrides: None,
+            scale_overrides: None,
         };
 
         let angles = whorl.calculate_angles();
@@ -397,6 +750,8 @@ mod tests {
             pattern: ArrangementPattern::GoldenSpiral,
             rotation_offset: 0.0,
             tilt_angle: 0.0,
+            angle_overrides: None,
+            scale_overrides: None,
         };
 
         let angles = whorl.calculate_angles();
@@ -410,6 +765,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_angle_and_scale_overrides() {
+        use std::f32::consts::PI;
+
+        let whorl = ComponentWhorl {
+            count: 3,
+            radius: 1.0,
+            height: 0.5,
+            pattern: ArrangementPattern::EvenlySpaced,
+            rotation_offset: 0.1,
+            tilt_angle: 0.0,
+            angle_overrides: Some(vec![0.0, PI / 2.0, PI]),
+            scale_overrides: Some(vec![1.5, 1.0, 0.5]),
+        };
+
+        let angles = whorl.calculate_angles();
+        assert_eq!(angles, vec![0.1, 0.1 + PI / 2.0, 0.1 + PI]);
+        assert_eq!(whorl.component_scale(0), 1.5);
+        assert_eq!(whorl.component_scale(2), 0.5);
+
+        let plain = ComponentWhorl {
+            angle_overrides: None,
+            scale_overrides: None,
+            ..whorl
+        };
+        assert_eq!(plain.component_scale(1), 1.0);
+        assert!((plain.calculate_angles()[1] - (0.1 + std::f32::consts::TAU / 3.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_overrides_count_mismatch() {
+        let whorl = ComponentWhorl {
+            count: 4,
+            radius: 1.0,
+            height: 0.5,
+            pattern: ArrangementPattern::EvenlySpaced,
+            rotation_offset: 0.0,
+            tilt_angle: 0.0,
+            angle_overrides: Some(vec![0.0, 1.0]),
+            scale_overrides: Some(vec![2.0, 0.5]),
+        };
+
+        // Mismatched angles fall back to the pattern
+        let plain = ComponentWhorl {
+            angle_overrides: None,
+            ..whorl.clone()
+        };
+        assert_eq!(whorl.calculate_angles(), plain.calculate_angles());
+
+        // Short scales are padded with 1.0
+        assert_eq!(whorl.component_scale(1), 0.5);
+        assert_eq!(whorl.component_scale(3), 1.0);
+    }
+
     #[test]
     fn test_rotation_offset() {
         let whorl = ComponentWhorl {
@@ -419,6 +828,8 @@ mod tests {
             pattern: ArrangementPattern::EvenlySpaced,
             rotation_offset: std::f32::consts::PI / 4.0,
             tilt_angle: 0.0,
+            angle_overrides: None,
+            scale_overrides: None,
         };
 
         let angles = whorl.calculate_angles();
@@ -484,6 +895,101 @@ mod tests {
         assert_eq!(diagram.total_pistil_count(), 1);
     }
 
+    #[test]
+    fn test_tulip_diagram() {
+        let diagram = FloralDiagram::tulip();
+
+        // P3+3 A3+3 G(3)
+        assert_eq!(diagram.petal_whorls.len(), 2);
+        assert!(diagram.petal_whorls.iter().all(|w| w.count == 3));
+        assert_eq!(diagram.total_petal_count(), 6);
+        assert_eq!(diagram.total_stamen_count(), 6);
+        assert_eq!(diagram.total_pistil_count(), 1);
+        assert_eq!(diagram.total_sepal_count(), 0);
+    }
+
+    #[test]
+    fn test_orchid_diagram() {
+        let diagram = FloralDiagram::orchid();
+
+        // K3 C3 with a labellum, column as 1 stamen + 1 pistil
+        assert_eq!(diagram.total_sepal_count(), 3);
+        assert_eq!(diagram.total_petal_count(), 3);
+        assert_eq!(diagram.total_stamen_count(), 1);
+        assert_eq!(diagram.total_pistil_count(), 1);
+
+        // Labellum is the single enlarged petal
+        let petals = &diagram.petal_whorls[0];
+        let largest = (0..petals.count)
+            .max_by(|&a, &b| {
+                petals
+                    .component_scale(a)
+                    .total_cmp(&petals.component_scale(b))
+            })
+            .unwrap();
+        assert_eq!(largest, 2);
+        assert_eq!(petals.calculate_angles().len(), 3);
+    }
+
+    #[test]
+    fn test_poppy_diagram() {
+        let diagram = FloralDiagram::poppy();
+
+        // C2+2 A∞ G(∞): 4 petals, 60 spiral-packed stamens, one capsule
+        assert_eq!(diagram.total_petal_count(), 4);
+        assert_eq!(diagram.total_stamen_count(), 60);
+        assert_eq!(diagram.total_pistil_count(), 1);
+        assert!(matches!(
+            diagram.stamen_whorls[0].pattern,
+            ArrangementPattern::VogelDisc { .. }
+        ));
+    }
+
+    #[test]
+    fn test_vogel_disc_positions() {
+        let whorl = ComponentWhorl {
+            count: 40,
+            radius: 1.0,
+            height: 0.2,
+            pattern: ArrangementPattern::VogelDisc {
+                inner_radius: 0.0,
+                inner_height: 1.0,
+            },
+            rotation_offset: 0.0,
+            tilt_angle: 0.0,
+            angle_overrides: None,
+            scale_overrides: None,
+        };
+
+        let positions = whorl.calculate_positions();
+        assert_eq!(positions.len(), 40);
+        assert_eq!(whorl.calculate_angles().len(), 40);
+
+        // √ packing law: radius² grows linearly with index
+        for (i, &(radius, height)) in positions.iter().enumerate() {
+            let expected = (i as f32 / 39.0).sqrt();
+            assert!((radius - expected).abs() < 1e-5);
+            // Height interpolates with radius: center high, rim low
+            assert!((height - (1.0 - 0.8 * radius)).abs() < 1e-5);
+        }
+
+        // Annulus keeps equal areas between consecutive components
+        let annulus = ComponentWhorl {
+            pattern: ArrangementPattern::VogelDisc {
+                inner_radius: 0.5,
+                inner_height: 0.2,
+            },
+            ..whorl.clone()
+        };
+        let radii: Vec<f32> = annulus.calculate_positions().iter().map(|p| p.0).collect();
+        assert!((radii[0] - 0.5).abs() < 1e-5);
+        assert!((radii[39] - 1.0).abs() < 1e-5);
+        let step = radii[1] * radii[1] - radii[0] * radii[0];
+        for pair in radii.windows(2) {
+            assert!((pair[1] * pair[1] - pair[0] * pair[0] - step).abs() < 1e-4);
+        }
+    }
+
     #[test]
     fn test_custom_offset_pattern() {
         let whorl = ComponentWhorl {
@@ -493,6 +999,8 @@ mod tests {
             pattern: ArrangementPattern::CustomOffset(1.0),
             rotation_offset: 0.0,
             tilt_angle: 0.0,
+            angle_overrides: None,
+            scale_overrides: None,
         };
 
         let angles = whorl.calculate_angles();
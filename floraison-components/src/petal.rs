@@ -3,6 +3,7 @@
 //! Creates petals with B-spline surfaces supporting deformations (curl, twist, ruffle).
 
 use crate::{Mesh, Vec2, Vec3};
+use floraison_core::geometry::tessellation::{tessellate_polygon, tessellate_surface_adaptive};
 use floraison_core::math::bezier::sample_cubic_2d;
 use floraison_core::math::bspline::{generate_knot_vector, BSplineSurface};
 
@@ -24,6 +25,20 @@ use serde::{Deserialize, Serialize};
 ///     curl: 0.0,
 ///     twist: 0.0,
 ///     lateral_curve: 0.0,
+///     droop: 0.0,
+///     adaptive_error: None,
+///     midrib_depth: 0.0,
+///     serration_freq: 0.0,
+///     serration_depth: 0.0,
+///     asymmetry: 0.0,
+///     tip_notch_depth: 0.0,
+///     tip_lobes: 1,
+///     tip_lobe_depth: 0.5,
+///     color_tip: None,
+///     cup: 0.0,
+///     reflex: 0.0,
+///     double_sided: true,
+///     thickness: 0.0,
 ///     ruffle_freq: 0.0,
 ///     ruffle_amp: 0.0,
 ///     resolution: 16,
@@ -50,6 +65,8 @@ pub struct PetalParams {
     pub base_width: f32,
 
     /// Curl amount (-1.0 = curl down, 0.0 = flat, 1.0 = curl up)
+    ///
+    /// Clamped to [-1, 1] by [`generate`] (at most 90° of bend at the tip).
     pub curl: f32,
 
     /// Twist angle in degrees (applied progressively toward tip)
@@ -65,6 +82,106 @@ pub struct PetalParams {
     /// Bends the petal sideways in the XY plane
     pub lateral_curve: f32,
 
+    /// Droop amount (0.0 = none, 1.0 = hanging 90° down from its base)
+    ///
+    /// Rotates the whole petal about its base toward -Z (the outer/lower side),
+    /// unlike `curl` which bends progressively toward the tip. Used for wilting.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub droop: f32,
+
+    /// Adaptive tessellation error bound (None = fixed `resolution` grid)
+    ///
+    /// When set, the surface is sampled densely only where it curves, so that
+    /// no quad deviates from the true surface by more than this distance.
+    /// `resolution` is ignored in that case.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub adaptive_error: Option<f32>,
+
+    /// Midrib depth (0.0 = none; height of the raised central vein along +Z)
+    ///
+    /// Lifts the center column of the petal, strongest mid-length and fading
+    /// out at the base and tip.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub midrib_depth: f32,
+
+    /// Serration frequency (number of teeth along each side edge)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub serration_freq: f32,
+
+    /// Serration depth (how far the teeth push in/out of the outline)
+    ///
+    /// Applied after tessellation to the side-edge vertices only, so the
+    /// interior of the petal stays smooth.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub serration_depth: f32,
+
+    /// Left/right asymmetry (-1.0 = left side wider, 0.0 = symmetric, 1.0 = right side wider)
+    ///
+    /// Scales the right half of the outline by `1 + asymmetry` and the left
+    /// half by `1 - asymmetry`, keeping the midline at x = 0. Clamped to
+    /// [-1, 1]; at the limits one half collapses onto the midline.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub asymmetry: f32,
+
+    /// Tip notch depth (0.0 = none, 1.0 = cleft reaching 30% of the length)
+    ///
+    /// Pulls the center of the tip region down toward the base, splitting the
+    /// tip into two lobes (as in Dianthus or Stellaria). Clamped to [0, 1].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tip_notch_depth: f32,
+
+    /// Number of rounded lobes along the tip edge (1 = single tip)
+    ///
+    /// Values above 1 scallop the tip outline and widen the control grid to
+    /// `4 * tip_lobes + 1` columns so each lobe has enough control points.
+    #[cfg_attr(feature = "serde", serde(default = "default_tip_lobes"))]
+    pub tip_lobes: usize,
+
+    /// Depth of the sinuses between tip lobes (0.0 = none, 1.0 = down to
+    /// the widest point of the petal)
+    ///
+    /// Only used when `tip_lobes > 1`. Clamped to [0, 1].
+    #[cfg_attr(feature = "serde", serde(default = "default_tip_lobe_depth"))]
+    pub tip_lobe_depth: f32,
+
+    /// Optional tip color (None = uniform `color`)
+    ///
+    /// When set, vertex colors blend from `color` at the base to this color
+    /// at the tip along the length of the petal.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub color_tip: Option<Vec3>,
+
+    /// Cup amount (0.0 = flat, > 0 = bowl shape; lift of the edges along +Z)
+    ///
+    /// Raises both side edges while the center stays low, like a tulip petal.
+    /// Unlike `curl`, the lift is the same along the whole length.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cup: f32,
+
+    /// Reflex amount (0.0 = none, 1.0 = tip bent 180° back toward -Z)
+    ///
+    /// Like a downward `curl`, but reaching past 90° for the swept-back
+    /// petals of Turk's-cap lilies. [`generate`] clamps it to [0, 1] and
+    /// further limits it so that curl and reflex together never bend the tip
+    /// more than 180° back, where it would fold into the flower's center.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub reflex: f32,
+
+    /// Whether to add back faces (default true)
+    ///
+    /// Set to false when rendering with double-sided materials to halve the
+    /// vertex and triangle count; only the front face is generated.
+    #[cfg_attr(feature = "serde", serde(default = "default_double_sided"))]
+    pub double_sided: bool,
+
+    /// Petal thickness (0.0 = zero-thickness sheet)
+    ///
+    /// When positive, the back face is pushed behind the front face by this
+    /// distance and the rim is stitched closed, producing a solid petal. The
+    /// back face is always generated in that case, regardless of `double_sided`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub thickness: f32,
+
     /// Tessellation resolution (samples per parametric direction)
     pub resolution: usize,
 
@@ -72,6 +189,21 @@ pub struct PetalParams {
     pub color: Vec3,
 }
 
+#[cfg(feature = "serde")]
+fn default_tip_lobes() -> usize {
+    1
+}
+
+#[cfg(feature = "serde")]
+fn default_tip_lobe_depth() -> f32 {
+    0.5
+}
+
+#[cfg(feature = "serde")]
+fn default_double_sided() -> bool {
+    true
+}
+
 impl Default for PetalParams {
     /// Create default parameters for a lily-like petal
     fn default() -> Self {
@@ -85,6 +217,20 @@ impl Default for PetalParams {
             ruffle_freq: 0.0,
             ruffle_amp: 0.0,
             lateral_curve: 0.0,
+            droop: 0.0,
+            adaptive_error: None,
+            midrib_depth: 0.0,
+            serration_freq: 0.0,
+            serration_depth: 0.0,
+            asymmetry: 0.0,
+            tip_notch_depth: 0.0,
+            tip_lobes: 1,
+            tip_lobe_depth: 0.5,
+            color_tip: None,
+            cup: 0.0,
+            reflex: 0.0,
+            double_sided: true,
+            thickness: 0.0,
             resolution: 16,
             color: Vec3::ONE, // White
         }
@@ -104,6 +250,20 @@ impl PetalParams {
             ruffle_freq: 0.0,
             ruffle_amp: 0.0,
             lateral_curve: 0.0,
+            droop: 0.0,
+            adaptive_error: None,
+            midrib_depth: 0.0,
+            serration_freq: 0.0,
+            serration_depth: 0.0,
+            asymmetry: 0.0,
+            tip_notch_depth: 0.0,
+            tip_lobes: 1,
+            tip_lobe_depth: 0.5,
+            color_tip: None,
+            cup: 0.0,
+            reflex: 0.0,
+            double_sided: true,
+            thickness: 0.0,
             resolution: 20,
             color: Vec3::ONE,
         }
@@ -121,11 +281,54 @@ impl PetalParams {
             ruffle_freq: 0.0,
             ruffle_amp: 0.0,
             lateral_curve: 0.0,
+            droop: 0.0,
+            adaptive_error: None,
+            midrib_depth: 0.0,
+            serration_freq: 0.0,
+            serration_depth: 0.0,
+            asymmetry: 0.0,
+            tip_notch_depth: 0.0,
+            tip_lobes: 1,
+            tip_lobe_depth: 0.5,
+            color_tip: None,
+            cup: 0.0,
+            reflex: 0.0,
+            double_sided: true,
+            thickness: 0.0,
             resolution: 16,
             color: Vec3::ONE,
         }
     }
 
+    /// Create a strongly reflexed petal (Turk's-cap lily)
+    pub fn reflexed() -> Self {
+        Self {
+            length: 3.5,
+            width: 1.0,
+            tip_sharpness: 0.5,
+            base_width: 0.3,
+            twist: 10.0,
+            reflex: 0.8,
+            resolution: 20,
+            ..Self::default()
+        }
+    }
+
+    /// Create a small green bract (leafy scale subtending a flower or pedicel)
+    pub fn bract() -> Self {
+        Self {
+            length: 0.6,
+            width: 0.3,
+            tip_sharpness: 0.7,
+            base_width: 0.15,
+            curl: -0.1,
+            midrib_depth: 0.02,
+            resolution: 8,
+            color: Vec3::new(0.35, 0.55, 0.25), // Leaf green
+            ..Self::default()
+        }
+    }
+
     /// Create a short, rounded petal
     pub fn short() -> Self {
         Self {
@@ -138,6 +341,20 @@ impl PetalParams {
             ruffle_freq: 0.0,
             ruffle_amp: 0.0,
             lateral_curve: 0.0,
+            droop: 0.0,
+            adaptive_error: None,
+            midrib_depth: 0.0,
+            serration_freq: 0.0,
+            serration_depth: 0.0,
+            asymmetry: 0.0,
+            tip_notch_depth: 0.0,
+            tip_lobes: 1,
+            tip_lobe_depth: 0.5,
+            color_tip: None,
+            cup: 0.0,
+            reflex: 0.0,
+            double_sided: true,
+            thickness: 0.0,
             resolution: 12,
             color: Vec3::ONE,
         }
@@ -152,12 +369,18 @@ impl PetalParams {
 /// - Tapered at tip
 ///
 /// The grid is oriented in the XY plane (all Z = 0) with the base at y=0
-/// and tip at y=length.
+/// and tip at y=length. The outline is symmetric about x=0 unless
+/// `params.asymmetry` is nonzero, in which case one half is wider.
+/// A nonzero `params.tip_notch_depth` pulls the tip-center points down,
+/// leaving a cleft between two tip lobes. With `params.tip_lobes > 1` the
+/// outline above the widest point is scalloped into that many rounded lobes
+/// by scaling its extent from the widest point by up to `params.tip_lobe_depth`.
 ///
 /// # Grid Dimensions
 ///
 /// - **Rows** (v direction, along length): 9 points
-/// - **Columns** (u direction, across width): 5 points
+/// - **Columns** (u direction, across width): 5 points, or `4 * tip_lobes + 1`
+///   for multi-lobed tips
 ///
 /// This provides smooth B-spline surfaces with cubic degree (p=3).
 ///
@@ -183,10 +406,19 @@ impl PetalParams {
 /// assert_eq!(grid[0].len(), 5);  // 5 columns
 /// ```
 pub fn generate_control_grid(params: &PetalParams) -> Vec<Vec<Vec3>> {
+    use std::f32::consts::PI;
+
     const ROWS: usize = 9; // Along length (v direction)
-    const COLS: usize = 5; // Across width (u direction)
+    let lobes = params.tip_lobes.max(1);
+    let cols = (4 * lobes + 1).max(5); // Across width (u direction)
+
+    let mut grid = vec![vec![Vec3::ZERO; cols]; ROWS];
 
-    let mut grid = vec![vec![Vec3::ZERO; COLS]; ROWS];
+    // Cleft depth at the tip; it deepens linearly across the top 40% of the
+    // petal, slower than the rows advance so the center column never folds back
+    let notch = params.tip_notch_depth.clamp(0.0, 1.0) * 0.3 * params.length;
+    let lobe_depth = params.tip_lobe_depth.clamp(0.0, 1.0);
+    let asymmetry = params.asymmetry.clamp(-1.0, 1.0);
 
     for (row, row_data) in grid.iter_mut().enumerate().take(ROWS) {
         // v parameter: 0.0 at base, 1.0 at tip
@@ -206,14 +438,39 @@ pub fn generate_control_grid(params: &PetalParams) -> Vec<Vec<Vec3>> {
             params.width + (params.width * params.tip_sharpness - params.width) * t
         };
 
-        for (col, cell) in row_data.iter_mut().enumerate().take(COLS) {
+        for (col, cell) in row_data.iter_mut().enumerate() {
             // u parameter: 0.0 at left edge, 1.0 at right edge
-            let u = col as f32 / (COLS - 1) as f32;
+            let u = col as f32 / (cols - 1) as f32;
 
-            // Map u to x coordinate: centered at x=0
-            let x = (u - 0.5) * width_at_v;
+            // Map u to x coordinate: centered at x=0, with each half
+            // scaled independently for asymmetric outlines
+            let side_scale = if u < 0.5 {
+                1.0 - asymmetry
+            } else {
+                1.0 + asymmetry
+            };
+            let x = (u - 0.5) * width_at_v * side_scale;
+
+            // Tip notch: pull the center of the tip region toward the base
+            let center_weight = 1.0 - (u - 0.5).abs() * 2.0;
+            let tip_t = ((v - 0.6) / 0.4).max(0.0);
+            let y = y - notch * tip_t * center_weight;
+
+            // Tip lobes: scallop the tip outline with one cosine period per
+            // lobe by shrinking its extent from the widest point toward the
+            // sinuses, keeping lobe centers in place. A factor in [0, 1]
+            // never pulls a point past the widest point, so the outline
+            // can't fold over.
+            let point = Vec2::new(x, y);
+            let point = if lobes > 1 {
+                let sinus = 0.5 * (1.0 + (2.0 * PI * lobes as f32 * u).cos());
+                let widest = Vec2::new(0.0, 0.6 * params.length);
+                widest + (point - widest) * (1.0 - lobe_depth * tip_t * sinus)
+            } else {
+                point
+            };
 
-            *cell = Vec3::new(x, y, 0.0);
+            *cell = point.extend(0.0);
         }
     }
 
@@ -269,6 +526,34 @@ pub fn apply_curl(control_points: &mut [Vec<Vec3>], amount: f32) {
     }
 }
 
+/// Apply reflex deformation to control points
+///
+/// Bends the petal backward toward -Z the same way a negative [`apply_curl`]
+/// does, but with twice the range: the tip is rotated by up to 180°, so it
+/// ends up pointing back past the base as in Turk's-cap lilies.
+///
+/// # Arguments
+///
+/// * `control_points` - Mutable reference to 2D grid of control points
+/// * `amount` - Reflex amount in range [0, 1] (1 = tip bent 180° back)
+///
+/// # Example
+///
+/// ```
+/// use floraison_components::petal::{PetalParams, generate_control_grid, apply_reflex};
+///
+/// let params = PetalParams::default();
+/// let mut grid = generate_control_grid(&params);
+///
+/// // Fully reflexed: the tip points straight back down the Y axis
+/// apply_reflex(&mut grid, 1.0);
+/// assert!((grid[8][2].y + params.length).abs() < 1e-4);
+/// ```
+pub fn apply_reflex(control_points: &mut [Vec<Vec3>], amount: f32) {
+    // Same quadratic bend as curl, downward, with a 180° range
+    apply_curl(control_points, -2.0 * amount);
+}
+
 /// Apply twist deformation to control points
 ///
 /// Twists the petal around its central axis (Y axis).
@@ -365,6 +650,136 @@ pub fn apply_lateral_curve(control_points: &mut [Vec<Vec3>], amount: f32) {
     }
 }
 
+/// Apply droop deformation to control points
+///
+/// Rotates the whole petal rigidly about its base (the X axis at y = 0),
+/// folding it down toward -Z. Unlike [`apply_curl`], which is tip-biased,
+/// droop pivots at the attachment point so the entire blade collapses,
+/// as seen in wilting flowers.
+///
+/// # Arguments
+///
+/// * `control_points` - Mutable reference to 2D grid of control points
+/// * `amount` - Droop amount (0.0 = none, 1.0 = 90° downward)
+///
+/// # Example
+///
+/// ```
+/// use floraison_components::petal::{PetalParams, generate_control_grid, apply_droop};
+///
+/// let params = PetalParams::default();
+/// let mut grid = generate_control_grid(&params);
+///
+/// // Fully droop: tip ends up pointing along -Z
+/// apply_droop(&mut grid, 1.0);
+/// let tip = grid[8][2];
+/// assert!(tip.y.abs() < 1e-4);
+/// assert!((tip.z + params.length).abs() < 1e-4);
+/// ```
+pub fn apply_droop(control_points: &mut [Vec<Vec3>], amount: f32) {
+    use std::f32::consts::PI;
+
+    let angle = -amount * PI * 0.5;
+    let (sin_a, cos_a) = angle.sin_cos();
+
+    for point in control_points.iter_mut().flatten() {
+        let y = point.y;
+        let z = point.z;
+
+        // Rotate in YZ plane around X axis through the base
+        point.y = y * cos_a - z * sin_a;
+        point.z = y * sin_a + z * cos_a;
+    }
+}
+
+/// Apply midrib deformation to control points
+///
+/// Raises the center column (u = 0.5) out of plane along +Z to form a
+/// central vein. The lift follows `sin(π·v)` along the length, so it peaks
+/// mid-petal and fades to zero at the base and tip, and falls off linearly
+/// toward the edges, which stay in place.
+///
+/// # Arguments
+///
+/// * `control_points` - Mutable reference to 2D grid of control points
+/// * `depth` - Height of the midrib at its peak (negative values form a groove)
+///
+/// # Example
+///
+/// ```
+/// use floraison_components::petal::{PetalParams, generate_control_grid, apply_midrib};
+///
+/// let params = PetalParams::default();
+/// let mut grid = generate_control_grid(&params);
+///
+/// apply_midrib(&mut grid, 0.1);
+/// assert!(grid[4][2].z > grid[4][0].z);
+/// ```
+pub fn apply_midrib(control_points: &mut [Vec<Vec3>], depth: f32) {
+    use std::f32::consts::PI;
+
+    let rows = control_points.len();
+    let cols = control_points[0].len();
+
+    for (row_idx, row) in control_points.iter_mut().enumerate() {
+        // v parameter: 0.0 at base, 1.0 at tip
+        let v = row_idx as f32 / (rows - 1) as f32;
+
+        // Peaks mid-length, zero at base and tip
+        let length_profile = (v * PI).sin();
+
+        for (col_idx, point) in row.iter_mut().enumerate() {
+            // u parameter: 0.0 at left edge, 1.0 at right edge
+            let u = col_idx as f32 / (cols - 1) as f32;
+
+            // Center weight: 1 at center, 0 at edges
+            let center_weight = 1.0 - (u - 0.5).abs() * 2.0;
+
+            point.z += depth * length_profile * center_weight;
+        }
+    }
+}
+
+/// Apply cup deformation to control points
+///
+/// Lifts the side columns along +Z in proportion to the squared distance from
+/// the center column, giving the petal a bowl-shaped cross-section. Every row
+/// is lifted the same way, so unlike [`apply_curl`] the petal does not bend
+/// along its length.
+///
+/// # Arguments
+///
+/// * `control_points` - Mutable reference to 2D grid of control points
+/// * `amount` - Height of the edges above the center (negative values cup downward)
+///
+/// # Example
+///
+/// ```
+/// use floraison_components::petal::{PetalParams, generate_control_grid, apply_cup};
+///
+/// let params = PetalParams::default();
+/// let mut grid = generate_control_grid(&params);
+///
+/// apply_cup(&mut grid, 0.3);
+/// assert!((grid[4][0].z - 0.3).abs() < 1e-6);
+/// assert_eq!(grid[4][2].z, 0.0);
+/// ```
+pub fn apply_cup(control_points: &mut [Vec<Vec3>], amount: f32) {
+    let cols = control_points[0].len();
+
+    for row in control_points.iter_mut() {
+        for (col_idx, point) in row.iter_mut().enumerate() {
+            // u parameter: 0.0 at left edge, 1.0 at right edge
+            let u = col_idx as f32 / (cols - 1) as f32;
+
+            // Distance from center: 0 at center, 1 at edges
+            let distance = (u - 0.5).abs() * 2.0;
+
+            point.z += amount * distance * distance;
+        }
+    }
+}
+
 /// Apply ruffle deformation to control points
 ///
 /// Adds sinusoidal waves to the edges of the petal for a ruffled appearance.
@@ -427,15 +842,21 @@ pub fn apply_ruffle(control_points: &mut [Vec<Vec3>], frequency: f32, amplitude:
 ///
 /// Creates a 3D petal using B-spline surface evaluation with support for:
 /// - Curl (bending up/down)
+/// - Reflex (bending back past 90°)
 /// - Twist (rotating around center)
 /// - Ruffle (wavy edges)
+/// - Droop (whole petal pivoting down at the base)
+/// - Midrib (raised central vein)
+/// - Serration (sawtooth side edges)
+/// - Cup (bowl-shaped cross-section)
 ///
 /// The petal is generated by:
 /// 1. Creating a control point grid matching the outline shape
 /// 2. Applying deformations (curl, twist, ruffle)
 /// 3. Creating a B-spline surface
 /// 4. Tessellating the surface at the specified resolution
-/// 5. Adding back faces for double-sided rendering
+/// 5. Adding back faces for double-sided rendering (unless `double_sided` is false)
+/// 6. Stitching the rim closed when `thickness` is positive
 ///
 /// # Arguments
 ///
@@ -459,6 +880,20 @@ pub fn apply_ruffle(control_points: &mut [Vec<Vec3>], frequency: f32, amplitude:
 ///     curl: 0.3,
 ///     twist: 15.0,
 ///     lateral_curve: 0.0,
+///     droop: 0.0,
+///     adaptive_error: None,
+///     midrib_depth: 0.0,
+///     serration_freq: 0.0,
+///     serration_depth: 0.0,
+///     asymmetry: 0.0,
+///     tip_notch_depth: 0.0,
+///     tip_lobes: 1,
+///     tip_lobe_depth: 0.5,
+///     color_tip: None,
+///     cup: 0.0,
+///     reflex: 0.0,
+///     double_sided: true,
+///     thickness: 0.0,
 ///     ruffle_freq: 2.0,
 ///     ruffle_amp: 0.1,
 ///     resolution: 16,
@@ -469,12 +904,62 @@ pub fn apply_ruffle(control_points: &mut [Vec<Vec3>], frequency: f32, amplitude:
 /// assert!(petal.triangle_count() > 0);
 /// ```
 pub fn generate(params: &PetalParams) -> Mesh {
+    generate_with_grid(params).0
+}
+
+/// Generate a petal mesh along with its deformed B-spline control grid
+///
+/// Same as [`generate`], but also returns the control grid after all
+/// deformations (curl, twist, lateral curve, ruffle) have been applied.
+/// Useful for visualizing the control cage that shapes the petal surface.
+///
+/// # Arguments
+///
+/// * `params` - Petal parameters
+///
+/// # Returns
+///
+/// Tuple of (petal mesh, deformed control grid). The grid uses the same
+/// `grid[row][col]` layout as [`generate_control_grid`] (9 rows × 5 columns,
+/// or more for multi-lobed tips).
+///
+/// # Example
+///
+/// ```
+/// use floraison_components::petal::{generate_control_grid, generate_with_grid, PetalParams};
+///
+/// let params = PetalParams {
+///     curl: 0.5,
+///     ..Default::default()
+/// };
+/// let (mesh, grid) = generate_with_grid(&params);
+///
+/// assert!(mesh.triangle_count() > 0);
+/// assert_eq!(grid.len(), 9);
+/// assert_eq!(grid[0].len(), 5);
+///
+/// // Deformations move the cage away from the flat outline
+/// assert_ne!(grid, generate_control_grid(&params));
+/// ```
+pub fn generate_with_grid(params: &PetalParams) -> (Mesh, Vec<Vec<Vec3>>) {
     // 1. Generate control grid
     let mut control_points = generate_control_grid(params);
 
-    // 2. Apply deformations
-    if params.curl.abs() > 0.001 {
-        apply_curl(&mut control_points, params.curl);
+    // 2. Apply deformations, clamping the bends so the tip never folds back
+    //    more than 180° into the flower's center
+    let curl = params.curl.clamp(-1.0, 1.0);
+    let reflex = params.reflex.clamp(0.0, 1.0).min(1.0 + curl * 0.5);
+    if params.midrib_depth.abs() > 0.001 {
+        apply_midrib(&mut control_points, params.midrib_depth);
+    }
+    if params.cup.abs() > 0.001 {
+        apply_cup(&mut control_points, params.cup);
+    }
+    if curl.abs() > 0.001 {
+        apply_curl(&mut control_points, curl);
+    }
+    if reflex > 0.001 {
+        apply_reflex(&mut control_points, reflex);
     }
     if params.twist.abs() > 0.001 {
         apply_twist(&mut control_points, params.twist);
@@ -485,16 +970,19 @@ pub fn generate(params: &PetalParams) -> Mesh {
     if params.ruffle_freq.abs() > 0.001 && params.ruffle_amp.abs() > 0.001 {
         apply_ruffle(&mut control_points, params.ruffle_freq, params.ruffle_amp);
     }
+    if params.droop.abs() > 0.001 {
+        apply_droop(&mut control_points, params.droop);
+    }
 
     // 3. Create B-spline surface
-    const ROWS: usize = 9;
-    const COLS: usize = 5;
+    let rows = control_points.len();
+    let cols = control_points[0].len();
 
     // Transpose control grid: BSplineSurface expects control_points[u_index][v_index]
     // but our grid is [row][col] = [v_index][u_index]
-    let mut transposed = vec![vec![Vec3::ZERO; ROWS]; COLS];
-    for (row, row_data) in control_points.iter().enumerate().take(ROWS) {
-        for (col, col_data) in transposed.iter_mut().enumerate().take(COLS) {
+    let mut transposed = vec![vec![Vec3::ZERO; rows]; cols];
+    for (row, row_data) in control_points.iter().enumerate() {
+        for (col, col_data) in transposed.iter_mut().enumerate() {
             col_data[row] = row_data[col];
         }
     }
@@ -503,29 +991,171 @@ pub fn generate(params: &PetalParams) -> Mesh {
         control_points: transposed,
         degree_u: 3,                                  // Cubic in u direction (width)
         degree_v: 3,                                  // Cubic in v direction (length)
-        knots_u: generate_knot_vector(COLS, 3, true), // 5 control points in u
-        knots_v: generate_knot_vector(ROWS, 3, true), // 9 control points in v
+        knots_u: generate_knot_vector(cols, 3, true), // 5+ control points in u
+        knots_v: generate_knot_vector(rows, 3, true), // 9 control points in v
+    };
+
+    // 4. Tessellate surface (front face)
+    let mut mesh = match params.adaptive_error {
+        Some(max_error) => {
+            let mut mesh = tessellate_surface_adaptive(&surface, max_error);
+            mesh.colors.fill(params.color);
+            mesh
+        }
+        None => tessellate_uniform(&surface, params.resolution, params.color),
+    };
+    if let Some(color_tip) = params.color_tip {
+        for (color, uv) in mesh.colors.iter_mut().zip(&mesh.uvs) {
+            *color = params.color.lerp(color_tip, uv.y);
+        }
+    }
+    if params.serration_freq.abs() > 0.001 && params.serration_depth.abs() > 0.001 {
+        serrate_edges(
+            &mut mesh,
+            &surface,
+            params.serration_freq,
+            params.serration_depth,
+        );
+    }
+
+    // 5. Add back faces (flip normals and winding order)
+    let solid = params.thickness > 0.0;
+    if !params.double_sided && !solid {
+        return (mesh, control_points);
+    }
+
+    let front_vertex_count = mesh.vertex_count();
+    let front_index_count = mesh.indices.len();
+    let rim = if solid {
+        directed_boundary_edges(&mesh)
+    } else {
+        Vec::new()
     };
 
-    // 4. Tessellate surface
-    let res = params.resolution;
+    // Duplicate vertices with flipped normals, pushed behind the front face
+    // for thick petals
+    for i in 0..front_vertex_count {
+        let normal = -mesh.normals[i]; // Flip normal
+        let pos = if solid {
+            mesh.positions[i] + normal * params.thickness
+        } else {
+            mesh.positions[i]
+        };
+        let uv = mesh.uvs[i];
+        let color = mesh.colors[i];
+        mesh.add_vertex(pos, normal, uv, color);
+    }
+
+    // Add back face triangles (reversed winding)
+    let offset = front_vertex_count as u32;
+    for t in (0..front_index_count).step_by(3) {
+        let (i0, i1, i2) = (mesh.indices[t], mesh.indices[t + 1], mesh.indices[t + 2]);
+        mesh.add_triangle(i0 + offset, i2 + offset, i1 + offset);
+    }
+
+    // 6. Close the rim between front and back faces (thick petals only)
+    for (a, b) in rim {
+        mesh.add_quad(b, a, a + offset, b + offset);
+    }
+
+    (mesh, control_points)
+}
+
+/// Boundary edges of a consistently wound mesh, oriented as in their triangle
+///
+/// An edge `a → b` is on the boundary when no triangle uses it as `b → a`.
+fn directed_boundary_edges(mesh: &Mesh) -> Vec<(u32, u32)> {
+    use std::collections::HashSet;
+
+    let directed = |t: &[u32]| [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])];
+    let edges: HashSet<(u32, u32)> = mesh.indices.chunks_exact(3).flat_map(directed).collect();
+
+    mesh.indices
+        .chunks_exact(3)
+        .flat_map(directed)
+        .filter(|&(a, b)| !edges.contains(&(b, a)))
+        .collect()
+}
+
+/// Paint nectar guides onto a petal mesh
+///
+/// Overwrites the vertex colors of `count` evenly spaced streaks that start at
+/// the base and taper to a point at `length_frac` of the petal length. Streaks
+/// are located through the mesh UVs (u across the width, v along the length),
+/// so this works on any mesh produced by [`generate`], front and back faces alike.
+///
+/// Colors are per-vertex, so the guides are only as crisp as the tessellation:
+/// raise `resolution` (or lower `adaptive_error`) for sharper streaks.
+///
+/// # Arguments
+///
+/// * `mesh` - Petal mesh to recolor
+/// * `guide_color` - Color of the streaks
+/// * `count` - Number of streaks across the width (0 = none)
+/// * `length_frac` - How far up the petal the streaks reach, clamped to [0, 1]
+///
+/// # Example
+///
+/// ```
+/// use floraison_components::petal::{apply_nectar_guides, generate, PetalParams};
+/// use floraison_components::Vec3;
+///
+/// let mut mesh = generate(&PetalParams {
+///     resolution: 32,
+///     ..Default::default()
+/// });
+/// let purple = Vec3::new(0.4, 0.1, 0.5);
+/// apply_nectar_guides(&mut mesh, purple, 3, 0.4);
+///
+/// assert!(mesh.colors.contains(&purple));
+/// ```
+pub fn apply_nectar_guides(mesh: &mut Mesh, guide_color: Vec3, count: usize, length_frac: f32) {
+    let length_frac = length_frac.clamp(0.0, 1.0);
+
+    if count == 0 || length_frac == 0.0 {
+        return;
+    }
+
+    let spacing = 1.0 / count as f32;
+
+    for (color, uv) in mesh.colors.iter_mut().zip(&mesh.uvs) {
+        if uv.y > length_frac {
+            continue;
+        }
+
+        // Streak half-width: a quarter of the spacing at the base, tapering to 0
+        let half_width = 0.25 * spacing * (1.0 - uv.y / length_frac);
+
+        // Distance to the nearest streak center at (k + 0.5) * spacing
+        let nearest = ((uv.x / spacing - 0.5)
+            .round()
+            .clamp(0.0, (count - 1) as f32)
+            + 0.5)
+            * spacing;
+
+        if (uv.x - nearest).abs() <= half_width {
+            *color = guide_color;
+        }
+    }
+}
+
+/// Sample a surface on a regular `(res + 1) × (res + 1)` grid (front face only)
+fn tessellate_uniform(surface: &BSplineSurface, res: usize, color: Vec3) -> Mesh {
     let mut mesh = Mesh::with_capacity((res + 1) * (res + 1), res * res * 2 * 3);
 
-    // Generate front face vertices
     for i in 0..=res {
         let u = i as f32 / res as f32;
         for j in 0..=res {
             let v = j as f32 / res as f32;
 
-            let pos = surface.evaluate(u, v);
+            let pos = surface.evaluate_de_boor(u, v);
             let normal = surface.normal(u, v);
             let uv_coord = Vec2::new(u, v);
 
-            mesh.add_vertex(pos, normal, uv_coord, params.color);
+            mesh.add_vertex(pos, normal, uv_coord, color);
         }
     }
 
-    // Generate front face triangles
     for i in 0..res {
         for j in 0..res {
             let i0 = i * (res + 1) + j;
@@ -538,32 +1168,74 @@ pub fn generate(params: &PetalParams) -> Mesh {
         }
     }
 
-    // 5. Add back faces (flip normals and winding order)
-    let front_vertex_count = mesh.vertex_count();
+    mesh
+}
 
-    // Duplicate vertices with flipped normals
-    for i in 0..front_vertex_count {
-        let pos = mesh.positions[i];
-        let normal = -mesh.normals[i]; // Flip normal
-        let uv = mesh.uvs[i];
-        mesh.add_vertex(pos, normal, uv, params.color);
+/// Displace the side-edge vertices (u = 0 and u = 1) in a sawtooth pattern
+///
+/// Each edge vertex is moved along the in-surface tangent across the width
+/// (`±dS/du`, pointing out of the outline) by `depth · (2·fract(v·frequency) - 1)`,
+/// producing `frequency` teeth per edge. Interior vertices are left untouched;
+/// normals next to the teeth are recomputed from the displaced faces.
+fn serrate_edges(mesh: &mut Mesh, surface: &BSplineSurface, frequency: f32, depth: f32) {
+    const EDGE_EPSILON: f32 = 1e-6;
+
+    let mut moved = vec![false; mesh.positions.len()];
+    for ((pos, uv), moved) in mesh.positions.iter_mut().zip(&mesh.uvs).zip(&mut moved) {
+        let side = if uv.x <= EDGE_EPSILON {
+            -1.0
+        } else if uv.x >= 1.0 - EDGE_EPSILON {
+            1.0
+        } else {
+            continue;
+        };
+
+        let outward = (surface.evaluate_derivative_u(uv.x, uv.y) * side).normalize_or_zero();
+        let tooth = 2.0 * (uv.y * frequency).fract() - 1.0;
+
+        *pos += outward * depth * tooth;
+        *moved = true;
     }
 
-    // Add back face triangles (reversed winding)
-    for i in 0..res {
-        for j in 0..res {
-            let i0 = i * (res + 1) + j + front_vertex_count;
-            let i1 = i0 + 1;
-            let i2 = i0 + res + 1;
-            let i3 = i2 + 1;
+    recompute_normals_near(mesh, &moved);
+}
+
+/// Recompute the normals of moved vertices and of every vertex sharing a
+/// triangle with one, as area-weighted averages of their displaced faces
+///
+/// Normals of vertices away from the displacement keep their analytic
+/// surface values.
+fn recompute_normals_near(mesh: &mut Mesh, moved: &[bool]) {
+    let mut affected = moved.to_vec();
+    for tri in mesh.indices.chunks_exact(3) {
+        if tri.iter().any(|&k| moved[k as usize]) {
+            for &k in tri {
+                affected[k as usize] = true;
+            }
+        }
+    }
+
+    let mut sums = vec![Vec3::ZERO; mesh.positions.len()];
+    for tri in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [tri[0] as usize, tri[1] as usize, tri[2] as usize];
+        if !(affected[a] || affected[b] || affected[c]) {
+            continue;
+        }
 
-            // Reversed winding order
-            mesh.add_triangle(i0 as u32, i1 as u32, i2 as u32);
-            mesh.add_triangle(i1 as u32, i3 as u32, i2 as u32);
+        let face =
+            (mesh.positions[b] - mesh.positions[a]).cross(mesh.positions[c] - mesh.positions[a]);
+        for k in [a, b, c] {
+            sums[k] += face;
         }
     }
 
-    mesh
+    for ((normal, sum), &affected) in mesh.normals.iter_mut().zip(sums).zip(&affected) {
+        if affected {
+            if let Some(n) = sum.try_normalize() {
+                *normal = n;
+            }
+        }
+    }
 }
 
 /// Generate a petal mesh using legacy Bézier curve outline (deprecated)
@@ -633,67 +1305,8 @@ fn generate_bezier_legacy(params: &PetalParams) -> Mesh {
     // Remove duplicate points at connections
     outline.dedup_by(|a, b| (a.x - b.x).abs() < 0.0001 && (a.y - b.y).abs() < 0.0001);
 
-    // Create mesh from outline using fan triangulation
-    create_petal_mesh(&outline)
-}
-
-/// Create a mesh from a 2D outline using fan triangulation
-fn create_petal_mesh(outline: &[Vec2]) -> Mesh {
-    let mut positions = Vec::new();
-    let mut normals = Vec::new();
-    let mut uvs = Vec::new();
-    let mut colors = Vec::new();
-    let mut indices = Vec::new();
-
-    // Calculate bounding box for UV mapping
-    let min_x = outline.iter().map(|p| p.x).fold(f32::MAX, f32::min);
-    let max_x = outline.iter().map(|p| p.x).fold(f32::MIN, f32::max);
-    let min_y = outline.iter().map(|p| p.y).fold(f32::MAX, f32::min);
-    let max_y = outline.iter().map(|p| p.y).fold(f32::MIN, f32::max);
-    let width = max_x - min_x;
-    let height = max_y - min_y;
-
-    // Center point for fan triangulation
-    let center_x = (min_x + max_x) / 2.0;
-    let center_y = (min_y + max_y) / 2.0;
-    let center = Vec2::new(center_x, center_y);
-
-    // Add center vertex
-    positions.push(Vec3::new(center.x, center.y, 0.0));
-    normals.push(Vec3::new(0.0, 0.0, 1.0)); // Normal pointing up (Z+)
-    uvs.push(Vec2::new(
-        (center.x - min_x) / width,
-        (center.y - min_y) / height,
-    ));
-    colors.push(Vec3::ONE); // White default color
-
-    // Add outline vertices
-    for point in outline {
-        positions.push(Vec3::new(point.x, point.y, 0.0));
-        normals.push(Vec3::new(0.0, 0.0, 1.0));
-        uvs.push(Vec2::new(
-            (point.x - min_x) / width,
-            (point.y - min_y) / height,
-        ));
-        colors.push(Vec3::ONE); // White default color
-    }
-
-    // Create fan triangles from center
-    let outline_count = outline.len();
-    for i in 0..outline_count {
-        let next = (i + 1) % outline_count;
-        indices.push(0); // Center
-        indices.push((i + 1) as u32);
-        indices.push((next + 1) as u32);
-    }
-
-    Mesh {
-        positions,
-        normals,
-        uvs,
-        colors,
-        indices,
-    }
+    // Triangulate the (possibly concave) outline
+    tessellate_polygon(&outline, Vec3::ONE)
 }
 
 #[cfg(test)]
@@ -723,6 +1336,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_flat_petal_tangents_orthogonal() {
+        let mesh = generate(&PetalParams::default());
+        let tangents = mesh.compute_tangents();
+
+        assert_eq!(tangents.len(), mesh.vertex_count());
+        for (tangent, normal) in tangents.iter().zip(&mesh.normals) {
+            let t = tangent.truncate();
+            assert!((t.length() - 1.0).abs() < 0.01);
+            assert!(
+                t.dot(*normal).abs() < 0.01,
+                "Tangent should be orthogonal to normal"
+            );
+            assert!(tangent.w.abs() == 1.0);
+        }
+    }
+
+    #[test]
+    fn test_adaptive_flat_fewer_triangles_than_ruffled() {
+        let flat = PetalParams {
+            adaptive_error: Some(0.01),
+            ..Default::default()
+        };
+        let ruffled = PetalParams {
+            ruffle_freq: 3.0,
+            ruffle_amp: 0.3,
+            ..flat.clone()
+        };
+
+        let flat_mesh = generate(&flat);
+        let ruffled_mesh = generate(&ruffled);
+
+        assert!(
+            flat_mesh.triangle_count() < ruffled_mesh.triangle_count(),
+            "flat {} vs ruffled {}",
+            flat_mesh.triangle_count(),
+            ruffled_mesh.triangle_count()
+        );
+        assert!(ruffled_mesh.validate().is_ok());
+        assert!(ruffled_mesh.colors.iter().all(|&c| c == ruffled.color));
+    }
+
+    #[test]
+    fn test_adaptive_matches_uniform_shape() {
+        let uniform = generate(&PetalParams::default());
+        let adaptive = generate(&PetalParams {
+            adaptive_error: Some(0.005),
+            ..Default::default()
+        });
+
+        let (umin, umax) = uniform.bounding_box().unwrap();
+        let (amin, amax) = adaptive.bounding_box().unwrap();
+        assert!((umin - amin).length() < 0.05);
+        assert!((umax - amax).length() < 0.05);
+    }
+
     #[test]
     fn test_wide_petal() {
         let mesh = generate(&PetalParams::wide());
@@ -754,6 +1423,8 @@ mod tests {
             curl: 0.0,
             twist: 0.0,
             lateral_curve: 0.0,
+            droop: 0.0,
+            adaptive_error: None,
             ruffle_freq: 0.0,
             ruffle_amp: 0.0,
             ..Default::default()
@@ -781,6 +1452,20 @@ mod tests {
             curl: 0.0,
             twist: 0.0,
             lateral_curve: 0.0,
+            droop: 0.0,
+            adaptive_error: None,
+            midrib_depth: 0.0,
+            serration_freq: 0.0,
+            serration_depth: 0.0,
+            asymmetry: 0.0,
+            tip_notch_depth: 0.0,
+            tip_lobes: 1,
+            tip_lobe_depth: 0.5,
+            color_tip: None,
+            cup: 0.0,
+            reflex: 0.0,
+            double_sided: true,
+            thickness: 0.0,
             ruffle_freq: 0.0,
             ruffle_amp: 0.0,
             resolution: 16,
@@ -880,6 +1565,20 @@ mod tests {
             curl: 0.0,
             twist: 0.0,
             lateral_curve: 0.0,
+            droop: 0.0,
+            adaptive_error: None,
+            midrib_depth: 0.0,
+            serration_freq: 0.0,
+            serration_depth: 0.0,
+            asymmetry: 0.0,
+            tip_notch_depth: 0.0,
+            tip_lobes: 1,
+            tip_lobe_depth: 0.5,
+            color_tip: None,
+            cup: 0.0,
+            reflex: 0.0,
+            double_sided: true,
+            thickness: 0.0,
             ruffle_freq: 0.0,
             ruffle_amp: 0.0,
             resolution: 16,
@@ -894,6 +1593,20 @@ mod tests {
             curl: 0.0,
             twist: 0.0,
             lateral_curve: 0.0,
+            droop: 0.0,
+            adaptive_error: None,
+            midrib_depth: 0.0,
+            serration_freq: 0.0,
+            serration_depth: 0.0,
+            asymmetry: 0.0,
+            tip_notch_depth: 0.0,
+            tip_lobes: 1,
+            tip_lobe_depth: 0.5,
+            color_tip: None,
+            cup: 0.0,
+            reflex: 0.0,
+            double_sided: true,
+            thickness: 0.0,
             ruffle_freq: 0.0,
             ruffle_amp: 0.0,
             resolution: 16,
@@ -959,6 +1672,20 @@ mod tests {
             curl: 0.0,
             twist: 0.0,
             lateral_curve: 0.0,
+            droop: 0.0,
+            adaptive_error: None,
+            midrib_depth: 0.0,
+            serration_freq: 0.0,
+            serration_depth: 0.0,
+            asymmetry: 0.0,
+            tip_notch_depth: 0.0,
+            tip_lobes: 1,
+            tip_lobe_depth: 0.5,
+            color_tip: None,
+            cup: 0.0,
+            reflex: 0.0,
+            double_sided: true,
+            thickness: 0.0,
             ruffle_freq: 0.0,
             ruffle_amp: 0.0,
             resolution: 16,
@@ -997,6 +1724,20 @@ mod tests {
             curl: 0.0,
             twist: 0.0,
             lateral_curve: 0.0,
+            droop: 0.0,
+            adaptive_error: None,
+            midrib_depth: 0.0,
+            serration_freq: 0.0,
+            serration_depth: 0.0,
+            asymmetry: 0.0,
+            tip_notch_depth: 0.0,
+            tip_lobes: 1,
+            tip_lobe_depth: 0.5,
+            color_tip: None,
+            cup: 0.0,
+            reflex: 0.0,
+            double_sided: true,
+            thickness: 0.0,
             ruffle_freq: 0.0,
             ruffle_amp: 0.0,
             resolution: 16,
@@ -1053,6 +1794,144 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_control_grid_asymmetric() {
+        let params = PetalParams {
+            asymmetry: 0.3,
+            ..Default::default()
+        };
+        let grid = generate_control_grid(&params);
+
+        // Right half is wider than the left; the midline stays at x=0
+        for row in &grid {
+            assert!(row[4].x + row[0].x > 0.01);
+            assert!(row[2].x.abs() < 1e-6);
+        }
+
+        // Negative asymmetry mirrors the skew
+        let mirrored = generate_control_grid(&PetalParams {
+            asymmetry: -0.3,
+            ..Default::default()
+        });
+        for (row, mirror) in grid.iter().zip(&mirrored) {
+            assert!((row[4].x + mirror[0].x).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_control_grid_asymmetry_clamped() {
+        for asymmetry in [-1.5, 1.5] {
+            let grid = generate_control_grid(&PetalParams {
+                asymmetry,
+                ..Default::default()
+            });
+            assert_eq!(
+                grid,
+                generate_control_grid(&PetalParams {
+                    asymmetry: asymmetry.signum(),
+                    ..Default::default()
+                })
+            );
+
+            // Neither half crosses the midline
+            for row in &grid {
+                let cols = row.len();
+                for (col, point) in row.iter().enumerate() {
+                    if 2 * col < cols - 1 {
+                        assert!(point.x <= 0.0, "{}: left {:?}", asymmetry, point);
+                    } else {
+                        assert!(point.x >= 0.0, "{}: right {:?}", asymmetry, point);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_control_grid_tip_notch() {
+        let params = PetalParams {
+            tip_notch_depth: 0.5,
+            ..Default::default()
+        };
+        let grid = generate_control_grid(&params);
+
+        let max_y = |col: usize| grid.iter().map(|row| row[col].y).fold(f32::MIN, f32::max);
+        assert!(max_y(2) < max_y(0));
+        assert!(max_y(2) < max_y(4));
+
+        // Lower half is untouched
+        let plain = generate_control_grid(&PetalParams::default());
+        assert_eq!(grid[..5], plain[..5]);
+
+        // Even the deepest notch keeps every column monotonic along the length
+        let deepest = generate_control_grid(&PetalParams {
+            tip_notch_depth: 1.0,
+            ..Default::default()
+        });
+        for col in 0..5 {
+            for rows in deepest.windows(2) {
+                assert!(rows[1][col].y > rows[0][col].y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tip_lobes() {
+        let single = generate_control_grid(&PetalParams {
+            tip_lobes: 1,
+            ..Default::default()
+        });
+        assert_eq!(single, generate_control_grid(&PetalParams::default()));
+
+        let params = PetalParams {
+            width: 2.0,
+            tip_sharpness: 0.9,
+            tip_lobes: 3,
+            resolution: 48,
+            ..Default::default()
+        };
+        let (mesh, grid) = generate_with_grid(&params);
+        assert_eq!(grid[0].len(), 13);
+
+        // Tip edge of the front face: vertex (i, res) for each u sample i
+        let res = params.resolution;
+        let tip_y: Vec<f32> = (0..=res)
+            .map(|i| mesh.positions[i * (res + 1) + res].y)
+            .collect();
+        let maxima = tip_y
+            .windows(3)
+            .filter(|w| w[1] > w[0] && w[1] > w[2])
+            .count();
+        assert_eq!(maxima, 3, "tip edge: {:?}", tip_y);
+    }
+
+    #[test]
+    fn test_tip_lobe_depth_bounded() {
+        let lobed = |width: f32, tip_lobe_depth: f32| {
+            generate_control_grid(&PetalParams {
+                width,
+                tip_lobes: 4,
+                tip_lobe_depth,
+                ..Default::default()
+            })
+        };
+
+        // Out-of-range depths are clamped
+        assert_eq!(lobed(1.0, 5.0), lobed(1.0, 1.0));
+        assert_eq!(lobed(1.0, -1.0), lobed(1.0, 0.0));
+
+        // Even a very wide petal at full depth keeps its tip above the
+        // widest point and every lobe width within the unlobed outline
+        let length = PetalParams::default().length;
+        let plain = lobed(20.0, 0.0);
+        for (row, plain_row) in lobed(20.0, 1.0).iter().zip(&plain).skip(5) {
+            for (p, q) in row.iter().zip(plain_row) {
+                assert!(p.y >= 0.6 * length - 1e-5, "{:?}", p);
+                assert!(p.x.abs() <= q.x.abs() + 1e-5, "{:?} vs {:?}", p, q);
+            }
+        }
+    }
+
     #[test]
     fn test_control_grid_all_finite() {
         let params = PetalParams::default();
@@ -1201,6 +2080,401 @@ mod tests {
         assert_eq!(grid[0].len(), 5);
     }
 
+    #[test]
+    fn test_generate_with_grid() {
+        let params = PetalParams {
+            curl: 0.4,
+            twist: 20.0,
+            ..Default::default()
+        };
+
+        let (mesh, grid) = generate_with_grid(&params);
+
+        // Mesh is identical to plain generation
+        let plain = generate(&params);
+        assert_eq!(mesh.positions, plain.positions);
+        assert_eq!(mesh.indices, plain.indices);
+
+        // Grid reflects the applied deformations
+        let mut expected = generate_control_grid(&params);
+        apply_curl(&mut expected, params.curl);
+        apply_twist(&mut expected, params.twist);
+        assert_eq!(grid, expected);
+    }
+
+    #[test]
+    fn test_apply_droop_pivots_at_base() {
+        let params = PetalParams::default();
+        let original = generate_control_grid(&params);
+        let mut grid = original.clone();
+
+        apply_droop(&mut grid, 0.5);
+
+        // Base row stays attached
+        for (a, b) in grid[0].iter().zip(original[0].iter()) {
+            assert!((*a - *b).length() < 1e-5);
+        }
+
+        // Rigid rotation: distances from the base are preserved
+        for (row, orig_row) in grid.iter().zip(original.iter()) {
+            for (a, b) in row.iter().zip(orig_row.iter()) {
+                assert!((a.length() - b.length()).abs() < 1e-4);
+            }
+        }
+
+        // Tip moved down (-Z) by 45°
+        let tip = grid[8][2];
+        assert!(tip.z < 0.0);
+        assert!((tip.z + tip.y).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_droop_differs_from_curl() {
+        let mut drooped = generate_control_grid(&PetalParams::default());
+        let mut curled = drooped.clone();
+        apply_droop(&mut drooped, 0.3);
+        apply_curl(&mut curled, -0.6);
+
+        // Curl leaves the lower half nearly untouched, droop tilts it already
+        let mid = 3;
+        assert!(drooped[mid][2].z < curled[mid][2].z - 0.1);
+    }
+
+    #[test]
+    fn test_generate_with_droop() {
+        let params = PetalParams {
+            droop: 0.6,
+            ..Default::default()
+        };
+        let mesh = generate(&params);
+
+        let min_z = mesh.positions.iter().map(|p| p.z).fold(f32::MAX, f32::min);
+        assert!(
+            min_z < -1.0,
+            "Drooped petal should hang below base, got {}",
+            min_z
+        );
+    }
+
+    #[test]
+    fn test_apply_midrib_raises_center() {
+        let params = PetalParams::default();
+        let mut grid = generate_control_grid(&params);
+
+        apply_midrib(&mut grid, 0.2);
+
+        // Center column is lifted more than the edges along the blade
+        for row in &grid[1..8] {
+            assert!(row[2].z.abs() > row[0].z.abs());
+            assert!(row[2].z.abs() > row[4].z.abs());
+        }
+
+        // Peak is mid-length, base and tip stay flat
+        assert!((grid[4][2].z - 0.2).abs() < 1e-5);
+        assert!(grid[0][2].z.abs() < 1e-5);
+        assert!(grid[8][2].z.abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_apply_midrib_zero_depth() {
+        let original = generate_control_grid(&PetalParams::default());
+        let mut grid = original.clone();
+
+        apply_midrib(&mut grid, 0.0);
+
+        assert_eq!(grid, original);
+    }
+
+    #[test]
+    fn test_serration_only_moves_edges() {
+        let res = 16;
+        let plain = PetalParams {
+            resolution: res,
+            ..Default::default()
+        };
+        let serrated = PetalParams {
+            serration_freq: 4.0,
+            serration_depth: 0.05,
+            ..plain.clone()
+        };
+
+        let plain_mesh = generate(&plain);
+        let serrated_mesh = generate(&serrated);
+        assert_eq!(plain_mesh.vertex_count(), serrated_mesh.vertex_count());
+
+        // Front-face vertex (i, j) lives at i * (res + 1) + j, with i along u
+        let index = |i: usize, j: usize| i * (res + 1) + j;
+
+        // Center column is untouched
+        for j in 0..=res {
+            let k = index(res / 2, j);
+            assert_eq!(serrated_mesh.positions[k], plain_mesh.positions[k]);
+        }
+
+        // Edge vertices move both outward and inward (flat petal: radial = |x|)
+        for i in [0, res] {
+            let offsets: Vec<f32> = (0..=res)
+                .map(|j| {
+                    let k = index(i, j);
+                    serrated_mesh.positions[k].x.abs() - plain_mesh.positions[k].x.abs()
+                })
+                .collect();
+
+            assert!(offsets.iter().any(|&d| d > 0.01), "{:?}", offsets);
+            assert!(offsets.iter().any(|&d| d < -0.01), "{:?}", offsets);
+            assert!(offsets.iter().all(|&d| d.abs() <= 0.05 + 1e-4));
+
+            // Sawtooth drops back inward once per tooth
+            let drops = offsets.windows(2).filter(|w| w[1] < w[0]).count();
+            assert!(drops >= 3, "expected alternating teeth, got {:?}", offsets);
+        }
+    }
+
+    #[test]
+    fn test_serration_recomputes_edge_normals() {
+        let res = 16;
+        let params = PetalParams {
+            resolution: res,
+            lateral_curve: 0.6,
+            double_sided: false,
+            serration_freq: 4.0,
+            serration_depth: 0.05,
+            ..Default::default()
+        };
+
+        let mesh = generate(&params);
+        let mut expected = mesh.clone();
+        expected.compute_normals();
+
+        let index = |i: usize, j: usize| i * (res + 1) + j;
+        for i in [0, 1, res - 1, res] {
+            for j in 0..=res {
+                let k = index(i, j);
+                assert!(
+                    mesh.normals[k].abs_diff_eq(expected.normals[k], 1e-4),
+                    "normal {k} {:?} vs {:?}",
+                    mesh.normals[k],
+                    expected.normals[k]
+                );
+            }
+        }
+
+        // Interior keeps the analytic surface normals
+        let plain = generate(&PetalParams {
+            serration_freq: 0.0,
+            ..params
+        });
+        for j in 0..=res {
+            let k = index(res / 2, j);
+            assert_eq!(mesh.normals[k], plain.normals[k]);
+        }
+    }
+
+    #[test]
+    fn test_color_tip_gradient() {
+        let base = Vec3::new(0.6, 0.1, 0.3);
+        let tip = Vec3::new(1.0, 0.9, 0.9);
+        let mesh = generate(&PetalParams {
+            color: base,
+            color_tip: Some(tip),
+            ..Default::default()
+        });
+
+        for (color, uv) in mesh.colors.iter().zip(&mesh.uvs) {
+            if uv.y == 0.0 {
+                assert!((*color - base).length() < 1e-5);
+            } else if uv.y == 1.0 {
+                assert!((*color - tip).length() < 1e-5);
+            }
+        }
+        assert!(mesh.uvs.iter().any(|uv| uv.y == 0.0));
+        assert!(mesh.uvs.iter().any(|uv| uv.y == 1.0));
+
+        // Without a tip color the petal stays uniform
+        let uniform = generate(&PetalParams {
+            color: base,
+            ..Default::default()
+        });
+        assert!(uniform.colors.iter().all(|&c| c == base));
+    }
+
+    #[test]
+    fn test_nectar_guides() {
+        let res = 48;
+        let base = Vec3::new(1.0, 0.9, 0.2);
+        let guide = Vec3::new(0.5, 0.1, 0.1);
+        let mut mesh = generate(&PetalParams {
+            resolution: res,
+            color: base,
+            ..Default::default()
+        });
+
+        apply_nectar_guides(&mut mesh, guide, 3, 0.4);
+
+        // Front-face vertex (i, j) at i * (res + 1) + j; i = 8 is u = 1/6,
+        // the center of the first of three streaks
+        for j in 1..=4 {
+            assert_eq!(mesh.colors[8 * (res + 1) + j], guide);
+        }
+
+        // Between streaks and above the guide length, the base color remains
+        assert_eq!(mesh.colors[16 * (res + 1) + 1], base);
+        for (color, uv) in mesh.colors.iter().zip(&mesh.uvs) {
+            if uv.y > 0.4 {
+                assert_eq!(*color, base);
+            }
+        }
+    }
+
+    #[test]
+    fn test_nectar_guides_clamp_length() {
+        let base = Vec3::new(1.0, 0.9, 0.2);
+        let guide = Vec3::new(0.5, 0.1, 0.1);
+        let plain = generate(&PetalParams {
+            color: base,
+            ..Default::default()
+        });
+        let guided = |length_frac: f32| {
+            let mut mesh = plain.clone();
+            apply_nectar_guides(&mut mesh, guide, 3, length_frac);
+            mesh.colors
+        };
+
+        assert_eq!(guided(1.5), guided(1.0));
+        assert_eq!(guided(-0.5), plain.colors);
+    }
+
+    #[test]
+    fn test_apply_cup_lifts_edges() {
+        let mut grid = generate_control_grid(&PetalParams::default());
+
+        apply_cup(&mut grid, 0.4);
+
+        // Same bowl cross-section at every row, edges above the center
+        for row in &grid {
+            assert!(row[0].z > row[1].z && row[1].z > row[2].z);
+            assert!(row[4].z > row[3].z && row[3].z > row[2].z);
+            assert!((row[0].z - grid[0][0].z).abs() < 1e-6);
+        }
+
+        let mesh = generate(&PetalParams {
+            cup: 0.4,
+            ..Default::default()
+        });
+        let max_z = mesh.positions.iter().map(|p| p.z).fold(f32::MIN, f32::max);
+        assert!(max_z > 0.3);
+    }
+
+    #[test]
+    fn test_bract_is_small_and_green() {
+        let params = PetalParams::bract();
+        let mesh = generate(&params);
+
+        assert!(mesh.validate().is_ok());
+        assert!(params.length < PetalParams::default().length / 4.0);
+        assert!(params.color.y > params.color.x && params.color.y > params.color.z);
+    }
+
+    #[test]
+    fn test_reflexed_petal() {
+        let params = PetalParams::reflexed();
+        let (mesh, grid) = generate_with_grid(&params);
+
+        // Tip swept back below the middle of the petal
+        assert!(grid[8][2].y < grid[4][2].y);
+        let max_y = mesh.positions.iter().map(|p| p.y).fold(f32::MIN, f32::max);
+        let min_y = mesh.positions.iter().map(|p| p.y).fold(f32::MAX, f32::min);
+        assert!(
+            min_y < 0.0,
+            "tip should reach behind the base, got {}",
+            min_y
+        );
+        assert!(max_y < params.length);
+        assert!(mesh.validate().is_ok());
+    }
+
+    #[test]
+    fn test_bend_is_clamped() {
+        let full = generate_with_grid(&PetalParams {
+            reflex: 1.0,
+            ..Default::default()
+        })
+        .1;
+        let excessive = generate_with_grid(&PetalParams {
+            reflex: 4.0,
+            ..Default::default()
+        })
+        .1;
+        assert_eq!(full, excessive);
+
+        // Curl down plus full reflex is capped at 180° total
+        let combined = generate_with_grid(&PetalParams {
+            curl: -1.0,
+            reflex: 1.0,
+            ..Default::default()
+        })
+        .1;
+        for (a, b) in combined.iter().flatten().zip(full.iter().flatten()) {
+            assert!((*a - *b).length() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_single_sided_halves_mesh() {
+        let double = generate(&PetalParams {
+            curl: 0.3,
+            ..Default::default()
+        });
+        let single = generate(&PetalParams {
+            curl: 0.3,
+            double_sided: false,
+            ..Default::default()
+        });
+
+        assert_eq!(single.vertex_count() * 2, double.vertex_count());
+        assert_eq!(single.triangle_count() * 2, double.triangle_count());
+        assert!(single.validate().is_ok());
+
+        // The single-sided mesh is exactly the front half
+        let front = single.vertex_count();
+        assert_eq!(single.positions, double.positions[..front]);
+        assert_eq!(single.normals, double.normals[..front]);
+        assert_eq!(single.uvs, double.uvs[..front]);
+        assert_eq!(single.indices, double.indices[..single.indices.len()]);
+    }
+
+    #[test]
+    fn test_thick_petal_is_solid() {
+        let thickness = 0.05;
+        for adaptive_error in [None, Some(0.01)] {
+            let params = PetalParams {
+                curl: 0.3,
+                thickness,
+                adaptive_error,
+                ..Default::default()
+            };
+            let mesh = generate(&params);
+            assert!(mesh.validate().is_ok());
+            assert!(mesh.is_watertight(), "rim should be closed");
+            // Every edge is matched by its reverse: walls wind consistently
+            assert!(directed_boundary_edges(&mesh).is_empty());
+
+            // Back face sits `thickness` behind the front face
+            let front = mesh.vertex_count() / 2;
+            for i in 0..front {
+                let gap = mesh.positions[i] - mesh.positions[i + front];
+                assert!((gap.length() - thickness).abs() < 1e-4);
+                assert!(gap.dot(mesh.normals[i]) > 0.0);
+            }
+        }
+
+        // Zero thickness keeps the coincident, open double-sided sheet
+        let sheet = generate(&PetalParams::default());
+        let front = sheet.vertex_count() / 2;
+        assert_eq!(sheet.positions[..front], sheet.positions[front..]);
+        assert!(!sheet.is_watertight());
+    }
+
     #[test]
     fn test_zero_deformations() {
         let params = PetalParams::default();
@@ -3,10 +3,12 @@
 //! A pistil consists of a style (elongated stalk) topped with a stigma (receptive surface).
 //! This generator creates a simple tapered cylinder for the style and a sphere for the stigma.
 
-use crate::{Mat4, Mesh, Vec2, Vec3};
+use crate::{Mat4, Mesh, Quat, Vec2, Vec3};
 use floraison_core::geometry::surface_revolution::{surface_of_revolution, uv_sphere};
 use floraison_core::geometry::sweep::sweep_tapered_cylinder;
 use floraison_core::math::curves::sample_catmull_rom_curve;
+use floraison_core::math::phyllotaxis::vogel_spiral;
+use std::f32::consts::PI;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -24,8 +26,11 @@ use serde::{Deserialize, Serialize};
 ///     tip_radius: 0.08,
 ///     stigma_radius: 0.15,
 ///     segments: 12,
+///     carpel_count: 1,
+///     stigma_lobes: 1,
 ///     color: Vec3::ONE,
 ///     style_curve: None,  // Straight style
+///     style_curvature: 0.0,
 /// };
 ///
 /// let mesh = generate(&params);
@@ -49,6 +54,21 @@ pub struct PistilParams {
     /// Number of segments around the circumference
     pub segments: usize,
 
+    /// Number of separate carpels (pistil units)
+    ///
+    /// 1 creates a single fused pistil. Larger values create an apocarpous
+    /// gynoecium (e.g. magnolia, buttercup): that many pistil units packed in a
+    /// Vogel spiral at the center, leaning slightly outward.
+    #[cfg_attr(feature = "serde", serde(default = "default_carpel_count"))]
+    pub carpel_count: usize,
+
+    /// Number of stigma lobes
+    ///
+    /// 1 creates a single rounded stigma. Larger values split the stigma head
+    /// into that many rounded lobes arranged radially (e.g. 3 for lilies).
+    #[cfg_attr(feature = "serde", serde(default = "default_stigma_lobes"))]
+    pub stigma_lobes: usize,
+
     /// Color of the pistil
     pub color: Vec3,
 
@@ -59,6 +79,24 @@ pub struct PistilParams {
     /// The curve should be specified as Catmull-Rom control points.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub style_curve: Option<Vec<Vec3>>,
+
+    /// Bend of a style without an explicit `style_curve`
+    /// (0.0 = straight, 1.0 = tip bent 90° toward +X, negative = toward -X)
+    ///
+    /// The style follows a circular arc of length `length` in the XY plane,
+    /// starting vertical at the ovary. Ignored when `style_curve` is set.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub style_curvature: f32,
+}
+
+#[cfg(feature = "serde")]
+fn default_carpel_count() -> usize {
+    1
+}
+
+#[cfg(feature = "serde")]
+fn default_stigma_lobes() -> usize {
+    1
 }
 
 impl Default for PistilParams {
@@ -70,8 +108,11 @@ impl Default for PistilParams {
             tip_radius: 0.06,
             stigma_radius: 0.12,
             segments: 12,
+            carpel_count: 1,
+            stigma_lobes: 1,
             color: Vec3::ONE,
             style_curve: None, // Straight style
+            style_curvature: 0.0,
         }
     }
 }
@@ -85,8 +126,11 @@ impl PistilParams {
             tip_radius: 0.12,
             stigma_radius: 0.2,
             segments: 12,
+            carpel_count: 1,
+            stigma_lobes: 1,
             color: Vec3::ONE,
             style_curve: None,
+            style_curvature: 0.0,
         }
     }
 
@@ -98,16 +142,25 @@ impl PistilParams {
             tip_radius: 0.04,
             stigma_radius: 0.08,
             segments: 10,
+            carpel_count: 1,
+            stigma_lobes: 1,
             color: Vec3::ONE,
             style_curve: None,
+            style_curvature: 0.0,
         }
     }
 }
 
+/// Maximum outward lean of the outermost carpel (radians)
+const CARPEL_MAX_LEAN: f32 = 0.26; // ~15°
+
 /// Generate a pistil mesh from parameters
 ///
 /// Creates a tapered style with a spherical stigma at the top.
-/// The style can be straight (using `length`) or curved (using `style_curve`).
+/// The style can be straight (using `length`), bent along an arc (using
+/// `length` and `style_curvature`) or follow an explicit `style_curve`.
+/// If `carpel_count > 1`, that many pistil units are arranged in a tight
+/// Vogel spiral around the center.
 ///
 /// # Arguments
 ///
@@ -126,6 +179,42 @@ impl PistilParams {
 /// assert!(pistil.triangle_count() > 0);
 /// ```
 pub fn generate(params: &PistilParams) -> Mesh {
+    let carpel = generate_carpel(params);
+    if params.carpel_count <= 1 {
+        return carpel;
+    }
+
+    // Spread radius so neighbouring stigmas roughly touch:
+    // Vogel spacing ≈ R·√(π/n), target spacing ≈ 2 × stigma radius
+    let count = params.carpel_count;
+    let footprint = params.stigma_radius.max(params.base_radius);
+    let spread = 2.0 * footprint * (count as f32 / PI).sqrt();
+
+    let mut mesh = Mesh::with_capacity(carpel.vertex_count() * count, carpel.indices.len() * count);
+
+    for i in 0..count {
+        let offset = vogel_spiral(i, count, spread);
+        let radial = offset.length() / spread;
+
+        // Lean outward, more at the rim (dome-like cluster)
+        let lean_axis = Vec3::new(offset.y, 0.0, -offset.x).normalize_or_zero();
+        let rotation = if lean_axis == Vec3::ZERO {
+            Quat::IDENTITY
+        } else {
+            Quat::from_axis_angle(lean_axis, radial * CARPEL_MAX_LEAN)
+        };
+
+        mesh.merge_transformed(
+            &carpel,
+            &Mat4::from_rotation_translation(rotation, Vec3::new(offset.x, 0.0, offset.y)),
+        );
+    }
+
+    mesh
+}
+
+/// Generate a single pistil unit (style + stigma)
+fn generate_carpel(params: &PistilParams) -> Mesh {
     // Generate style based on whether curve is provided
     let (mut style, tip_position) = if let Some(ref curve_points) = params.style_curve {
         // Curved style: sweep tapered cylinder along curve
@@ -149,6 +238,21 @@ pub fn generate(params: &PistilParams) -> Mesh {
         // Tip position is at the end of the curve
         let tip_pos = *sampled_curve.last().unwrap();
 
+        (style_mesh, tip_pos)
+    } else if params.style_curvature.abs() > 0.001 {
+        // Bent style: sweep tapered cylinder along a circular arc
+        let arc = style_arc(params.length, params.style_curvature, 20);
+
+        let style_mesh = sweep_tapered_cylinder(
+            params.base_radius,
+            params.tip_radius,
+            &arc,
+            params.segments,
+            params.color,
+        );
+
+        let tip_pos = *arc.last().unwrap();
+
         (style_mesh, tip_pos)
     } else {
         // Straight style: surface of revolution
@@ -163,8 +267,11 @@ pub fn generate(params: &PistilParams) -> Mesh {
         (style_mesh, tip_pos)
     };
 
-    // Create the stigma (sphere)
+    // Create the stigma (sphere, optionally lobed)
     let mut stigma = uv_sphere(params.stigma_radius, 6, params.segments, params.color);
+    if params.stigma_lobes > 1 {
+        shape_stigma_lobes(&mut stigma, params.stigma_lobes);
+    }
 
     // Position the stigma at the tip
     let stigma_position = Mat4::from_translation(tip_position);
@@ -176,6 +283,35 @@ pub fn generate(params: &PistilParams) -> Mesh {
     style
 }
 
+/// Split an origin-centered stigma sphere into `lobes` radial lobes
+///
+/// The horizontal radius at azimuth φ is scaled by
+/// `0.6 + 0.6·|cos(lobes·φ / 2)|`, which bulges out at `lobes` evenly spaced
+/// directions (starting at +X) and pinches into creases between them.
+fn shape_stigma_lobes(stigma: &mut Mesh, lobes: usize) {
+    for pos in &mut stigma.positions {
+        let azimuth = pos.z.atan2(pos.x);
+        let scale = 0.6 + 0.6 * (lobes as f32 * azimuth * 0.5).cos().abs();
+        pos.x *= scale;
+        pos.z *= scale;
+    }
+    stigma.compute_normals();
+}
+
+/// Sample a circular arc of the given length that starts at the origin
+/// heading up +Y and turns `curvature · 90°` toward +X
+fn style_arc(length: f32, curvature: f32, samples: usize) -> Vec<Vec3> {
+    let total_angle = curvature * PI * 0.5;
+    let radius = length / total_angle;
+
+    (0..=samples)
+        .map(|i| {
+            let angle = total_angle * i as f32 / samples as f32;
+            Vec3::new(radius * (1.0 - angle.cos()), radius * angle.sin(), 0.0)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -250,6 +386,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_style_curvature() {
+        let params = PistilParams {
+            style_curvature: 0.5,
+            ..Default::default()
+        };
+        let mesh = generate(&params);
+        assert!(mesh.validate().is_ok());
+
+        // Ovary ring (first ring of the style sweep) stays centered on the axis
+        let base_center = mesh.positions[..params.segments]
+            .iter()
+            .copied()
+            .sum::<Vec3>()
+            / params.segments as f32;
+        assert!(base_center.length() < 1e-4, "base at {:?}", base_center);
+
+        // Stigma (last sphere) is pushed off the axis toward +X
+        let stigma = &mesh.positions[mesh.vertex_count() - 7 * params.segments..];
+        let stigma_center = stigma.iter().copied().sum::<Vec3>() / stigma.len() as f32;
+        assert!(stigma_center.x > 0.5, "stigma at {:?}", stigma_center);
+        assert!(stigma_center.z.abs() < 1e-4);
+
+        // Style keeps its length: 45° arc of length 2 ends at r·(1 - cos, sin)
+        let radius = params.length / (PI * 0.25);
+        let expected = Vec3::new(
+            radius * (1.0 - (PI * 0.25).cos()),
+            radius * (PI * 0.25).sin(),
+            0.0,
+        );
+        assert!((stigma_center - expected).length() < 0.02);
+    }
+
+    #[test]
+    fn test_stigma_lobes() {
+        let single = generate(&PistilParams {
+            stigma_lobes: 1,
+            ..Default::default()
+        });
+        assert_eq!(
+            single.positions,
+            generate(&PistilParams::default()).positions
+        );
+
+        let params = PistilParams {
+            stigma_lobes: 3,
+            ..Default::default()
+        };
+        let mesh = generate(&params);
+        assert!(mesh.validate().is_ok());
+        assert!(mesh.normals.iter().all(|n| (n.length() - 1.0).abs() < 1e-3));
+
+        // Equator ring of the stigma sphere (ring 3 of 7, after the style rings)
+        let n = params.segments;
+        let ring_start = 2 * n + 3 * n;
+        let radii: Vec<f32> = mesh.positions[ring_start..ring_start + n]
+            .iter()
+            .map(|p| Vec2::new(p.x, p.z).length())
+            .collect();
+
+        let maxima = (0..n)
+            .filter(|&i| {
+                let prev = radii[(i + n - 1) % n];
+                let next = radii[(i + 1) % n];
+                radii[i] > prev && radii[i] > next
+            })
+            .count();
+        assert_eq!(maxima, 3, "radii around stigma: {:?}", radii);
+    }
+
     #[test]
     fn test_indices_in_bounds() {
         let mesh = generate(&PistilParams::default());
@@ -273,7 +479,10 @@ mod tests {
             tip_radius: 0.08,
             stigma_radius: 0.15,
             segments: 12,
+            carpel_count: 1,
+            stigma_lobes: 1,
             style_curve: None,
+            style_curvature: 0.0,
             color: Vec3::ONE,
         };
 
@@ -301,7 +510,10 @@ mod tests {
             tip_radius: 0.1,
             stigma_radius: 0.15,
             segments: 12,
+            carpel_count: 1,
+            stigma_lobes: 1,
             style_curve: None,
+            style_curvature: 0.0,
             color: Vec3::ONE,
         };
 
@@ -358,7 +570,10 @@ mod tests {
             tip_radius: 0.08,
             stigma_radius: 0.15,
             segments: 12,
+            carpel_count: 1,
+            stigma_lobes: 1,
             style_curve: Some(curve),
+            style_curvature: 0.0,
             color: Vec3::ONE,
         };
 
@@ -388,10 +603,50 @@ mod tests {
             tip_radius: 0.08,
             stigma_radius: 0.15,
             segments: 12,
+            carpel_count: 1,
+            stigma_lobes: 1,
             style_curve: Some(curve),
+            style_curvature: 0.0,
             color: Vec3::ONE,
         };
 
         generate(&params); // Should panic
     }
+
+    #[test]
+    fn test_single_carpel_matches_default() {
+        let params = PistilParams::default();
+        let explicit = PistilParams {
+            carpel_count: 1,
+            ..params.clone()
+        };
+        assert_eq!(generate(&params).positions, generate(&explicit).positions);
+    }
+
+    #[test]
+    fn test_multiple_carpels() {
+        let single = PistilParams::short();
+        let params = PistilParams {
+            carpel_count: 12,
+            ..single.clone()
+        };
+
+        let unit = generate(&single);
+        let mesh = generate(&params);
+
+        assert_eq!(mesh.vertex_count(), unit.vertex_count() * 12);
+        assert_eq!(mesh.triangle_count(), unit.triangle_count() * 12);
+
+        // Carpels spread out around the center instead of stacking
+        let max_radius = mesh
+            .positions
+            .iter()
+            .map(|p| (p.x * p.x + p.z * p.z).sqrt())
+            .fold(0.0f32, f32::max);
+        assert!(max_radius > single.stigma_radius * 2.0);
+
+        for pos in &mesh.positions {
+            assert!(pos.is_finite());
+        }
+    }
 }
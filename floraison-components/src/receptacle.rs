@@ -1,7 +1,8 @@
 //! Receptacle (flower base) generator
 //!
 //! The receptacle is the base structure of a flower where all other components attach.
-//! It's generated using surface of revolution with a smooth Bézier curve profile.
+//! It's generated using surface of revolution with a smooth Bézier curve profile,
+//! optionally extended by a hypanthium cup for epigynous flowers.
 
 use crate::{Mesh, Vec2, Vec3};
 use floraison_core::geometry::surface_revolution::surface_of_revolution;
@@ -29,6 +30,8 @@ use serde::{Deserialize, Serialize};
 ///     bulge_position: 0.6,
 ///     segments: 16,
 ///     profile_samples: 8,
+///     hypanthium_height: 0.0,
+///     hypanthium_radius: 0.0,
 ///     color: Vec3::ONE,
 /// };
 ///
@@ -59,6 +62,19 @@ pub struct ReceptacleParams {
     /// Number of samples along the profile curve (affects smoothness)
     pub profile_samples: usize,
 
+    /// Height of the hypanthium cup above `height` (0.0 = no hypanthium)
+    ///
+    /// Epigynous flowers (e.g. apple blossom) have a floral cup that rises
+    /// around the ovary. When positive, the profile continues from the top
+    /// of the receptacle up to `height + hypanthium_height`, flaring out to
+    /// `hypanthium_radius`, and petals attached at the top seat on its rim.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hypanthium_height: f32,
+
+    /// Radius of the hypanthium rim (used when `hypanthium_height > 0`)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hypanthium_radius: f32,
+
     /// Color of the receptacle
     pub color: Vec3,
 }
@@ -76,6 +92,8 @@ impl Default for ReceptacleParams {
             bulge_position: 0.5,
             segments: 16,
             profile_samples: 8,
+            hypanthium_height: 0.0,
+            hypanthium_radius: 0.0,
             color: Vec3::ONE,
         }
     }
@@ -92,6 +110,8 @@ impl ReceptacleParams {
             bulge_position: 0.5,
             segments: 16,
             profile_samples: 4,
+            hypanthium_height: 0.0,
+            hypanthium_radius: 0.0,
             color: Vec3::ONE,
         }
     }
@@ -106,10 +126,26 @@ impl ReceptacleParams {
             bulge_position: 0.6,
             segments: 20,
             profile_samples: 10,
+            hypanthium_height: 0.0,
+            hypanthium_radius: 0.0,
             color: Vec3::ONE,
         }
     }
 
+    /// Create an epigynous receptacle with a flared hypanthium cup (apple blossom)
+    pub fn hypanthium() -> Self {
+        Self {
+            height: 0.6,
+            base_radius: 0.2,
+            bulge_radius: 0.35,
+            top_radius: 0.3,
+            bulge_position: 0.5,
+            hypanthium_height: 0.4,
+            hypanthium_radius: 0.6,
+            ..Self::default()
+        }
+    }
+
     /// Create a concave (cup-like) receptacle
     pub fn concave() -> Self {
         Self {
@@ -120,6 +156,8 @@ impl ReceptacleParams {
             bulge_position: 0.3,
             segments: 16,
             profile_samples: 8,
+            hypanthium_height: 0.0,
+            hypanthium_radius: 0.0,
             color: Vec3::ONE,
         }
     }
@@ -166,12 +204,48 @@ pub fn generate(params: &ReceptacleParams) -> Mesh {
     let p3 = Vec2::new(params.top_radius, params.height);
 
     // Sample the Bézier curve to get profile points
-    let profile = sample_cubic_2d(p0, p1, p2, p3, params.profile_samples);
+    let mut profile = sample_cubic_2d(p0, p1, p2, p3, params.profile_samples);
+
+    // Continue up the hypanthium cup, skipping its first point (the old top)
+    if let Some([q0, q1, q2, q3]) = hypanthium_control_points(params) {
+        profile.extend(
+            sample_cubic_2d(q0, q1, q2, q3, params.profile_samples)
+                .into_iter()
+                .skip(1),
+        );
+    }
 
     // Revolve the profile around the Y-axis
     surface_of_revolution(&profile, params.segments, params.color)
 }
 
+/// Bézier control points (radius, height) of the hypanthium cup, if any
+///
+/// The cup starts at the receptacle top, rises steeply, then flares out to
+/// the rim at `(hypanthium_radius, height + hypanthium_height)`.
+pub(crate) fn hypanthium_control_points(params: &ReceptacleParams) -> Option<[Vec2; 4]> {
+    if params.hypanthium_height <= 0.0 {
+        return None;
+    }
+
+    let top = params.height;
+    let rim = top + params.hypanthium_height;
+    let flare = params.hypanthium_radius - params.top_radius;
+
+    Some([
+        Vec2::new(params.top_radius, top),
+        Vec2::new(
+            params.top_radius + flare * 0.2,
+            top + params.hypanthium_height * 0.5,
+        ),
+        Vec2::new(
+            params.hypanthium_radius,
+            top + params.hypanthium_height * 0.8,
+        ),
+        Vec2::new(params.hypanthium_radius, rim),
+    ])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -252,6 +326,8 @@ mod tests {
             bulge_position: 0.5,
             segments: 8,
             profile_samples: 4,
+            hypanthium_height: 0.0,
+            hypanthium_radius: 0.0,
             color: Vec3::ONE,
         };
 
@@ -268,6 +344,34 @@ mod tests {
         assert_eq!(mesh.triangle_count(), expected_triangles);
     }
 
+    #[test]
+    fn test_hypanthium_extends_profile() {
+        let params = ReceptacleParams::hypanthium();
+        let mesh = generate(&params);
+
+        let samples = 2 * params.profile_samples - 1;
+        assert_eq!(mesh.vertex_count(), samples * params.segments);
+
+        // Rim ring sits at the top of the cup, at the hypanthium radius
+        let rim_height = params.height + params.hypanthium_height;
+        let max_y = mesh.positions.iter().map(|p| p.y).fold(0.0f32, f32::max);
+        assert!((max_y - rim_height).abs() < 1e-5);
+        for p in mesh.positions.iter().filter(|p| p.y > rim_height - 1e-5) {
+            let radius = Vec2::new(p.x, p.z).length();
+            assert!((radius - params.hypanthium_radius).abs() < 1e-4);
+        }
+
+        // Zero height leaves the plain receptacle
+        let plain = ReceptacleParams {
+            hypanthium_height: 0.0,
+            ..params
+        };
+        assert_eq!(
+            generate(&plain).vertex_count(),
+            plain.profile_samples * plain.segments
+        );
+    }
+
     #[test]
     fn test_indices_in_bounds() {
         let mesh = generate(&ReceptacleParams::default());
@@ -53,6 +53,20 @@ pub fn default() -> PetalParams {
         ruffle_freq: 0.0,
         ruffle_amp: 0.0,
         lateral_curve: 0.0,
+        droop: 0.0,
+        adaptive_error: None,
+        midrib_depth: 0.0,
+        serration_freq: 0.0,
+        serration_depth: 0.0,
+        asymmetry: 0.0,
+        tip_notch_depth: 0.0,
+        tip_lobes: 1,
+        tip_lobe_depth: 0.5,
+        color_tip: None,
+        cup: 0.0,
+        reflex: 0.0,
+        double_sided: true,
+        thickness: 0.0,
         resolution: 16,
         color: crate::Vec3::new(0.2, 0.6, 0.2), // Green
     }
@@ -83,6 +97,20 @@ pub fn narrow() -> PetalParams {
         ruffle_freq: 0.0,
         ruffle_amp: 0.0,
         lateral_curve: 0.0,
+        droop: 0.0,
+        adaptive_error: None,
+        midrib_depth: 0.0,
+        serration_freq: 0.0,
+        serration_depth: 0.0,
+        asymmetry: 0.0,
+        tip_notch_depth: 0.0,
+        tip_lobes: 1,
+        tip_lobe_depth: 0.5,
+        color_tip: None,
+        cup: 0.0,
+        reflex: 0.0,
+        double_sided: true,
+        thickness: 0.0,
         resolution: 14,
         color: crate::Vec3::new(0.2, 0.6, 0.2), // Green
     }
@@ -113,6 +141,20 @@ pub fn wide() -> PetalParams {
         ruffle_freq: 0.0,
         ruffle_amp: 0.0,
         lateral_curve: 0.0,
+        droop: 0.0,
+        adaptive_error: None,
+        midrib_depth: 0.0,
+        serration_freq: 0.0,
+        serration_depth: 0.0,
+        asymmetry: 0.0,
+        tip_notch_depth: 0.0,
+        tip_lobes: 1,
+        tip_lobe_depth: 0.5,
+        color_tip: None,
+        cup: 0.0,
+        reflex: 0.0,
+        double_sided: true,
+        thickness: 0.0,
         resolution: 18,
         color: crate::Vec3::new(0.2, 0.6, 0.2), // Green
     }
@@ -143,6 +185,20 @@ pub fn recurved() -> PetalParams {
         ruffle_freq: 0.0,
         ruffle_amp: 0.0,
         lateral_curve: 0.0,
+        droop: 0.0,
+        adaptive_error: None,
+        midrib_depth: 0.0,
+        serration_freq: 0.0,
+        serration_depth: 0.0,
+        asymmetry: 0.0,
+        tip_notch_depth: 0.0,
+        tip_lobes: 1,
+        tip_lobe_depth: 0.5,
+        color_tip: None,
+        cup: 0.0,
+        reflex: 0.0,
+        double_sided: true,
+        thickness: 0.0,
         resolution: 16,
         color: crate::Vec3::new(0.2, 0.6, 0.2), // Green
     }
@@ -27,6 +27,7 @@ use serde::{Deserialize, Serialize};
 ///     segments: 10,
 ///     color: Vec3::ONE,
 ///     filament_curve: None,  // Straight filament
+///     anther_split: 0.0,
 /// };
 ///
 /// let mesh = generate(&params);
@@ -63,6 +64,14 @@ pub struct StamenParams {
     /// The curve should be specified as Catmull-Rom control points.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub filament_curve: Option<Vec<Vec3>>,
+
+    /// Anther dehiscence (0.0 = closed, 1.0 = fully split open)
+    ///
+    /// Opens a longitudinal slit down each side of the anther (along ±X) by
+    /// pushing the surface next to it outward, as when a mature anther
+    /// splits to release pollen.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub anther_split: f32,
 }
 
 impl Default for StamenParams {
@@ -77,6 +86,7 @@ impl Default for StamenParams {
             segments: 10,
             color: Vec3::ONE,
             filament_curve: None,
+            anther_split: 0.0,
         }
     }
 }
@@ -93,6 +103,7 @@ impl StamenParams {
             segments: 10,
             color: Vec3::ONE,
             filament_curve: None,
+            anther_split: 0.0,
         }
     }
 
@@ -107,6 +118,7 @@ impl StamenParams {
             segments: 8,
             color: Vec3::ONE,
             filament_curve: None,
+            anther_split: 0.0,
         }
     }
 
@@ -121,6 +133,7 @@ impl StamenParams {
             segments: 10,
             color: Vec3::ONE,
             filament_curve: None,
+            anther_split: 0.0,
         }
     }
 }
@@ -196,6 +209,10 @@ pub fn generate(params: &StamenParams) -> Mesh {
     let anther_scale = Mat4::from_scale(Vec3::new(scale_x, scale_y, scale_z));
     anther.transform(&anther_scale);
 
+    if params.anther_split > 0.0 {
+        split_anther(&mut anther, params);
+    }
+
     // Position the anther at the tip
     let anther_position = Mat4::from_translation(tip_position);
     anther.transform(&anther_position);
@@ -206,6 +223,34 @@ pub fn generate(params: &StamenParams) -> Mesh {
     filament
 }
 
+/// Open the two lateral slits of a closed, origin-centered anther
+///
+/// Vertices are pushed radially outward (in XZ) by up to
+/// `anther_split * anther_width / 2`, strongest on the ±X lines and fading
+/// out around the circumference and toward both ends of the anther. Normals
+/// are recomputed from the displaced surface.
+fn split_anther(anther: &mut Mesh, params: &StamenParams) {
+    let max_offset = params.anther_split * params.anther_width * 0.5;
+
+    for pos in &mut anther.positions {
+        let radial = Vec3::new(pos.x, 0.0, pos.z);
+        let radius = radial.length();
+        if radius < 1e-6 {
+            continue;
+        }
+
+        // Narrow ridge around the ±X slit lines
+        let side_weight = (pos.x / radius).powi(8);
+        // Closed at the tips of the anther
+        let along = pos.y / params.anther_length;
+        let length_weight = (1.0 - along * along).max(0.0);
+
+        *pos += radial / radius * max_offset * side_weight * length_weight;
+    }
+
+    anther.compute_normals();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -288,6 +333,81 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_anther_split() {
+        // Ellipsoid equation of the anther, centered at the filament tip
+        let ellipsoid = |params: &StamenParams, p: Vec3| {
+            let local = p - Vec3::new(0.0, params.filament_length, 0.0);
+            (local.x / params.anther_width).powi(2)
+                + (local.y / params.anther_length).powi(2)
+                + (local.z / params.anther_height).powi(2)
+        };
+        let anther_start = 2 * StamenParams::default().segments;
+
+        // Closed anther is a smooth ellipsoid
+        let closed = StamenParams::default();
+        let mesh = generate(&closed);
+        for &p in &mesh.positions[anther_start..] {
+            assert!((ellipsoid(&closed, p) - 1.0).abs() < 1e-3);
+        }
+
+        // Split anther bulges out along the slits, away from them it is unchanged
+        let open = StamenParams {
+            anther_split: 1.0,
+            ..Default::default()
+        };
+        let split_mesh = generate(&open);
+        assert_eq!(split_mesh.vertex_count(), mesh.vertex_count());
+
+        let pushed = split_mesh.positions[anther_start..]
+            .iter()
+            .filter(|&&p| ellipsoid(&open, p) > 1.2)
+            .count();
+        assert!(pushed >= 2, "both slits should open, got {}", pushed);
+        for (&a, &b) in split_mesh.positions[anther_start..]
+            .iter()
+            .zip(&mesh.positions[anther_start..])
+        {
+            let local_x = b.x.abs() / closed.anther_width;
+            if local_x < 0.1 {
+                assert!((a - b).length() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_anther_split_recomputes_normals() {
+        let anther_start = 2 * StamenParams::default().segments;
+        let anther_normals = |params: &StamenParams| {
+            let mesh = generate(params);
+            let mut anther = Mesh::new();
+            anther.positions = mesh.positions[anther_start..].to_vec();
+            anther.normals = vec![Vec3::ZERO; anther.positions.len()];
+            anther.indices = mesh
+                .indices
+                .iter()
+                .skip_while(|&&k| (k as usize) < anther_start)
+                .map(|&k| k - anther_start as u32)
+                .collect();
+            anther.compute_normals();
+            (mesh.normals[anther_start..].to_vec(), anther.normals)
+        };
+
+        let (closed, _) = anther_normals(&StamenParams::default());
+        let (split, expected) = anther_normals(&StamenParams {
+            anther_split: 1.0,
+            ..Default::default()
+        });
+
+        for (n, e) in split.iter().zip(&expected) {
+            assert!(n.abs_diff_eq(*e, 1e-5), "{:?} vs {:?}", n, e);
+        }
+        assert!(split
+            .iter()
+            .zip(&closed)
+            .any(|(s, c)| s.angle_between(*c) > 0.1));
+    }
+
     #[test]
     fn test_indices_in_bounds() {
         let mesh = generate(&StamenParams::default());
@@ -314,6 +434,7 @@ mod tests {
             segments: 10,
             color: Vec3::ONE,
             filament_curve: None,
+            anther_split: 0.0,
         };
 
         let mesh = generate(&params);
@@ -344,6 +465,7 @@ mod tests {
             segments: 12,
             color: Vec3::ONE,
             filament_curve: None,
+            anther_split: 0.0,
         };
 
         let mesh = generate(&params);
@@ -436,6 +558,7 @@ mod tests {
             segments: 10,
             color: Vec3::ONE,
             filament_curve: Some(curve),
+            anther_split: 0.0,
         };
 
         let mesh = generate(&params);
@@ -467,6 +590,7 @@ mod tests {
             segments: 10,
             color: Vec3::ONE,
             filament_curve: Some(curve),
+            anther_split: 0.0,
         };
 
         generate(&params); // Should panic
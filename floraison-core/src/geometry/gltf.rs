@@ -0,0 +1,421 @@
+//! Binary glTF (GLB) export
+//!
+//! Packs a [`Mesh`] into a self-contained `.glb` file that can be loaded
+//! directly by three.js, Blender and other glTF 2.0 consumers.
+//!
+//! The JSON chunk is written by hand to keep the core crate free of extra
+//! dependencies. The output contains a single scene with one node, one mesh
+//! and one `TRIANGLES` primitive using a default white material, so vertex
+//! colors (`COLOR_0`) show through unchanged.
+
+use crate::geometry::mesh::Mesh;
+use crate::{Vec2, Vec3};
+use std::fmt::Write;
+
+/// GLB header magic (`glTF` in little-endian ASCII)
+const GLB_MAGIC: u32 = 0x4654_6C67;
+
+/// GLB container version
+const GLB_VERSION: u32 = 2;
+
+/// Chunk type for the JSON chunk (`JSON`)
+const CHUNK_JSON: u32 = 0x4E4F_534A;
+
+/// Chunk type for the binary buffer chunk (`BIN\0`)
+const CHUNK_BIN: u32 = 0x004E_4942;
+
+/// glTF component type for `f32`
+const COMPONENT_FLOAT: u32 = 5126;
+
+/// glTF component type for `u32`
+const COMPONENT_UNSIGNED_INT: u32 = 5125;
+
+/// Buffer view target for vertex attributes
+const TARGET_ARRAY_BUFFER: u32 = 34962;
+
+/// Buffer view target for indices
+const TARGET_ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+/// Convert a mesh to a binary glTF (GLB) file
+///
+/// Positions, normals, UVs and colors are written as `POSITION`, `NORMAL`,
+/// `TEXCOORD_0` and `COLOR_0`; indices as `u32`. The `POSITION` accessor carries
+/// the min/max bounds required by the glTF specification.
+///
+/// An empty mesh produces a valid file containing a scene with no meshes, and
+/// a mesh without indices is written as a non-indexed primitive.
+///
+/// Values that glTF can't represent are sanitized on the way out: non-finite
+/// components are written as 0, and normals are rescaled to unit length, with
+/// zero normals replaced by +Y.
+///
+/// # Arguments
+/// * `mesh` - The mesh to export
+///
+/// # Returns
+/// The bytes of a `.glb` file
+///
+/// # Example
+/// ```
+/// use floraison_core::geometry::gltf::mesh_to_glb;
+/// use floraison_core::geometry::surface_revolution::uv_sphere;
+/// use floraison_core::Vec3;
+///
+/// let mesh = uv_sphere(1.0, 8, 12, Vec3::ONE);
+/// let glb = mesh_to_glb(&mesh);
+///
+/// assert_eq!(&glb[0..4], b"glTF");
+/// assert_eq!(glb.len() % 4, 0);
+/// ```
+pub fn mesh_to_glb(mesh: &Mesh) -> Vec<u8> {
+    let (json, bin) = if mesh.is_empty() {
+        (empty_json(), Vec::new())
+    } else {
+        let mesh = sanitized(mesh);
+        let bin = pack_buffer(&mesh);
+        (mesh_json(&mesh, bin.len()), bin)
+    };
+
+    let mut json = json.into_bytes();
+    pad_to_4(&mut json, b' ');
+    let mut bin = bin;
+    pad_to_4(&mut bin, 0);
+
+    let bin_chunk_len = if bin.is_empty() { 0 } else { 8 + bin.len() };
+    let total_len = 12 + 8 + json.len() + bin_chunk_len;
+
+    let mut glb = Vec::with_capacity(total_len);
+    glb.extend_from_slice(&GLB_MAGIC.to_le_bytes());
+    glb.extend_from_slice(&GLB_VERSION.to_le_bytes());
+    glb.extend_from_slice(&(total_len as u32).to_le_bytes());
+
+    glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+    glb.extend_from_slice(&CHUNK_JSON.to_le_bytes());
+    glb.extend_from_slice(&json);
+
+    if !bin.is_empty() {
+        glb.extend_from_slice(&(bin.len() as u32).to_le_bytes());
+        glb.extend_from_slice(&CHUNK_BIN.to_le_bytes());
+        glb.extend_from_slice(&bin);
+    }
+
+    glb
+}
+
+/// Pad a chunk to a 4-byte boundary as required by the GLB container
+fn pad_to_4(bytes: &mut Vec<u8>, fill: u8) {
+    while !bytes.len().is_multiple_of(4) {
+        bytes.push(fill);
+    }
+}
+
+/// Copy of a mesh with only finite components and unit normals
+///
+/// Non-finite components become 0; normals that can't be normalized (zero
+/// length after that) become +Y, matching [`Mesh::compute_normals`].
+fn sanitized(mesh: &Mesh) -> Mesh {
+    let finite = |c: f32| if c.is_finite() { c } else { 0.0 };
+    let finite3 = |v: &Vec3| Vec3::from_array(v.to_array().map(finite));
+
+    Mesh {
+        positions: mesh.positions.iter().map(finite3).collect(),
+        normals: mesh
+            .normals
+            .iter()
+            .map(|n| finite3(n).try_normalize().unwrap_or(Vec3::Y))
+            .collect(),
+        uvs: mesh
+            .uvs
+            .iter()
+            .map(|uv| Vec2::from_array(uv.to_array().map(finite)))
+            .collect(),
+        colors: mesh.colors.iter().map(finite3).collect(),
+        indices: mesh.indices.clone(),
+    }
+}
+
+/// Write all vertex attributes followed by indices into one binary buffer
+///
+/// Every section is a multiple of 4 bytes, so no inter-section padding is needed.
+fn pack_buffer(mesh: &Mesh) -> Vec<u8> {
+    let n = mesh.vertex_count();
+    let mut bin = Vec::with_capacity(n * 44 + mesh.indices.len() * 4);
+
+    for v in mesh
+        .positions
+        .iter()
+        .chain(&mesh.normals)
+        .chain(&mesh.colors)
+    {
+        // Positions, normals and colors are laid out back to back
+        for c in v.to_array() {
+            bin.extend_from_slice(&c.to_le_bytes());
+        }
+    }
+    for uv in &mesh.uvs {
+        for c in uv.to_array() {
+            bin.extend_from_slice(&c.to_le_bytes());
+        }
+    }
+    for i in &mesh.indices {
+        bin.extend_from_slice(&i.to_le_bytes());
+    }
+
+    bin
+}
+
+/// JSON chunk for a file without geometry
+fn empty_json() -> String {
+    r#"{"asset":{"version":"2.0","generator":"floraison"},"scene":0,"scenes":[{"nodes":[]}]}"#
+        .to_string()
+}
+
+/// JSON chunk describing the packed buffer from [`pack_buffer`]
+///
+/// Without indices the index buffer view and accessor are left out, since
+/// glTF forbids zero-length buffer views.
+fn mesh_json(mesh: &Mesh, buffer_len: usize) -> String {
+    let n = mesh.vertex_count();
+    let vec3_len = n * 12;
+    let uv_len = n * 8;
+    let index_len = mesh.indices.len() * 4;
+    let indexed = index_len > 0;
+
+    let (min, max) = mesh
+        .bounding_box()
+        .expect("mesh_json is only called for non-empty meshes");
+
+    let mut json = String::new();
+    json.push_str(r#"{"asset":{"version":"2.0","generator":"floraison"},"#);
+    json.push_str(r#""scene":0,"scenes":[{"nodes":[0]}],"nodes":[{"mesh":0}],"#);
+    json.push_str(
+        r#""meshes":[{"primitives":[{"attributes":{"POSITION":0,"NORMAL":1,"COLOR_0":2,"TEXCOORD_0":3},"#,
+    );
+    if indexed {
+        json.push_str(r#""indices":4,"#);
+    }
+    json.push_str(r#""material":0,"mode":4}]}],"#);
+    json.push_str(
+        r#""materials":[{"pbrMetallicRoughness":{"baseColorFactor":[1,1,1,1],"metallicFactor":0,"roughnessFactor":1},"doubleSided":true}],"#,
+    );
+
+    // Buffer and views: positions, normals, colors, uvs, indices
+    let _ = write!(json, r#""buffers":[{{"byteLength":{}}}],"#, buffer_len);
+    json.push_str(r#""bufferViews":["#);
+    let views = [
+        (0, vec3_len, TARGET_ARRAY_BUFFER),
+        (vec3_len, vec3_len, TARGET_ARRAY_BUFFER),
+        (vec3_len * 2, vec3_len, TARGET_ARRAY_BUFFER),
+        (vec3_len * 3, uv_len, TARGET_ARRAY_BUFFER),
+        (
+            vec3_len * 3 + uv_len,
+            index_len,
+            TARGET_ELEMENT_ARRAY_BUFFER,
+        ),
+    ];
+    let views = if indexed { &views[..] } else { &views[..4] };
+    for (i, (offset, len, target)) in views.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        let _ = write!(
+            json,
+            r#"{{"buffer":0,"byteOffset":{},"byteLength":{},"target":{}}}"#,
+            offset, len, target
+        );
+    }
+    json.push_str("],");
+
+    // Accessors
+    let _ = write!(
+        json,
+        r#""accessors":[{{"bufferView":0,"componentType":{},"count":{},"type":"VEC3","min":[{},{},{}],"max":[{},{},{}]}},"#,
+        COMPONENT_FLOAT, n, min.x, min.y, min.z, max.x, max.y, max.z
+    );
+    let _ = write!(
+        json,
+        r#"{{"bufferView":1,"componentType":{},"count":{},"type":"VEC3"}},"#,
+        COMPONENT_FLOAT, n
+    );
+    let _ = write!(
+        json,
+        r#"{{"bufferView":2,"componentType":{},"count":{},"type":"VEC3"}},"#,
+        COMPONENT_FLOAT, n
+    );
+    let _ = write!(
+        json,
+        r#"{{"bufferView":3,"componentType":{},"count":{},"type":"VEC2"}}"#,
+        COMPONENT_FLOAT, n
+    );
+    if indexed {
+        let _ = write!(
+            json,
+            r#",{{"bufferView":4,"componentType":{},"count":{},"type":"SCALAR"}}"#,
+            COMPONENT_UNSIGNED_INT,
+            mesh.indices.len()
+        );
+    }
+    json.push_str("]}");
+
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::surface_revolution::uv_sphere;
+
+    fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    /// Split a GLB into (json, bin) chunks
+    fn split_chunks(glb: &[u8]) -> (serde_json::Value, &[u8]) {
+        let json_len = read_u32(glb, 12) as usize;
+        assert_eq!(read_u32(glb, 16), CHUNK_JSON);
+        let json_bytes = &glb[20..20 + json_len];
+        let json = serde_json::from_slice(json_bytes).expect("JSON chunk should parse");
+
+        let bin_start = 20 + json_len;
+        if bin_start == glb.len() {
+            return (json, &[]);
+        }
+        let bin_len = read_u32(glb, bin_start) as usize;
+        assert_eq!(read_u32(glb, bin_start + 4), CHUNK_BIN);
+        (json, &glb[bin_start + 8..bin_start + 8 + bin_len])
+    }
+
+    fn create_triangle() -> Mesh {
+        let mut mesh = Mesh::new();
+        let v0 = mesh.add_vertex(Vec3::new(-1.0, 0.0, 0.0), Vec3::Z, Vec2::ZERO, Vec3::X);
+        let v1 = mesh.add_vertex(Vec3::new(1.0, 0.0, 0.0), Vec3::Z, Vec2::X, Vec3::Y);
+        let v2 = mesh.add_vertex(Vec3::new(0.0, 2.0, 0.5), Vec3::Z, Vec2::Y, Vec3::Z);
+        mesh.add_triangle(v0, v1, v2);
+        mesh
+    }
+
+    #[test]
+    fn test_glb_header() {
+        let glb = mesh_to_glb(&create_triangle());
+
+        assert_eq!(&glb[0..4], b"glTF");
+        assert_eq!(read_u32(&glb, 4), 2);
+        assert_eq!(read_u32(&glb, 8) as usize, glb.len());
+        assert_eq!(glb.len() % 4, 0);
+    }
+
+    #[test]
+    fn test_json_chunk_parses() {
+        let mesh = uv_sphere(1.0, 6, 8, Vec3::ONE);
+        let glb = mesh_to_glb(&mesh);
+        let (json, bin) = split_chunks(&glb);
+
+        assert_eq!(json["asset"]["version"], "2.0");
+        assert_eq!(json["meshes"].as_array().unwrap().len(), 1);
+
+        let primitive = &json["meshes"][0]["primitives"][0];
+        assert_eq!(primitive["mode"], 4);
+        assert!(primitive["attributes"]["COLOR_0"].is_number());
+
+        let buffer_len = json["buffers"][0]["byteLength"].as_u64().unwrap() as usize;
+        assert_eq!(buffer_len, bin.len());
+
+        let indices = &json["accessors"][primitive["indices"].as_u64().unwrap() as usize];
+        assert_eq!(
+            indices["count"].as_u64().unwrap() as usize,
+            mesh.indices.len()
+        );
+    }
+
+    #[test]
+    fn test_position_min_max() {
+        let glb = mesh_to_glb(&create_triangle());
+        let (json, _) = split_chunks(&glb);
+
+        let position = &json["accessors"][0];
+        let min: Vec<f64> = serde_json::from_value(position["min"].clone()).unwrap();
+        let max: Vec<f64> = serde_json::from_value(position["max"].clone()).unwrap();
+
+        assert_eq!(min, vec![-1.0, 0.0, 0.0]);
+        assert_eq!(max, vec![1.0, 2.0, 0.5]);
+    }
+
+    #[test]
+    fn test_buffer_contents() {
+        let mesh = create_triangle();
+        let glb = mesh_to_glb(&mesh);
+        let (json, bin) = split_chunks(&glb);
+
+        // Second position's x coordinate
+        let read_f32 =
+            |offset: usize| f32::from_le_bytes(bin[offset..offset + 4].try_into().unwrap());
+        assert_eq!(read_f32(12), 1.0);
+
+        // Indices round-trip
+        let view = &json["bufferViews"][4];
+        let offset = view["byteOffset"].as_u64().unwrap() as usize;
+        let indices: Vec<u32> = (0..3).map(|i| read_u32(bin, offset + i * 4)).collect();
+        assert_eq!(indices, mesh.indices);
+    }
+
+    #[test]
+    fn test_empty_mesh() {
+        let glb = mesh_to_glb(&Mesh::new());
+        let (json, bin) = split_chunks(&glb);
+
+        assert_eq!(&glb[0..4], b"glTF");
+        assert!(json.get("meshes").is_none());
+        assert!(bin.is_empty());
+    }
+
+    #[test]
+    fn test_mesh_without_indices() {
+        let mut mesh = create_triangle();
+        mesh.indices.clear();
+        let glb = mesh_to_glb(&mesh);
+        let (json, bin) = split_chunks(&glb);
+
+        assert!(json["meshes"][0]["primitives"][0].get("indices").is_none());
+        assert_eq!(json["accessors"].as_array().unwrap().len(), 4);
+
+        let views = json["bufferViews"].as_array().unwrap();
+        assert_eq!(views.len(), 4);
+        assert!(views.iter().all(|v| v["byteLength"].as_u64().unwrap() > 0));
+        assert_eq!(
+            json["buffers"][0]["byteLength"].as_u64().unwrap() as usize,
+            bin.len()
+        );
+    }
+
+    #[test]
+    fn test_sanitizes_non_finite_values_and_normals() {
+        let mut mesh = create_triangle();
+        mesh.positions[0].x = f32::NAN;
+        mesh.positions[1].y = f32::INFINITY;
+        mesh.uvs[2].x = f32::NEG_INFINITY;
+        mesh.normals[0] = Vec3::ZERO;
+        mesh.normals[1] = Vec3::new(0.0, 0.0, 3.0);
+        mesh.normals[2] = Vec3::new(f32::NAN, 0.0, 0.0);
+
+        let glb = mesh_to_glb(&mesh);
+        // A NaN or infinity in min/max would make the JSON unparsable
+        let (json, bin) = split_chunks(&glb);
+
+        let position = &json["accessors"][0];
+        let min: Vec<f64> = serde_json::from_value(position["min"].clone()).unwrap();
+        let max: Vec<f64> = serde_json::from_value(position["max"].clone()).unwrap();
+        assert_eq!(min, vec![0.0, 0.0, 0.0]);
+        assert_eq!(max, vec![1.0, 2.0, 0.5]);
+
+        let floats: Vec<f32> = bin[..mesh.vertex_count() * 44]
+            .chunks_exact(4)
+            .map(|c| f32::from_le_bytes(c.try_into().unwrap()))
+            .collect();
+        assert!(floats.iter().all(|c| c.is_finite()));
+
+        let normal = |i: usize| Vec3::from_slice(&floats[9 + i * 3..12 + i * 3]);
+        assert_eq!(normal(0), Vec3::Y);
+        assert_eq!(normal(1), Vec3::Z);
+        assert_eq!(normal(2), Vec3::Y);
+    }
+}
@@ -4,11 +4,97 @@
 //! for representing triangulated 3D geometry. Meshes are built procedurally
 //! from flower components and can be merged, transformed, and exported.
 
-use crate::{Mat4, Vec2, Vec3};
+use crate::{Mat4, Vec2, Vec3, Vec4};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Integrity problem found by [`Mesh::validate`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MeshError {
+    /// A triangle index refers to a vertex that does not exist
+    IndexOutOfBounds {
+        /// The offending index value
+        index: u32,
+        /// Number of vertices in the mesh
+        vertex_count: usize,
+    },
+
+    /// Vertex attribute arrays have different lengths
+    AttributeLengthMismatch {
+        /// Number of positions
+        positions: usize,
+        /// Number of normals
+        normals: usize,
+        /// Number of UVs
+        uvs: usize,
+        /// Number of colors
+        colors: usize,
+    },
+
+    /// The index buffer does not describe whole triangles
+    IndicesNotMultipleOfThree {
+        /// Length of the index buffer
+        len: usize,
+    },
+}
+
+impl fmt::Display for MeshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeshError::IndexOutOfBounds {
+                index,
+                vertex_count,
+            } => write!(
+                f,
+                "Index {} out of bounds (vertex count: {})",
+                index, vertex_count
+            ),
+            MeshError::AttributeLengthMismatch {
+                positions,
+                normals,
+                uvs,
+                colors,
+            } => write!(
+                f,
+                "Attribute lengths differ (positions: {}, normals: {}, uvs: {}, colors: {})",
+                positions, normals, uvs, colors
+            ),
+            MeshError::IndicesNotMultipleOfThree { len } => {
+                write!(f, "Index count {} is not a multiple of 3", len)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MeshError {}
+
+/// Plane to reflect a mesh across with [`Mesh::mirror`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MirrorPlane {
+    /// XY plane (negates Z)
+    XY,
+    /// YZ plane (negates X)
+    YZ,
+    /// XZ plane (negates Y)
+    XZ,
+}
+
+impl MirrorPlane {
+    /// Component-wise scale that performs the reflection
+    fn scale(self) -> Vec3 {
+        match self {
+            MirrorPlane::XY => Vec3::new(1.0, 1.0, -1.0),
+            MirrorPlane::YZ => Vec3::new(-1.0, 1.0, 1.0),
+            MirrorPlane::XZ => Vec3::new(1.0, -1.0, 1.0),
+        }
+    }
+}
+
 /// A triangulated 3D mesh with positions, normals, UVs, and indices
 ///
 /// The mesh stores vertex attributes in separate arrays (Structure of Arrays pattern)
@@ -91,6 +177,32 @@ impl Mesh {
         }
     }
 
+    /// Reserve capacity for additional vertices and indices
+    ///
+    /// Call before merging many meshes whose total size is known in advance,
+    /// so the buffers grow once instead of reallocating repeatedly.
+    ///
+    /// # Arguments
+    /// * `vertices` - Number of vertices about to be added
+    /// * `indices` - Number of indices about to be added (triangles * 3)
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    ///
+    /// let mut mesh = Mesh::new();
+    /// mesh.reserve(100, 300);
+    /// assert!(mesh.positions.capacity() >= 100);
+    /// assert!(mesh.is_empty());
+    /// ```
+    pub fn reserve(&mut self, vertices: usize, indices: usize) {
+        self.positions.reserve(vertices);
+        self.normals.reserve(vertices);
+        self.uvs.reserve(vertices);
+        self.colors.reserve(vertices);
+        self.indices.reserve(indices);
+    }
+
     /// Add a vertex to the mesh and return its index
     ///
     /// # Arguments
@@ -233,6 +345,399 @@ impl Mesh {
             .extend(other.indices.iter().map(|&idx| idx + index_offset));
     }
 
+    /// Merge a transformed copy of another mesh into this one
+    ///
+    /// Same result as cloning `other`, calling [`Mesh::transform`] on the clone
+    /// and merging it, but vertices are transformed while being appended, so
+    /// no intermediate mesh is allocated.
+    ///
+    /// # Arguments
+    /// * `other` - The mesh to merge into this one
+    /// * `matrix` - Transformation applied to `other`'s positions (normals use
+    ///   its inverse transpose)
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Mat4, Vec3, Vec2};
+    ///
+    /// let mut tri = Mesh::new();
+    /// let v0 = tri.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v1 = tri.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v2 = tri.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// tri.add_triangle(v0, v1, v2);
+    ///
+    /// let mut mesh = tri.clone();
+    /// mesh.merge_transformed(&tri, &Mat4::from_translation(Vec3::Y));
+    /// assert_eq!(mesh.vertex_count(), 6);
+    /// assert_eq!(mesh.positions[3], Vec3::Y);
+    /// assert_eq!(mesh.indices[3..], [3, 4, 5]);
+    /// ```
+    pub fn merge_transformed(&mut self, other: &Mesh, matrix: &Mat4) {
+        let index_offset = self.positions.len() as u32;
+        let normal_matrix = matrix.inverse().transpose();
+
+        // Append transformed vertex data
+        self.positions.extend(
+            other
+                .positions
+                .iter()
+                .map(|&pos| matrix.transform_point3(pos)),
+        );
+        self.normals.extend(
+            other
+                .normals
+                .iter()
+                .map(|&normal| transform_normal(&normal_matrix, normal)),
+        );
+        self.uvs.extend_from_slice(&other.uvs);
+        self.colors.extend_from_slice(&other.colors);
+
+        // Append indices with offset
+        self.indices
+            .extend(other.indices.iter().map(|&idx| idx + index_offset));
+    }
+
+    /// Join many meshes into a single new mesh
+    ///
+    /// Equivalent to folding [`Mesh::merge`] over `meshes`, but all buffers are
+    /// sized up front so the result is allocated exactly once.
+    ///
+    /// # Arguments
+    /// * `meshes` - Meshes to join, in order
+    ///
+    /// # Returns
+    /// A new mesh containing every vertex and triangle from `meshes`
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// let mut tri = Mesh::new();
+    /// let v0 = tri.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v1 = tri.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v2 = tri.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// tri.add_triangle(v0, v1, v2);
+    ///
+    /// let joined = Mesh::join_all(&[tri.clone(), tri.clone(), tri]);
+    /// assert_eq!(joined.vertex_count(), 9);
+    /// assert_eq!(joined.triangle_count(), 3);
+    /// assert_eq!(joined.indices[6..], [6, 7, 8]);
+    /// ```
+    pub fn join_all(meshes: &[Mesh]) -> Mesh {
+        let vertex_count = meshes.iter().map(|m| m.positions.len()).sum();
+        let index_count = meshes.iter().map(|m| m.indices.len()).sum();
+
+        let mut result = Mesh::with_capacity(vertex_count, index_count);
+        for mesh in meshes {
+            result.merge(mesh);
+        }
+        result
+    }
+
+    /// Merge vertices whose positions lie within `epsilon` of each other
+    ///
+    /// Repeated [`Mesh::merge`] calls leave coincident vertices along component
+    /// seams. Welding collapses each group of nearby vertices into one, remaps
+    /// the indices, and averages the group's normals, UVs, and colors.
+    ///
+    /// Candidates are found with a spatial hash on positions quantized to
+    /// `epsilon`, so the cost stays close to linear in the vertex count.
+    /// Triangle count and winding are preserved; every index refers to a
+    /// surviving vertex.
+    ///
+    /// # Arguments
+    /// * `epsilon` - Maximum distance between vertices that are merged
+    ///
+    /// # Returns
+    /// The number of vertices removed
+    ///
+    /// # Panics
+    /// Panics if `epsilon <= 0`
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// // Two triangles sharing an edge, but with duplicated edge vertices
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let b = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let c = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_triangle(a, c, b);
+    ///
+    /// let b2 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let c2 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let d = mesh.add_vertex(Vec3::new(1.0, 0.0, 1.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_triangle(b2, c2, d);
+    ///
+    /// let removed = mesh.weld_vertices(1e-5);
+    /// assert_eq!(removed, 2);
+    /// assert_eq!(mesh.vertex_count(), 4);
+    /// assert_eq!(mesh.triangle_count(), 2);
+    /// ```
+    pub fn weld_vertices(&mut self, epsilon: f32) -> usize {
+        assert!(epsilon > 0.0, "Weld epsilon must be positive");
+
+        let cell_of = |p: Vec3| {
+            let q = (p / epsilon).floor();
+            (q.x as i64, q.y as i64, q.z as i64)
+        };
+        let epsilon_sq = epsilon * epsilon;
+
+        // Spatial hash of representative (kept) vertices, keyed by cell
+        let mut grid: HashMap<(i64, i64, i64), Vec<u32>> = HashMap::new();
+        let mut remap = Vec::with_capacity(self.positions.len());
+        let mut kept: Vec<usize> = Vec::new();
+        let mut group_size: Vec<u32> = Vec::new();
+        let mut normal_sum: Vec<Vec3> = Vec::new();
+        let mut uv_sum: Vec<Vec2> = Vec::new();
+        let mut color_sum: Vec<Vec3> = Vec::new();
+
+        for (i, &p) in self.positions.iter().enumerate() {
+            let (cx, cy, cz) = cell_of(p);
+
+            // A match within epsilon can only lie in this cell or a neighbor
+            let mut found = None;
+            'search: for dx in -1..=1 {
+                for dy in -1..=1 {
+                    for dz in -1..=1 {
+                        if let Some(candidates) = grid.get(&(cx + dx, cy + dy, cz + dz)) {
+                            for &rep in candidates {
+                                let rep_pos = self.positions[kept[rep as usize]];
+                                if rep_pos.distance_squared(p) <= epsilon_sq {
+                                    found = Some(rep);
+                                    break 'search;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            let rep = match found {
+                Some(rep) => rep,
+                None => {
+                    let rep = kept.len() as u32;
+                    kept.push(i);
+                    group_size.push(0);
+                    normal_sum.push(Vec3::ZERO);
+                    uv_sum.push(Vec2::ZERO);
+                    color_sum.push(Vec3::ZERO);
+                    grid.entry((cx, cy, cz)).or_default().push(rep);
+                    rep
+                }
+            };
+
+            let r = rep as usize;
+            group_size[r] += 1;
+            normal_sum[r] += self.normals.get(i).copied().unwrap_or(Vec3::ZERO);
+            uv_sum[r] += self.uvs.get(i).copied().unwrap_or(Vec2::ZERO);
+            color_sum[r] += self.colors.get(i).copied().unwrap_or(Vec3::ZERO);
+            remap.push(rep);
+        }
+
+        let removed = self.positions.len() - kept.len();
+        if removed == 0 {
+            return 0;
+        }
+
+        let has_normals = self.normals.len() == self.positions.len();
+        let has_uvs = self.uvs.len() == self.positions.len();
+        let has_colors = self.colors.len() == self.positions.len();
+
+        let mut positions = Vec::with_capacity(kept.len());
+        let mut normals = Vec::with_capacity(kept.len());
+        let mut uvs = Vec::with_capacity(kept.len());
+        let mut colors = Vec::with_capacity(kept.len());
+
+        for (r, &original) in kept.iter().enumerate() {
+            let count = group_size[r] as f32;
+            positions.push(self.positions[original]);
+            if has_normals {
+                // Opposing normals can cancel out; keep the first one then
+                normals.push(
+                    normal_sum[r]
+                        .try_normalize()
+                        .unwrap_or(self.normals[original]),
+                );
+            }
+            if has_uvs {
+                uvs.push(uv_sum[r] / count);
+            }
+            if has_colors {
+                colors.push(color_sum[r] / count);
+            }
+        }
+
+        self.positions = positions;
+        self.normals = normals;
+        self.uvs = uvs;
+        self.colors = colors;
+        for index in &mut self.indices {
+            *index = remap[*index as usize];
+        }
+
+        removed
+    }
+
+    /// Subdivide every triangle into four by splitting its edges at the midpoint
+    ///
+    /// New midpoint vertices are shared between the triangles on either side of
+    /// an edge, so the result stays connected. Normals are interpolated and
+    /// renormalized, UVs and colors are averaged. Positions are not moved, so
+    /// this refines shading and gives later deformations more vertices to work
+    /// with rather than changing the shape.
+    ///
+    /// Each iteration multiplies the triangle count by four.
+    ///
+    /// # Arguments
+    /// * `iterations` - Number of subdivision passes (0 leaves the mesh unchanged)
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// let mut mesh = Mesh::new();
+    /// let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v1 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v2 = mesh.add_vertex(Vec3::new(1.0, 0.0, 1.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v3 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_quad(v0, v1, v2, v3);
+    ///
+    /// mesh.subdivide_midpoint(1);
+    ///
+    /// assert_eq!(mesh.triangle_count(), 8);
+    /// // 4 corners + 5 edge midpoints (the diagonal is shared)
+    /// assert_eq!(mesh.vertex_count(), 9);
+    /// ```
+    pub fn subdivide_midpoint(&mut self, iterations: usize) {
+        for _ in 0..iterations {
+            let mut midpoints: HashMap<(u32, u32), u32> = HashMap::new();
+            let mut indices = Vec::with_capacity(self.indices.len() * 4);
+
+            for t in 0..self.triangle_count() {
+                let [a, b, c] = [
+                    self.indices[t * 3],
+                    self.indices[t * 3 + 1],
+                    self.indices[t * 3 + 2],
+                ];
+                let ab = self.edge_midpoint(&mut midpoints, a, b);
+                let bc = self.edge_midpoint(&mut midpoints, b, c);
+                let ca = self.edge_midpoint(&mut midpoints, c, a);
+
+                indices.extend_from_slice(&[a, ab, ca, ab, b, bc, ca, bc, c, ab, bc, ca]);
+            }
+
+            self.indices = indices;
+        }
+    }
+
+    /// Get or create the midpoint vertex of edge (a, b) for [`Mesh::subdivide_midpoint`]
+    fn edge_midpoint(&mut self, midpoints: &mut HashMap<(u32, u32), u32>, a: u32, b: u32) -> u32 {
+        let key = (a.min(b), a.max(b));
+        if let Some(&index) = midpoints.get(&key) {
+            return index;
+        }
+
+        let (a, b) = (a as usize, b as usize);
+        let normal = (self.normals[a] + self.normals[b])
+            .try_normalize()
+            .unwrap_or(self.normals[a]);
+        let index = self.add_vertex(
+            (self.positions[a] + self.positions[b]) * 0.5,
+            normal,
+            (self.uvs[a] + self.uvs[b]) * 0.5,
+            (self.colors[a] + self.colors[b]) * 0.5,
+        );
+        midpoints.insert(key, index);
+        index
+    }
+
+    /// Copy a range of triangles out into a standalone mesh
+    ///
+    /// Only vertices referenced by the selected triangles are copied, and
+    /// indices are remapped to the new compact vertex buffer. Combined with
+    /// [`Mesh::triangle_span`] on a component's index range from flower
+    /// assembly this extracts a single component.
+    ///
+    /// # Arguments
+    /// * `range` - Triangle range (in triangles, not indices)
+    ///
+    /// # Returns
+    /// A new mesh containing just the selected triangles
+    ///
+    /// # Panics
+    /// Panics if `range.end > triangle_count()`
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// let mut mesh = Mesh::new();
+    /// let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v1 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v2 = mesh.add_vertex(Vec3::new(1.0, 0.0, 1.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v3 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_quad(v0, v1, v2, v3);
+    ///
+    /// let second = mesh.extract_triangles(1..2);
+    /// assert_eq!(second.triangle_count(), 1);
+    /// assert_eq!(second.vertex_count(), 3);
+    /// ```
+    pub fn extract_triangles(&self, range: Range<usize>) -> Mesh {
+        assert!(
+            range.end <= self.triangle_count(),
+            "Triangle range {:?} out of bounds (triangle count: {})",
+            range,
+            self.triangle_count()
+        );
+
+        let source = &self.indices[range.start * 3..range.end * 3];
+        let mut remap: HashMap<u32, u32> = HashMap::new();
+        let mut result = Mesh::with_capacity(source.len(), source.len());
+
+        for &index in source {
+            let new_index = *remap.entry(index).or_insert_with(|| {
+                let i = index as usize;
+                result.add_vertex(
+                    self.positions[i],
+                    self.normals[i],
+                    self.uvs[i],
+                    self.colors[i],
+                )
+            });
+            result.indices.push(new_index);
+        }
+
+        result
+    }
+
+    /// Convert an index buffer range to the triangle range it covers
+    ///
+    /// Per-component ranges from flower assembly are in index buffer units
+    /// (three per triangle); [`Mesh::extract_triangles`] takes triangles.
+    ///
+    /// # Arguments
+    /// * `indices` - Index buffer range whose ends are multiples of 3
+    ///
+    /// # Returns
+    /// The same range in triangles
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    ///
+    /// assert_eq!(Mesh::triangle_span(6..15), 2..5);
+    /// ```
+    pub fn triangle_span(indices: Range<u32>) -> Range<usize> {
+        indices.start as usize / 3..indices.end as usize / 3
+    }
+
     /// Compute vertex normals from face geometry
     ///
     /// This replaces all existing normals with normals computed from the mesh triangles.
@@ -301,48 +806,128 @@ impl Mesh {
         }
     }
 
-    /// Apply a transformation matrix to all vertices
+    /// Compute smooth normals that stay sharp across creases
     ///
-    /// Positions are transformed by the matrix, while normals are transformed
-    /// by the inverse transpose to handle non-uniform scaling correctly.
+    /// Like [`Mesh::compute_normals`], but at each vertex a face only averages
+    /// with neighboring faces whose normals differ from its own by less than
+    /// `angle_threshold_deg`. Where the faces around a vertex fall into several
+    /// such smoothing groups, the vertex is split: the first group keeps the
+    /// original vertex and each further group gets a new vertex that copies its
+    /// position, UV, and color.
+    ///
+    /// A threshold of 0° gives flat shading at every edge; 180° gives the same
+    /// result as [`Mesh::compute_normals`].
     ///
     /// # Arguments
-    /// * `matrix` - 4x4 transformation matrix
+    /// * `angle_threshold_deg` - Maximum angle between face normals that are smoothed together
     ///
     /// # Example
     /// ```
     /// use floraison_core::geometry::mesh::Mesh;
-    /// use floraison_core::{Vec3, Vec2, Mat4};
+    /// use floraison_core::{Vec3, Vec2};
     ///
+    /// // Floor and wall triangles sharing an edge at a right angle
     /// let mut mesh = Mesh::new();
-    /// mesh.add_vertex(Vec3::new(1.0, 0.0, 0.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let a = mesh.add_vertex(Vec3::ZERO, Vec3::ZERO, Vec2::ZERO, Vec3::ONE);
+    /// let b = mesh.add_vertex(Vec3::Z, Vec3::ZERO, Vec2::ZERO, Vec3::ONE);
+    /// let floor = mesh.add_vertex(Vec3::X, Vec3::ZERO, Vec2::ZERO, Vec3::ONE);
+    /// let wall = mesh.add_vertex(Vec3::Y, Vec3::ZERO, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_triangle(a, b, floor);
+    /// mesh.add_triangle(a, wall, b);
     ///
-    /// // Translate by (1, 2, 3)
-    /// let transform = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
-    /// mesh.transform(&transform);
+    /// mesh.compute_normals_with_crease(30.0);
     ///
-    /// assert_eq!(mesh.positions[0], Vec3::new(2.0, 2.0, 3.0));
+    /// // Shared edge vertices were split to keep the crease sharp
+    /// assert_eq!(mesh.vertex_count(), 6);
     /// ```
-    pub fn transform(&mut self, matrix: &Mat4) {
-        // Transform positions
-        for pos in &mut self.positions {
-            *pos = matrix.transform_point3(*pos);
+    pub fn compute_normals_with_crease(&mut self, angle_threshold_deg: f32) {
+        let cos_threshold = angle_threshold_deg.clamp(0.0, 180.0).to_radians().cos();
+
+        // Area-weighted face normals and their unit directions
+        let face_normals: Vec<Vec3> = self
+            .indices
+            .chunks_exact(3)
+            .map(|tri| {
+                let v0 = self.positions[tri[0] as usize];
+                let v1 = self.positions[tri[1] as usize];
+                let v2 = self.positions[tri[2] as usize];
+                (v1 - v0).cross(v2 - v0)
+            })
+            .collect();
+        let face_units: Vec<Option<Vec3>> = face_normals
+            .iter()
+            .map(|n| {
+                if n.length_squared() < 1e-10 {
+                    None // Degenerate triangle
+                } else {
+                    Some(n.normalize())
+                }
+            })
+            .collect();
+
+        // Faces incident to each vertex
+        let vertex_count = self.positions.len();
+        let mut incident: Vec<Vec<usize>> = vec![Vec::new(); vertex_count];
+        for (face, tri) in self.indices.chunks_exact(3).enumerate() {
+            for &i in tri {
+                incident[i as usize].push(face);
+            }
         }
 
-        // Transform normals using inverse transpose
-        // This ensures normals remain perpendicular to surfaces under non-uniform scaling
-        let normal_matrix = matrix.inverse().transpose();
-        for normal in &mut self.normals {
-            *normal = normal_matrix.transform_vector3(*normal);
-            // Re-normalize after transformation
-            let len = normal.length();
-            if len > 1e-6 {
-                *normal /= len;
+        self.normals.resize(vertex_count, Vec3::Y);
+
+        for (v, faces) in incident.iter().enumerate() {
+            // (smoothed normal, vertex index) for each smoothing group at v
+            let mut groups: Vec<(Vec3, u32)> = Vec::new();
+
+            for &face in faces {
+                let Some(unit) = face_units[face] else {
+                    continue;
+                };
+
+                let normal = faces
+                    .iter()
+                    .filter(|&&other| {
+                        face_units[other].is_some_and(|u| u.dot(unit) >= cos_threshold)
+                    })
+                    .map(|&other| face_normals[other])
+                    .sum::<Vec3>()
+                    .normalize();
+
+                let index = match groups.iter().find(|(n, _)| n.abs_diff_eq(normal, 1e-6)) {
+                    Some(&(_, index)) => index,
+                    None => {
+                        let index = if groups.is_empty() {
+                            v as u32
+                        } else {
+                            self.add_vertex(self.positions[v], normal, self.uvs[v], self.colors[v])
+                        };
+                        groups.push((normal, index));
+                        index
+                    }
+                };
+
+                for corner in &mut self.indices[face * 3..face * 3 + 3] {
+                    if *corner == v as u32 {
+                        *corner = index;
+                    }
+                }
             }
+
+            // Original vertex takes the first group's normal (Y-up if only degenerate faces)
+            self.normals[v] = groups.first().map_or(Vec3::Y, |&(n, _)| n);
         }
     }
 
-    /// Get the number of vertices in the mesh
+    /// Compute flat (per-face) normals for a faceted look
+    ///
+    /// Every triangle gets its own three vertices carrying the geometric face
+    /// normal, so lighting is constant across each face. UVs and colors are
+    /// copied from the original vertices.
+    ///
+    /// This un-shares all vertices: the vertex count becomes `3 * triangle_count`
+    /// and the index buffer is rebuilt, so any previously stored vertex indices
+    /// are invalidated. Degenerate triangles receive a Y-up normal.
     ///
     /// # Example
     /// ```
@@ -350,63 +935,569 @@ impl Mesh {
     /// use floraison_core::{Vec3, Vec2};
     ///
     /// let mut mesh = Mesh::new();
-    /// mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
-    /// mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
-    /// assert_eq!(mesh.vertex_count(), 2);
+    /// let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::ZERO, Vec2::ZERO, Vec3::ONE);
+    /// let v1 = mesh.add_vertex(Vec3::Z, Vec3::ZERO, Vec2::ZERO, Vec3::ONE);
+    /// let v2 = mesh.add_vertex(Vec3::X, Vec3::ZERO, Vec2::ZERO, Vec3::ONE);
+    /// let v3 = mesh.add_vertex(Vec3::new(0.0, 1.0, 1.0), Vec3::ZERO, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_triangle(v0, v1, v2);
+    /// mesh.add_triangle(v0, v3, v1);
+    ///
+    /// mesh.compute_flat_normals();
+    ///
+    /// assert_eq!(mesh.vertex_count(), 6);
+    /// assert_eq!(mesh.normals[0], Vec3::Y);
+    /// assert_eq!(mesh.normals[3], Vec3::X);
     /// ```
-    pub fn vertex_count(&self) -> usize {
-        self.positions.len()
+    pub fn compute_flat_normals(&mut self) {
+        let triangle_count = self.triangle_count();
+        let mut flat = Mesh::with_capacity(triangle_count * 3, triangle_count * 3);
+
+        for tri in self.indices.chunks_exact(3) {
+            let [i0, i1, i2] = [tri[0] as usize, tri[1] as usize, tri[2] as usize];
+            let face_normal = (self.positions[i1] - self.positions[i0])
+                .cross(self.positions[i2] - self.positions[i0])
+                .try_normalize()
+                .unwrap_or(Vec3::Y);
+
+            let base = flat.positions.len() as u32;
+            for i in [i0, i1, i2] {
+                flat.add_vertex(self.positions[i], face_normal, self.uvs[i], self.colors[i]);
+            }
+            flat.add_triangle(base, base + 1, base + 2);
+        }
+
+        *self = flat;
     }
 
-    /// Get the number of triangles in the mesh
+    /// Compute per-vertex tangents for normal mapping
+    ///
+    /// Uses Lengyel's method: each triangle contributes the directions of
+    /// increasing U and V in object space, which are accumulated per vertex,
+    /// Gram-Schmidt orthogonalized against the vertex normal and normalized.
+    ///
+    /// The `w` component encodes handedness (`±1.0`) so the bitangent can be
+    /// reconstructed as `cross(normal, tangent.xyz) * w`, matching the glTF and
+    /// three.js convention.
+    ///
+    /// Triangles with degenerate UVs contribute nothing; vertices left without a
+    /// tangent receive an arbitrary unit vector perpendicular to their normal.
+    ///
+    /// # Returns
+    /// One tangent per vertex, parallel to `positions`
     ///
     /// # Example
     /// ```
     /// use floraison_core::geometry::mesh::Mesh;
-    /// use floraison_core::{Vec3, Vec2};
+    /// use floraison_core::{Vec3, Vec2, Vec4};
     ///
     /// let mut mesh = Mesh::new();
-    /// let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
-    /// let v1 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
-    /// let v2 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Z, Vec2::new(0.0, 0.0), Vec3::ONE);
+    /// let v1 = mesh.add_vertex(Vec3::X, Vec3::Z, Vec2::new(1.0, 0.0), Vec3::ONE);
+    /// let v2 = mesh.add_vertex(Vec3::Y, Vec3::Z, Vec2::new(0.0, 1.0), Vec3::ONE);
     /// mesh.add_triangle(v0, v1, v2);
-    /// mesh.add_triangle(v0, v2, v1);
-    /// assert_eq!(mesh.triangle_count(), 2);
+    ///
+    /// let tangents = mesh.compute_tangents();
+    /// assert_eq!(tangents[0], Vec4::new(1.0, 0.0, 0.0, 1.0));
     /// ```
-    pub fn triangle_count(&self) -> usize {
-        self.indices.len() / 3
+    pub fn compute_tangents(&self) -> Vec<Vec4> {
+        let vertex_count = self.positions.len();
+        let mut tan_u = vec![Vec3::ZERO; vertex_count];
+        let mut tan_v = vec![Vec3::ZERO; vertex_count];
+
+        for tri in self.indices.chunks_exact(3) {
+            let [i0, i1, i2] = [tri[0] as usize, tri[1] as usize, tri[2] as usize];
+
+            let edge1 = self.positions[i1] - self.positions[i0];
+            let edge2 = self.positions[i2] - self.positions[i0];
+            let duv1 = self.uvs[i1] - self.uvs[i0];
+            let duv2 = self.uvs[i2] - self.uvs[i0];
+
+            let det = duv1.x * duv2.y - duv2.x * duv1.y;
+            if det.abs() < 1e-12 {
+                continue; // Degenerate UV mapping
+            }
+            let r = 1.0 / det;
+
+            let sdir = (edge1 * duv2.y - edge2 * duv1.y) * r;
+            let tdir = (edge2 * duv1.x - edge1 * duv2.x) * r;
+
+            for i in [i0, i1, i2] {
+                tan_u[i] += sdir;
+                tan_v[i] += tdir;
+            }
+        }
+
+        (0..vertex_count)
+            .map(|i| {
+                let normal = self.normals[i];
+                let tangent = (tan_u[i] - normal * normal.dot(tan_u[i]))
+                    .try_normalize()
+                    .unwrap_or_else(|| normal.any_orthonormal_vector());
+                let handedness = if normal.cross(tangent).dot(tan_v[i]) < 0.0 {
+                    -1.0
+                } else {
+                    1.0
+                };
+                tangent.extend(handedness)
+            })
+            .collect()
     }
 
-    /// Check if the mesh is empty (has no vertices)
+    /// Apply a transformation matrix to all vertices
     ///
-    /// # Example
-    /// ```
-    /// use floraison_core::geometry::mesh::Mesh;
+    /// Positions are transformed by the matrix, while normals are transformed
+    /// by the inverse transpose to handle non-uniform scaling correctly.
     ///
-    /// let mesh = Mesh::new();
-    /// assert!(mesh.is_empty());
-    /// ```
-    pub fn is_empty(&self) -> bool {
-        self.positions.is_empty()
-    }
-
-    /// Clear all mesh data
+    /// # Arguments
+    /// * `matrix` - 4x4 transformation matrix
     ///
     /// # Example
     /// ```
     /// use floraison_core::geometry::mesh::Mesh;
-    /// use floraison_core::{Vec3, Vec2};
+    /// use floraison_core::{Vec3, Vec2, Mat4};
     ///
     /// let mut mesh = Mesh::new();
-    /// mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
-    /// mesh.clear();
-    /// assert!(mesh.is_empty());
-    /// ```
-    pub fn clear(&mut self) {
-        self.positions.clear();
-        self.normals.clear();
-        self.uvs.clear();
-        self.indices.clear();
+    /// mesh.add_vertex(Vec3::new(1.0, 0.0, 0.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    ///
+    /// // Translate by (1, 2, 3)
+    /// let transform = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+    /// mesh.transform(&transform);
+    ///
+    /// assert_eq!(mesh.positions[0], Vec3::new(2.0, 2.0, 3.0));
+    /// ```
+    pub fn transform(&mut self, matrix: &Mat4) {
+        // Transform positions
+        for pos in &mut self.positions {
+            *pos = matrix.transform_point3(*pos);
+        }
+
+        // Transform normals using inverse transpose
+        let normal_matrix = matrix.inverse().transpose();
+        for normal in &mut self.normals {
+            *normal = transform_normal(&normal_matrix, *normal);
+        }
+    }
+
+    /// Reflect the mesh across a coordinate plane in place
+    ///
+    /// Negates the matching position and normal component and reverses the
+    /// winding of every triangle, so faces keep pointing outward. No vertices
+    /// are added; to build a symmetric shape, mirror a clone of one half and
+    /// [`Mesh::merge`] it back.
+    ///
+    /// # Arguments
+    /// * `plane` - Plane to reflect across
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::{Mesh, MirrorPlane};
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// let mut mesh = Mesh::new();
+    /// let v0 = mesh.add_vertex(Vec3::new(1.0, 0.0, 0.0), Vec3::X, Vec2::ZERO, Vec3::ONE);
+    /// let v1 = mesh.add_vertex(Vec3::new(1.0, 1.0, 0.0), Vec3::X, Vec2::ZERO, Vec3::ONE);
+    /// let v2 = mesh.add_vertex(Vec3::new(1.0, 0.0, 1.0), Vec3::X, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_triangle(v0, v1, v2);
+    ///
+    /// mesh.mirror(MirrorPlane::YZ);
+    ///
+    /// assert_eq!(mesh.positions[0], Vec3::new(-1.0, 0.0, 0.0));
+    /// assert_eq!(mesh.normals[0], -Vec3::X);
+    /// assert_eq!(mesh.indices, vec![0, 2, 1]);
+    /// ```
+    pub fn mirror(&mut self, plane: MirrorPlane) {
+        let scale = plane.scale();
+
+        for pos in &mut self.positions {
+            *pos *= scale;
+        }
+        for normal in &mut self.normals {
+            *normal *= scale;
+        }
+
+        // A reflection inverts handedness; swap two corners to restore winding
+        for tri in self.indices.chunks_exact_mut(3) {
+            tri.swap(1, 2);
+        }
+    }
+
+    /// Get the number of vertices in the mesh
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// let mut mesh = Mesh::new();
+    /// mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// assert_eq!(mesh.vertex_count(), 2);
+    /// ```
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Get the number of triangles in the mesh
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// let mut mesh = Mesh::new();
+    /// let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v1 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v2 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_triangle(v0, v1, v2);
+    /// mesh.add_triangle(v0, v2, v1);
+    /// assert_eq!(mesh.triangle_count(), 2);
+    /// ```
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    /// Compute the area of a single triangle
+    ///
+    /// # Arguments
+    /// * `tri_index` - Triangle index (not vertex index), in `0..triangle_count()`
+    ///
+    /// # Returns
+    /// Triangle area (half the length of the edge cross product)
+    ///
+    /// # Panics
+    /// Panics if `tri_index >= triangle_count()`
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// let mut mesh = Mesh::new();
+    /// let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v1 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v2 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_triangle(v0, v1, v2);
+    ///
+    /// assert_eq!(mesh.triangle_area(0), 0.5);
+    /// ```
+    pub fn triangle_area(&self, tri_index: usize) -> f32 {
+        assert!(
+            tri_index < self.triangle_count(),
+            "Triangle index {} out of bounds (triangle count: {})",
+            tri_index,
+            self.triangle_count()
+        );
+
+        let base = tri_index * 3;
+        let v0 = self.positions[self.indices[base] as usize];
+        let v1 = self.positions[self.indices[base + 1] as usize];
+        let v2 = self.positions[self.indices[base + 2] as usize];
+
+        (v1 - v0).cross(v2 - v0).length() * 0.5
+    }
+
+    /// Compute the total surface area of the mesh
+    ///
+    /// # Returns
+    /// Sum of all triangle areas (0.0 for an empty mesh)
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// let mut mesh = Mesh::new();
+    /// let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v1 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v2 = mesh.add_vertex(Vec3::new(1.0, 0.0, 1.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v3 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_quad(v0, v1, v2, v3);
+    ///
+    /// assert!((mesh.surface_area() - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn surface_area(&self) -> f32 {
+        (0..self.triangle_count())
+            .map(|i| self.triangle_area(i))
+            .sum()
+    }
+
+    /// Check the mesh for structural problems
+    ///
+    /// Verifies that all attribute arrays have one entry per vertex, that the
+    /// index buffer holds whole triangles, and that every index refers to an
+    /// existing vertex. Useful before handing a programmatically built mesh to
+    /// a renderer or exporter.
+    ///
+    /// # Returns
+    /// `Ok(())` for a well-formed mesh, otherwise the first problem found
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::{Mesh, MeshError};
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// let mut mesh = Mesh::new();
+    /// let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v1 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v2 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_triangle(v0, v1, v2);
+    /// assert!(mesh.validate().is_ok());
+    ///
+    /// mesh.indices[2] = 7;
+    /// assert_eq!(
+    ///     mesh.validate(),
+    ///     Err(MeshError::IndexOutOfBounds { index: 7, vertex_count: 3 })
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), MeshError> {
+        let vertex_count = self.positions.len();
+
+        if self.normals.len() != vertex_count
+            || self.uvs.len() != vertex_count
+            || self.colors.len() != vertex_count
+        {
+            return Err(MeshError::AttributeLengthMismatch {
+                positions: vertex_count,
+                normals: self.normals.len(),
+                uvs: self.uvs.len(),
+                colors: self.colors.len(),
+            });
+        }
+
+        if !self.indices.len().is_multiple_of(3) {
+            return Err(MeshError::IndicesNotMultipleOfThree {
+                len: self.indices.len(),
+            });
+        }
+
+        if let Some(&index) = self.indices.iter().find(|&&i| i as usize >= vertex_count) {
+            return Err(MeshError::IndexOutOfBounds {
+                index,
+                vertex_count,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Count how many triangles use each undirected edge, keyed by (min, max) index
+    fn edge_use_counts(&self) -> HashMap<(u32, u32), usize> {
+        let mut counts = HashMap::with_capacity(self.indices.len());
+        for tri in self.indices.chunks_exact(3) {
+            for (a, b) in [(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+                *counts.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    /// Find edges that belong to exactly one triangle
+    ///
+    /// Edges are identified by vertex index, so coincident but distinct
+    /// vertices (e.g. seams left by [`Mesh::merge`]) count as separate and
+    /// their edges show up as boundaries. Call [`Mesh::weld_vertices`] first to
+    /// analyze the shape rather than the vertex layout.
+    ///
+    /// # Returns
+    /// Boundary edges as `(min_index, max_index)` pairs, sorted
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// let mut mesh = Mesh::new();
+    /// let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v1 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// let v2 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_triangle(v0, v1, v2);
+    ///
+    /// assert_eq!(mesh.boundary_edges(), vec![(0, 1), (0, 2), (1, 2)]);
+    /// ```
+    pub fn boundary_edges(&self) -> Vec<(u32, u32)> {
+        let mut edges: Vec<(u32, u32)> = self
+            .edge_use_counts()
+            .into_iter()
+            .filter(|&(_, count)| count == 1)
+            .map(|(edge, _)| edge)
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    /// Check whether the mesh is a closed, manifold surface
+    ///
+    /// True when the mesh has at least one triangle and every edge is shared by
+    /// exactly two triangles: no holes ([`Mesh::boundary_edges`] is empty) and
+    /// no edges shared by three or more faces. As with `boundary_edges`,
+    /// coincident vertices are only treated as connected after
+    /// [`Mesh::weld_vertices`].
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::geometry::surface_revolution::cylinder;
+    /// use floraison_core::Vec3;
+    ///
+    /// // An open-ended tube is not watertight
+    /// let tube = cylinder(1.0, 2.0, 8, Vec3::ONE);
+    /// assert!(!tube.is_watertight());
+    /// ```
+    pub fn is_watertight(&self) -> bool {
+        !self.indices.is_empty() && self.edge_use_counts().values().all(|&count| count == 2)
+    }
+
+    /// Check if the mesh is empty (has no vertices)
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new();
+    /// assert!(mesh.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Compute the axis-aligned bounding box of the mesh
+    ///
+    /// # Returns
+    /// `Some((min, max))` over all vertex positions, or `None` if the mesh is empty
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// let mut mesh = Mesh::new();
+    /// assert!(mesh.bounding_box().is_none());
+    ///
+    /// mesh.add_vertex(Vec3::new(-1.0, 0.0, 2.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_vertex(Vec3::new(3.0, -2.0, 0.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    ///
+    /// let (min, max) = mesh.bounding_box().unwrap();
+    /// assert_eq!(min, Vec3::new(-1.0, -2.0, 0.0));
+    /// assert_eq!(max, Vec3::new(3.0, 0.0, 2.0));
+    /// ```
+    pub fn bounding_box(&self) -> Option<(Vec3, Vec3)> {
+        let (&first, rest) = self.positions.split_first()?;
+        Some(
+            rest.iter()
+                .fold((first, first), |(min, max), &p| (min.min(p), max.max(p))),
+        )
+    }
+
+    /// Compute the center of the mesh's bounding box
+    ///
+    /// # Returns
+    /// Midpoint of [`Mesh::bounding_box`], or `None` if the mesh is empty
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// let mut mesh = Mesh::new();
+    /// mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_vertex(Vec3::new(2.0, 4.0, 6.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    ///
+    /// assert_eq!(mesh.center(), Some(Vec3::new(1.0, 2.0, 3.0)));
+    /// ```
+    pub fn center(&self) -> Option<Vec3> {
+        self.bounding_box().map(|(min, max)| (min + max) * 0.5)
+    }
+
+    /// Translate the mesh so its bounding-box center sits at the origin
+    ///
+    /// Normals are unaffected. Does nothing for an empty mesh.
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// let mut mesh = Mesh::new();
+    /// mesh.add_vertex(Vec3::new(2.0, 2.0, 2.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_vertex(Vec3::new(4.0, 6.0, 2.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    ///
+    /// mesh.recenter();
+    /// assert_eq!(mesh.center(), Some(Vec3::ZERO));
+    /// ```
+    pub fn recenter(&mut self) {
+        if let Some(center) = self.center() {
+            for pos in &mut self.positions {
+                *pos -= center;
+            }
+        }
+    }
+
+    /// Recenter the mesh and scale it uniformly so its largest extent is 1.0
+    ///
+    /// After this call the mesh fits inside the cube `[-0.5, 0.5]³`. Normals are
+    /// renormalized after scaling. Meshes with zero extent on every axis (e.g. a
+    /// single point) are only recentered.
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// let mut mesh = Mesh::new();
+    /// mesh.add_vertex(Vec3::new(0.0, 0.0, 0.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// mesh.add_vertex(Vec3::new(10.0, 4.0, 0.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    ///
+    /// mesh.fit_to_unit_cube();
+    ///
+    /// let (min, max) = mesh.bounding_box().unwrap();
+    /// assert!(((max - min).max_element() - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn fit_to_unit_cube(&mut self) {
+        self.recenter();
+
+        let Some((min, max)) = self.bounding_box() else {
+            return;
+        };
+        let extent = (max - min).max_element();
+        if extent <= f32::EPSILON {
+            return;
+        }
+
+        self.transform(&Mat4::from_scale(Vec3::splat(1.0 / extent)));
+    }
+
+    /// Clear all mesh data
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec3, Vec2};
+    ///
+    /// let mut mesh = Mesh::new();
+    /// mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    /// mesh.clear();
+    /// assert!(mesh.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.positions.clear();
+        self.normals.clear();
+        self.uvs.clear();
+        self.indices.clear();
+    }
+}
+
+/// Transform a normal by a normal matrix (inverse transpose) and re-normalize
+///
+/// The inverse transpose keeps normals perpendicular to surfaces under
+/// non-uniform scaling.
+#[inline]
+fn transform_normal(normal_matrix: &Mat4, normal: Vec3) -> Vec3 {
+    let normal = normal_matrix.transform_vector3(normal);
+    let len = normal.length();
+    if len > 1e-6 {
+        normal / len
+    } else {
+        normal
     }
 }
 
@@ -502,6 +1593,190 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_join_all_matches_merge() {
+        let mut a = Mesh::new();
+        let v0 = a.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        let v1 = a.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        let v2 = a.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        a.add_triangle(v0, v1, v2);
+
+        let mut b = Mesh::new();
+        let v0 = b.add_vertex(Vec3::new(2.0, 0.0, 0.0), Vec3::Y, Vec2::ZERO, Vec3::X);
+        let v1 = b.add_vertex(Vec3::new(3.0, 0.0, 0.0), Vec3::Y, Vec2::ZERO, Vec3::X);
+        let v2 = b.add_vertex(Vec3::new(2.0, 0.0, 1.0), Vec3::Y, Vec2::ZERO, Vec3::X);
+        let v3 = b.add_vertex(Vec3::new(3.0, 0.0, 1.0), Vec3::Y, Vec2::ZERO, Vec3::X);
+        b.add_quad(v0, v1, v3, v2);
+
+        let meshes = [a.clone(), b.clone(), Mesh::new(), a.clone()];
+        let joined = Mesh::join_all(&meshes);
+
+        let mut folded = Mesh::new();
+        for mesh in &meshes {
+            folded.merge(mesh);
+        }
+
+        assert_eq!(joined.positions, folded.positions);
+        assert_eq!(joined.colors, folded.colors);
+        assert_eq!(joined.indices, folded.indices);
+        assert_eq!(joined.vertex_count(), 10);
+        assert_eq!(joined.triangle_count(), 4);
+        assert!(joined.positions.capacity() >= 10);
+        assert!(joined.indices.capacity() >= 12);
+    }
+
+    #[test]
+    fn test_join_all_empty() {
+        let joined = Mesh::join_all(&[]);
+        assert!(joined.is_empty());
+    }
+
+    #[test]
+    fn test_weld_merged_seam() {
+        use crate::geometry::surface_revolution::uv_sphere;
+
+        // Same sphere merged twice: every vertex has an exact duplicate
+        let sphere = uv_sphere(1.0, 6, 8, Vec3::ONE);
+        let mut mesh = sphere.clone();
+        mesh.merge(&sphere);
+
+        let before = mesh.vertex_count();
+        let removed = mesh.weld_vertices(1e-4);
+
+        assert!(removed >= sphere.vertex_count());
+        assert_eq!(mesh.vertex_count(), before - removed);
+        assert_eq!(mesh.triangle_count(), sphere.triangle_count() * 2);
+        assert_eq!(mesh.normals.len(), mesh.vertex_count());
+        assert_eq!(mesh.uvs.len(), mesh.vertex_count());
+        assert_eq!(mesh.colors.len(), mesh.vertex_count());
+        assert!(mesh
+            .indices
+            .iter()
+            .all(|&i| (i as usize) < mesh.vertex_count()));
+    }
+
+    #[test]
+    fn test_weld_averages_attributes() {
+        let mut mesh = Mesh::new();
+        let a = mesh.add_vertex(Vec3::ZERO, Vec3::X, Vec2::new(0.0, 0.0), Vec3::X);
+        let b = mesh.add_vertex(
+            Vec3::new(0.0, 1e-6, 0.0),
+            Vec3::Y,
+            Vec2::new(1.0, 1.0),
+            Vec3::Y,
+        );
+        let c = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        mesh.add_triangle(a, b, c);
+
+        assert_eq!(mesh.weld_vertices(1e-4), 1);
+        assert_eq!(mesh.indices, vec![0, 0, 1]);
+
+        let expected_normal = Vec3::new(1.0, 1.0, 0.0).normalize();
+        assert!((mesh.normals[0] - expected_normal).length() < EPSILON);
+        assert!((mesh.uvs[0] - Vec2::new(0.5, 0.5)).length() < EPSILON);
+        assert!((mesh.colors[0] - Vec3::new(0.5, 0.5, 0.0)).length() < EPSILON);
+    }
+
+    #[test]
+    fn test_weld_across_cell_boundary() {
+        // Positions straddle a quantization cell edge but are within epsilon
+        let mut mesh = Mesh::new();
+        mesh.add_vertex(Vec3::new(0.0099, 0.0, 0.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        mesh.add_vertex(Vec3::new(0.0101, 0.0, 0.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+
+        assert_eq!(mesh.weld_vertices(0.01), 1);
+        assert_eq!(mesh.vertex_count(), 1);
+    }
+
+    #[test]
+    fn test_weld_keeps_distinct_vertices() {
+        let mut mesh = Mesh::new();
+        let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        let v1 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        let v2 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        mesh.add_triangle(v0, v1, v2);
+        let original = mesh.clone();
+
+        assert_eq!(mesh.weld_vertices(1e-3), 0);
+        assert_eq!(mesh.positions, original.positions);
+        assert_eq!(mesh.indices, original.indices);
+    }
+
+    #[test]
+    #[should_panic(expected = "epsilon must be positive")]
+    fn test_weld_zero_epsilon() {
+        Mesh::new().weld_vertices(0.0);
+    }
+
+    #[test]
+    fn test_subdivide_midpoint_quadruples_triangles() {
+        use crate::geometry::surface_revolution::uv_sphere;
+
+        let mut mesh = uv_sphere(1.0, 6, 8, Vec3::ONE);
+        let triangles = mesh.triangle_count();
+
+        mesh.subdivide_midpoint(1);
+        assert_eq!(mesh.triangle_count(), triangles * 4);
+        assert!(mesh
+            .indices
+            .iter()
+            .all(|&i| (i as usize) < mesh.vertex_count()));
+
+        mesh.subdivide_midpoint(2);
+        assert_eq!(mesh.triangle_count(), triangles * 64);
+        assert!(mesh.validate().is_ok());
+    }
+
+    #[test]
+    fn test_subdivide_midpoint_interpolates_attributes() {
+        let mut mesh = Mesh::new();
+        let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::X, Vec2::new(0.0, 0.0), Vec3::X);
+        let v1 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::new(0.0, 1.0), Vec3::Y);
+        let v2 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::new(1.0, 0.0), Vec3::Z);
+        mesh.add_triangle(v0, v1, v2);
+
+        mesh.subdivide_midpoint(1);
+
+        // First midpoint is on edge v0-v1
+        assert_eq!(mesh.vertex_count(), 6);
+        assert_eq!(mesh.positions[3], Vec3::new(0.0, 0.0, 0.5));
+        assert_eq!(mesh.uvs[3], Vec2::new(0.0, 0.5));
+        assert_eq!(mesh.colors[3], Vec3::new(0.5, 0.5, 0.0));
+        assert!((mesh.normals[3] - Vec3::new(1.0, 1.0, 0.0).normalize()).length() < EPSILON);
+    }
+
+    #[test]
+    fn test_subdivide_zero_iterations() {
+        let mut mesh = create_valid_triangle();
+        let original = mesh.clone();
+        mesh.subdivide_midpoint(0);
+        assert_eq!(mesh.indices, original.indices);
+        assert_eq!(mesh.positions, original.positions);
+    }
+
+    #[test]
+    fn test_extract_triangles() {
+        let mut a = create_valid_triangle();
+        let mut b = create_valid_triangle();
+        b.transform(&Mat4::from_translation(Vec3::new(5.0, 0.0, 0.0)));
+        b.colors = vec![Vec3::X; 3];
+        a.merge(&b);
+
+        let extracted = a.extract_triangles(1..2);
+        assert_eq!(extracted.vertex_count(), 3);
+        assert_eq!(extracted.indices, vec![0, 1, 2]);
+        assert_eq!(extracted.positions, b.positions);
+        assert_eq!(extracted.colors, b.colors);
+
+        assert!(a.extract_triangles(1..1).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_extract_triangles_out_of_range() {
+        create_valid_triangle().extract_triangles(0..2);
+    }
+
     #[test]
     fn test_compute_normals_horizontal_triangle() {
         let mut mesh = Mesh::new();
@@ -578,6 +1853,157 @@ mod tests {
         }
     }
 
+    /// Two triangles sharing the edge (0,0,0)-(0,0,1): one on the floor, one on a wall
+    fn create_l_shape() -> Mesh {
+        let mut mesh = Mesh::new();
+        let a = mesh.add_vertex(Vec3::ZERO, Vec3::ZERO, Vec2::ZERO, Vec3::ONE);
+        let b = mesh.add_vertex(Vec3::Z, Vec3::ZERO, Vec2::new(0.0, 1.0), Vec3::X);
+        let floor = mesh.add_vertex(Vec3::X, Vec3::ZERO, Vec2::ZERO, Vec3::ONE);
+        let wall = mesh.add_vertex(Vec3::Y, Vec3::ZERO, Vec2::ZERO, Vec3::ONE);
+        mesh.add_triangle(a, b, floor);
+        mesh.add_triangle(a, wall, b);
+        mesh
+    }
+
+    #[test]
+    fn test_crease_keeps_sharp_edge() {
+        let mut mesh = create_l_shape();
+        mesh.compute_normals_with_crease(30.0);
+
+        // Both shared vertices are split
+        assert_eq!(mesh.vertex_count(), 6);
+        assert_eq!(mesh.triangle_count(), 2);
+
+        let floor = &mesh.indices[0..3];
+        let wall = &mesh.indices[3..6];
+        assert!(floor.iter().all(|&i| wall.iter().all(|&j| i != j)));
+        for &i in floor {
+            assert!((mesh.normals[i as usize] - Vec3::Y).length() < EPSILON);
+        }
+        for &i in wall {
+            assert!((mesh.normals[i as usize] - Vec3::X).length() < EPSILON);
+        }
+
+        // Split vertices copy attributes
+        assert_eq!(mesh.uvs[5], Vec2::new(0.0, 1.0));
+        assert_eq!(mesh.colors[5], Vec3::X);
+        assert_eq!(mesh.positions[5], Vec3::Z);
+    }
+
+    #[test]
+    fn test_crease_smooths_below_threshold() {
+        let mut mesh = create_l_shape();
+        mesh.compute_normals_with_crease(170.0);
+
+        assert_eq!(mesh.vertex_count(), 4);
+        let diagonal = Vec3::new(1.0, 1.0, 0.0).normalize();
+        assert!((mesh.normals[0] - diagonal).length() < EPSILON);
+        assert!((mesh.normals[1] - diagonal).length() < EPSILON);
+    }
+
+    #[test]
+    fn test_crease_180_matches_smooth() {
+        use crate::geometry::surface_revolution::uv_sphere;
+
+        let mut smooth = uv_sphere(1.0, 6, 8, Vec3::ONE);
+        let mut creased = smooth.clone();
+        smooth.compute_normals();
+        creased.compute_normals_with_crease(180.0);
+
+        assert_eq!(smooth.vertex_count(), creased.vertex_count());
+        for (a, b) in smooth.normals.iter().zip(&creased.normals) {
+            assert!((*a - *b).length() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_compute_flat_normals() {
+        use crate::geometry::surface_revolution::uv_sphere;
+
+        let mut mesh = uv_sphere(1.0, 6, 8, Vec3::new(0.2, 0.4, 0.6));
+        let triangle_count = mesh.triangle_count();
+
+        mesh.compute_flat_normals();
+
+        assert_eq!(mesh.vertex_count(), triangle_count * 3);
+        assert_eq!(mesh.triangle_count(), triangle_count);
+
+        for tri in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0] as usize, tri[1] as usize, tri[2] as usize];
+            assert_eq!(mesh.normals[a], mesh.normals[b]);
+            assert_eq!(mesh.normals[b], mesh.normals[c]);
+
+            let face = (mesh.positions[b] - mesh.positions[a])
+                .cross(mesh.positions[c] - mesh.positions[a]);
+            if let Some(face) = face.try_normalize() {
+                assert!((mesh.normals[a] - face).length() < EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_compute_flat_normals_preserves_attributes() {
+        let mut mesh = Mesh::new();
+        let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::new(0.1, 0.2), Vec3::X);
+        let v1 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::new(0.3, 0.4), Vec3::Y);
+        let v2 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::new(0.5, 0.6), Vec3::Z);
+        mesh.add_triangle(v0, v1, v2);
+        mesh.add_triangle(v2, v1, v0);
+
+        mesh.compute_flat_normals();
+
+        assert_eq!(mesh.uvs[0], Vec2::new(0.1, 0.2));
+        assert_eq!(mesh.colors[4], Vec3::Y);
+        assert_eq!(mesh.uvs[5], Vec2::new(0.1, 0.2));
+        assert_eq!(mesh.normals[0], -mesh.normals[3]);
+    }
+
+    #[test]
+    fn test_compute_tangents_flat_quad() {
+        let mut mesh = Mesh::new();
+        let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::new(0.0, 0.0), Vec3::ONE);
+        let v1 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::new(0.0, 1.0), Vec3::ONE);
+        let v2 = mesh.add_vertex(Vec3::new(1.0, 0.0, 1.0), Vec3::Y, Vec2::ONE, Vec3::ONE);
+        let v3 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::new(1.0, 0.0), Vec3::ONE);
+        mesh.add_quad(v0, v1, v2, v3);
+
+        let tangents = mesh.compute_tangents();
+        assert_eq!(tangents.len(), 4);
+        for t in &tangents {
+            assert!((t.truncate() - Vec3::X).length() < EPSILON);
+            // U along +X, V along +Z, normal +Y: cross(N, T) = -Z, opposite to V
+            assert_eq!(t.w, -1.0);
+        }
+    }
+
+    #[test]
+    fn test_compute_tangents_mirrored_uvs() {
+        let mut mesh = Mesh::new();
+        let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Z, Vec2::new(1.0, 0.0), Vec3::ONE);
+        let v1 = mesh.add_vertex(Vec3::X, Vec3::Z, Vec2::new(0.0, 0.0), Vec3::ONE);
+        let v2 = mesh.add_vertex(Vec3::Y, Vec3::Z, Vec2::new(1.0, 1.0), Vec3::ONE);
+        mesh.add_triangle(v0, v1, v2);
+
+        let tangents = mesh.compute_tangents();
+        assert!((tangents[0].truncate() + Vec3::X).length() < EPSILON);
+        assert_eq!(tangents[0].w, -1.0);
+    }
+
+    #[test]
+    fn test_compute_tangents_degenerate_uvs() {
+        let mut mesh = Mesh::new();
+        let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        let v1 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        let v2 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        mesh.add_triangle(v0, v1, v2);
+
+        for t in mesh.compute_tangents() {
+            let tangent = t.truncate();
+            assert!((tangent.length() - 1.0).abs() < EPSILON);
+            assert!(tangent.dot(Vec3::Y).abs() < EPSILON);
+        }
+    }
+
     #[test]
     fn test_transform_translation() {
         let mut mesh = Mesh::new();
@@ -623,6 +2049,60 @@ mod tests {
         assert!((mesh.normals[0].length() - 1.0).abs() < EPSILON);
     }
 
+    #[test]
+    fn test_reserve_keeps_length() {
+        let mut mesh = Mesh::new();
+        let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        let v1 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        let v2 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        mesh.add_triangle(v0, v1, v2);
+
+        mesh.reserve(1000, 3000);
+
+        assert!(mesh.positions.capacity() >= 1003);
+        assert!(mesh.normals.capacity() >= 1003);
+        assert!(mesh.uvs.capacity() >= 1003);
+        assert!(mesh.colors.capacity() >= 1003);
+        assert!(mesh.indices.capacity() >= 3003);
+        assert_eq!(mesh.vertex_count(), 3);
+        assert_eq!(mesh.indices, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_merge_transformed_matches_clone_transform_merge() {
+        let mut other = Mesh::new();
+        let v0 = other.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::X);
+        let v1 = other.add_vertex(Vec3::X, Vec3::new(0.6, 0.8, 0.0), Vec2::X, Vec3::Y);
+        let v2 = other.add_vertex(Vec3::Z, Vec3::Z, Vec2::Y, Vec3::Z);
+        other.add_triangle(v0, v1, v2);
+
+        let mut base = Mesh::new();
+        let b0 = base.add_vertex(Vec3::NEG_X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        let b1 = base.add_vertex(Vec3::NEG_Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        let b2 = base.add_vertex(Vec3::NEG_Y, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        base.add_triangle(b0, b1, b2);
+
+        let matrix = Mat4::from_scale_rotation_translation(
+            Vec3::new(2.0, 0.5, 1.5),
+            crate::Quat::from_rotation_z(0.7),
+            Vec3::new(1.0, -2.0, 3.0),
+        );
+
+        let mut expected = base.clone();
+        let mut instance = other.clone();
+        instance.transform(&matrix);
+        expected.merge(&instance);
+
+        let mut merged = base;
+        merged.merge_transformed(&other, &matrix);
+
+        assert_eq!(merged.positions, expected.positions);
+        assert_eq!(merged.normals, expected.normals);
+        assert_eq!(merged.uvs, expected.uvs);
+        assert_eq!(merged.colors, expected.colors);
+        assert_eq!(merged.indices, expected.indices);
+    }
+
     #[test]
     fn test_transform_scale_nonuniform() {
         let mut mesh = Mesh::new();
@@ -637,6 +2117,274 @@ mod tests {
         assert!((mesh.normals[0].length() - 1.0).abs() < EPSILON);
     }
 
+    #[test]
+    fn test_bounding_box_corners() {
+        let mut mesh = Mesh::new();
+        let corners = [
+            Vec3::new(-2.0, 1.0, 0.5),
+            Vec3::new(4.0, -3.0, 0.0),
+            Vec3::new(0.0, 5.0, -1.5),
+            Vec3::new(1.0, 0.0, 7.0),
+        ];
+        for corner in corners {
+            mesh.add_vertex(corner, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        }
+
+        let (min, max) = mesh.bounding_box().unwrap();
+        assert_eq!(min, Vec3::new(-2.0, -3.0, -1.5));
+        assert_eq!(max, Vec3::new(4.0, 5.0, 7.0));
+        assert_eq!(mesh.center(), Some(Vec3::new(1.0, 1.0, 2.75)));
+    }
+
+    #[test]
+    fn test_bounding_box_single_vertex() {
+        let mut mesh = Mesh::new();
+        let p = Vec3::new(1.0, 2.0, 3.0);
+        mesh.add_vertex(p, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+
+        assert_eq!(mesh.bounding_box(), Some((p, p)));
+        assert_eq!(mesh.center(), Some(p));
+    }
+
+    #[test]
+    fn test_bounding_box_empty() {
+        let mesh = Mesh::new();
+        assert!(mesh.bounding_box().is_none());
+        assert!(mesh.center().is_none());
+    }
+
+    #[test]
+    fn test_recenter() {
+        let mut mesh = Mesh::new();
+        mesh.add_vertex(Vec3::new(1.0, 1.0, 1.0), Vec3::X, Vec2::ZERO, Vec3::ONE);
+        mesh.add_vertex(Vec3::new(5.0, 3.0, 2.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+
+        mesh.recenter();
+
+        let (min, max) = mesh.bounding_box().unwrap();
+        assert!((min + max).length() < EPSILON);
+        assert_eq!(mesh.normals, vec![Vec3::X, Vec3::Y]);
+    }
+
+    #[test]
+    fn test_fit_to_unit_cube() {
+        use crate::geometry::surface_revolution::uv_sphere;
+
+        let mut mesh = uv_sphere(7.5, 8, 12, Vec3::ONE);
+        mesh.transform(&Mat4::from_translation(Vec3::new(3.0, -20.0, 4.0)));
+
+        mesh.fit_to_unit_cube();
+
+        let (min, max) = mesh.bounding_box().unwrap();
+        assert!((min + max).length() < EPSILON, "Should be centered");
+        assert!(((max - min).max_element() - 1.0).abs() < EPSILON);
+        for normal in &mesh.normals {
+            assert!((normal.length() - 1.0).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_fit_to_unit_cube_flat_mesh() {
+        // Zero extent along Y must not produce NaNs
+        let mut mesh = Mesh::new();
+        mesh.add_vertex(Vec3::new(0.0, 2.0, 0.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        mesh.add_vertex(Vec3::new(4.0, 2.0, 2.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+
+        mesh.fit_to_unit_cube();
+
+        let (min, max) = mesh.bounding_box().unwrap();
+        assert!(((max - min).max_element() - 1.0).abs() < EPSILON);
+        assert_eq!(max.y - min.y, 0.0);
+        assert!(mesh.positions.iter().all(|p| p.is_finite()));
+    }
+
+    #[test]
+    fn test_fit_to_unit_cube_single_point() {
+        let mut mesh = Mesh::new();
+        mesh.add_vertex(Vec3::new(3.0, 3.0, 3.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+
+        mesh.fit_to_unit_cube();
+        assert_eq!(mesh.positions[0], Vec3::ZERO);
+
+        // Empty mesh is a no-op
+        Mesh::new().fit_to_unit_cube();
+    }
+
+    #[test]
+    fn test_triangle_area_unit_right_triangle() {
+        let mut mesh = Mesh::new();
+        let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Z, Vec2::ZERO, Vec3::ONE);
+        let v1 = mesh.add_vertex(Vec3::X, Vec3::Z, Vec2::ZERO, Vec3::ONE);
+        let v2 = mesh.add_vertex(Vec3::Y, Vec3::Z, Vec2::ZERO, Vec3::ONE);
+        mesh.add_triangle(v0, v1, v2);
+
+        assert!((mesh.triangle_area(0) - 0.5).abs() < EPSILON);
+        assert!((mesh.surface_area() - 0.5).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_surface_area_sphere() {
+        use crate::geometry::surface_revolution::uv_sphere;
+
+        let mesh = uv_sphere(1.0, 32, 64, Vec3::ONE);
+        let expected = 4.0 * std::f32::consts::PI;
+        let area = mesh.surface_area();
+
+        // Tessellated sphere slightly underestimates the true area
+        assert!(area < expected && area > expected * 0.98, "got {}", area);
+        assert_eq!(Mesh::new().surface_area(), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_triangle_area_out_of_range() {
+        let mut mesh = Mesh::new();
+        let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Z, Vec2::ZERO, Vec3::ONE);
+        mesh.add_triangle(v0, v0, v0);
+        mesh.triangle_area(1);
+    }
+
+    fn create_valid_triangle() -> Mesh {
+        let mut mesh = Mesh::new();
+        let v0 = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        let v1 = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        let v2 = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        mesh.add_triangle(v0, v1, v2);
+        mesh
+    }
+
+    #[test]
+    fn test_validate_ok() {
+        assert_eq!(create_valid_triangle().validate(), Ok(()));
+        assert_eq!(Mesh::new().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_index_out_of_bounds() {
+        let mut mesh = create_valid_triangle();
+        mesh.indices[1] = 3;
+
+        assert_eq!(
+            mesh.validate(),
+            Err(MeshError::IndexOutOfBounds {
+                index: 3,
+                vertex_count: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_attribute_length_mismatch() {
+        let mut mesh = create_valid_triangle();
+        mesh.colors.pop();
+
+        assert_eq!(
+            mesh.validate(),
+            Err(MeshError::AttributeLengthMismatch {
+                positions: 3,
+                normals: 3,
+                uvs: 3,
+                colors: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_indices_not_multiple_of_three() {
+        let mut mesh = create_valid_triangle();
+        mesh.indices.push(0);
+
+        let err = mesh.validate().unwrap_err();
+        assert_eq!(err, MeshError::IndicesNotMultipleOfThree { len: 4 });
+        assert_eq!(err.to_string(), "Index count 4 is not a multiple of 3");
+    }
+
+    #[test]
+    fn test_mirror_yz() {
+        let mut mesh = Mesh::new();
+        let v0 = mesh.add_vertex(Vec3::new(1.0, 0.0, 0.0), Vec3::ZERO, Vec2::ZERO, Vec3::ONE);
+        let v1 = mesh.add_vertex(Vec3::new(2.0, 0.0, 1.0), Vec3::ZERO, Vec2::ZERO, Vec3::ONE);
+        let v2 = mesh.add_vertex(Vec3::new(2.0, 0.0, 0.0), Vec3::ZERO, Vec2::ZERO, Vec3::ONE);
+        mesh.add_triangle(v0, v1, v2);
+        mesh.compute_normals();
+        let original = mesh.clone();
+
+        mesh.mirror(MirrorPlane::YZ);
+
+        assert_eq!(mesh.vertex_count(), 3);
+        assert_eq!(mesh.indices, vec![0, 2, 1]);
+        for (mirrored, orig) in mesh.positions.iter().zip(&original.positions) {
+            assert_eq!(mirrored.x, -orig.x);
+            assert_eq!(mirrored.y, orig.y);
+            assert_eq!(mirrored.z, orig.z);
+        }
+
+        // Stored normals still agree with the geometric face normal
+        let stored = mesh.normals[0];
+        mesh.compute_normals();
+        assert!((mesh.normals[0] - stored).length() < EPSILON);
+    }
+
+    #[test]
+    fn test_mirror_twice_is_identity() {
+        use crate::geometry::surface_revolution::uv_sphere;
+
+        let original = uv_sphere(1.0, 4, 6, Vec3::ONE);
+        for plane in [MirrorPlane::XY, MirrorPlane::YZ, MirrorPlane::XZ] {
+            let mut mesh = original.clone();
+            mesh.mirror(plane);
+            mesh.mirror(plane);
+            assert_eq!(mesh.positions, original.positions);
+            assert_eq!(mesh.indices, original.indices);
+        }
+    }
+
+    #[test]
+    fn test_boundary_edges_open_triangle() {
+        let mesh = create_valid_triangle();
+        assert_eq!(mesh.boundary_edges().len(), 3);
+        assert!(!mesh.is_watertight());
+    }
+
+    #[test]
+    fn test_tetrahedron_watertight() {
+        let mut mesh = Mesh::new();
+        let a = mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        let b = mesh.add_vertex(Vec3::X, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        let c = mesh.add_vertex(Vec3::Z, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        let d = mesh.add_vertex(Vec3::Y, Vec3::Y, Vec2::ZERO, Vec3::ONE);
+        mesh.add_triangle(a, b, c);
+        mesh.add_triangle(a, d, b);
+        mesh.add_triangle(b, d, c);
+        mesh.add_triangle(c, d, a);
+
+        assert!(mesh.boundary_edges().is_empty());
+        assert!(mesh.is_watertight());
+
+        // Removing a face opens three edges
+        mesh.indices.truncate(9);
+        assert_eq!(mesh.boundary_edges().len(), 3);
+        assert!(!mesh.is_watertight());
+    }
+
+    #[test]
+    fn test_watertight_requires_welding() {
+        use crate::geometry::surface_revolution::uv_sphere;
+
+        // Poles and the UV seam leave coincident but distinct vertices
+        let mut sphere = uv_sphere(1.0, 8, 12, Vec3::ONE);
+        assert!(!sphere.boundary_edges().is_empty());
+
+        sphere.weld_vertices(1e-5);
+        assert!(sphere.boundary_edges().is_empty());
+    }
+
+    #[test]
+    fn test_empty_mesh_not_watertight() {
+        assert!(!Mesh::new().is_watertight());
+        assert!(Mesh::new().boundary_edges().is_empty());
+    }
+
     #[test]
     fn test_clear() {
         let mut mesh = Mesh::new();
@@ -0,0 +1,90 @@
+//! Wavefront OBJ export
+//!
+//! Writes a [`Mesh`] as a text `.obj` file with positions, texture
+//! coordinates and normals. Vertex colors are appended to each `v` line
+//! (`v x y z r g b`), an extension understood by Blender, MeshLab and
+//! three.js' `OBJLoader`; readers that don't support it ignore the extra values.
+
+use crate::geometry::mesh::Mesh;
+use std::fmt::Write;
+
+/// Convert a mesh to Wavefront OBJ text
+///
+/// Every vertex gets one `v` (with color), `vt` and `vn` line, in that
+/// order, followed by one `f` line per triangle. Since attributes share the
+/// vertex index, faces use `f a/a/a b/b/b c/c/c` with 1-based indices.
+///
+/// An empty mesh produces an empty string.
+///
+/// # Arguments
+/// * `mesh` - The mesh to export
+///
+/// # Returns
+/// The contents of a `.obj` file
+///
+/// # Example
+/// ```
+/// use floraison_core::geometry::obj::mesh_to_obj;
+/// use floraison_core::geometry::surface_revolution::uv_sphere;
+/// use floraison_core::Vec3;
+///
+/// let mesh = uv_sphere(1.0, 8, 12, Vec3::ONE);
+/// let obj = mesh_to_obj(&mesh);
+///
+/// assert!(obj.starts_with("v "));
+/// assert_eq!(obj.lines().filter(|l| l.starts_with("f ")).count(), mesh.triangle_count());
+/// ```
+pub fn mesh_to_obj(mesh: &Mesh) -> String {
+    // Roughly 40 bytes per attribute line
+    let mut obj = String::with_capacity(mesh.vertex_count() * 120 + mesh.triangle_count() * 40);
+
+    for (p, c) in mesh.positions.iter().zip(&mesh.colors) {
+        // Writing to a String cannot fail
+        let _ = writeln!(obj, "v {} {} {} {} {} {}", p.x, p.y, p.z, c.x, c.y, c.z);
+    }
+    for uv in &mesh.uvs {
+        let _ = writeln!(obj, "vt {} {}", uv.x, uv.y);
+    }
+    for n in &mesh.normals {
+        let _ = writeln!(obj, "vn {} {} {}", n.x, n.y, n.z);
+    }
+    for tri in mesh.indices.chunks_exact(3) {
+        let (a, b, c) = (tri[0] + 1, tri[1] + 1, tri[2] + 1);
+        let _ = writeln!(obj, "f {a}/{a}/{a} {b}/{b}/{b} {c}/{c}/{c}");
+    }
+
+    obj
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vec2, Vec3};
+
+    fn create_triangle() -> Mesh {
+        let mut mesh = Mesh::new();
+        let v0 = mesh.add_vertex(Vec3::new(-1.0, 0.0, 0.0), Vec3::Z, Vec2::ZERO, Vec3::X);
+        let v1 = mesh.add_vertex(Vec3::new(1.0, 0.0, 0.0), Vec3::Z, Vec2::X, Vec3::Y);
+        let v2 = mesh.add_vertex(Vec3::new(0.0, 2.0, 0.5), Vec3::Z, Vec2::Y, Vec3::Z);
+        mesh.add_triangle(v0, v1, v2);
+        mesh
+    }
+
+    #[test]
+    fn test_obj_triangle() {
+        let obj = mesh_to_obj(&create_triangle());
+        let lines: Vec<&str> = obj.lines().collect();
+
+        assert_eq!(lines.len(), 3 + 3 + 3 + 1);
+        assert_eq!(lines[0], "v -1 0 0 1 0 0");
+        assert_eq!(lines[2], "v 0 2 0.5 0 0 1");
+        assert_eq!(lines[4], "vt 1 0");
+        assert_eq!(lines[6], "vn 0 0 1");
+        assert_eq!(lines[9], "f 1/1/1 2/2/2 3/3/3");
+    }
+
+    #[test]
+    fn test_obj_empty_mesh() {
+        assert!(mesh_to_obj(&Mesh::new()).is_empty());
+    }
+}
@@ -0,0 +1,506 @@
+//! Ambient occlusion baking
+//!
+//! Bakes a per-vertex ambient occlusion term into mesh vertex colors, so that
+//! crevices (between petals, inside the corolla) read as darker even when the
+//! mesh is rendered with plain vertex colors and no real-time AO.
+//!
+//! Occlusion is estimated by casting cosine-weighted hemisphere rays from each
+//! vertex against the mesh's own triangles, accelerated by a simple bounding
+//! volume hierarchy (BVH).
+
+use crate::geometry::mesh::Mesh;
+use crate::Vec3;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use std::f32::consts::PI;
+
+/// Maximum number of triangles stored in a BVH leaf
+const LEAF_SIZE: usize = 4;
+
+/// Axis-aligned bounding box
+#[derive(Debug, Clone, Copy)]
+struct Aabb {
+    min: Vec3,
+    max: Vec3,
+}
+
+impl Aabb {
+    fn empty() -> Self {
+        Self {
+            min: Vec3::splat(f32::MAX),
+            max: Vec3::splat(f32::MIN),
+        }
+    }
+
+    fn grow(&mut self, point: Vec3) {
+        self.min = self.min.min(point);
+        self.max = self.max.max(point);
+    }
+
+    /// Slab test: does the ray hit the box before `max_t`?
+    ///
+    /// A ray parallel to an axis (infinite `inv_dir` component) can't enter
+    /// or leave that slab, so it only has to start inside it. Handling this
+    /// separately avoids `0 * inf = NaN` when the origin lies on a slab plane.
+    fn hit(&self, origin: Vec3, inv_dir: Vec3, max_t: f32) -> bool {
+        let mut t_near = 0.0_f32;
+        let mut t_far = max_t;
+
+        for axis in 0..3 {
+            let (lo, hi, o) = (self.min[axis], self.max[axis], origin[axis]);
+            if inv_dir[axis].is_infinite() {
+                if o < lo || o > hi {
+                    return false;
+                }
+                continue;
+            }
+
+            let t0 = (lo - o) * inv_dir[axis];
+            let t1 = (hi - o) * inv_dir[axis];
+            t_near = t_near.max(t0.min(t1));
+            t_far = t_far.min(t0.max(t1));
+        }
+
+        t_near <= t_far
+    }
+}
+
+/// BVH node: either an interior node with two children or a leaf with triangles
+#[derive(Debug, Clone)]
+struct BvhNode {
+    bounds: Aabb,
+    /// Interior: index of left child (right child is `left + 1`)
+    /// Leaf: first index into `Bvh::order`
+    first: usize,
+    /// Number of triangles (0 for interior nodes)
+    count: usize,
+}
+
+/// Bounding volume hierarchy over a mesh's triangles
+struct Bvh {
+    triangles: Vec<[Vec3; 3]>,
+    order: Vec<usize>,
+    nodes: Vec<BvhNode>,
+}
+
+impl Bvh {
+    fn build(mesh: &Mesh) -> Self {
+        let triangles: Vec<[Vec3; 3]> = mesh
+            .indices
+            .chunks_exact(3)
+            .map(|tri| {
+                [
+                    mesh.positions[tri[0] as usize],
+                    mesh.positions[tri[1] as usize],
+                    mesh.positions[tri[2] as usize],
+                ]
+            })
+            .collect();
+        let centroids: Vec<Vec3> = triangles
+            .iter()
+            .map(|[a, b, c]| (*a + *b + *c) / 3.0)
+            .collect();
+
+        let mut bvh = Self {
+            order: (0..triangles.len()).collect(),
+            triangles,
+            nodes: Vec::new(),
+        };
+
+        if !bvh.triangles.is_empty() {
+            bvh.nodes.push(BvhNode {
+                bounds: Aabb::empty(),
+                first: 0,
+                count: bvh.triangles.len(),
+            });
+            bvh.subdivide(0, &centroids);
+        }
+
+        bvh
+    }
+
+    /// Recursively split a node at the median centroid along its longest axis
+    fn subdivide(&mut self, node_idx: usize, centroids: &[Vec3]) {
+        let (first, count) = (self.nodes[node_idx].first, self.nodes[node_idx].count);
+
+        let mut bounds = Aabb::empty();
+        let mut centroid_bounds = Aabb::empty();
+        for &tri in &self.order[first..first + count] {
+            for &v in &self.triangles[tri] {
+                bounds.grow(v);
+            }
+            centroid_bounds.grow(centroids[tri]);
+        }
+        self.nodes[node_idx].bounds = bounds;
+
+        if count <= LEAF_SIZE {
+            return;
+        }
+
+        let extent = centroid_bounds.max - centroid_bounds.min;
+        let axis = if extent.x >= extent.y && extent.x >= extent.z {
+            0
+        } else if extent.y >= extent.z {
+            1
+        } else {
+            2
+        };
+
+        let mid = count / 2;
+        self.order[first..first + count].select_nth_unstable_by(mid, |&a, &b| {
+            centroids[a][axis].total_cmp(&centroids[b][axis])
+        });
+
+        let left = self.nodes.len();
+        self.nodes.push(BvhNode {
+            bounds: Aabb::empty(),
+            first,
+            count: mid,
+        });
+        self.nodes.push(BvhNode {
+            bounds: Aabb::empty(),
+            first: first + mid,
+            count: count - mid,
+        });
+        self.nodes[node_idx].first = left;
+        self.nodes[node_idx].count = 0;
+
+        self.subdivide(left, centroids);
+        self.subdivide(left + 1, centroids);
+    }
+
+    /// Does a ray hit any triangle within distance `max_t`?
+    fn occluded(&self, origin: Vec3, direction: Vec3, max_t: f32) -> bool {
+        if self.nodes.is_empty() {
+            return false;
+        }
+
+        let inv_dir = direction.recip();
+        let mut stack = vec![0usize];
+
+        while let Some(node_idx) = stack.pop() {
+            let node = &self.nodes[node_idx];
+            if !node.bounds.hit(origin, inv_dir, max_t) {
+                continue;
+            }
+
+            if node.count == 0 {
+                stack.push(node.first);
+                stack.push(node.first + 1);
+                continue;
+            }
+
+            for &tri in &self.order[node.first..node.first + node.count] {
+                if let Some(t) = ray_triangle(origin, direction, &self.triangles[tri]) {
+                    if t <= max_t {
+                        return true;
+                    }
+                }
+            }
+        }
+
+        false
+    }
+}
+
+/// Möller–Trumbore ray/triangle intersection (double-sided)
+///
+/// Returns the ray parameter of the hit, if any, ignoring hits at t ≈ 0.
+fn ray_triangle(origin: Vec3, direction: Vec3, [a, b, c]: &[Vec3; 3]) -> Option<f32> {
+    const EPS: f32 = 1e-7;
+
+    let edge1 = *b - *a;
+    let edge2 = *c - *a;
+    let p = direction.cross(edge2);
+    let det = edge1.dot(p);
+    if det.abs() < EPS {
+        return None; // Ray parallel to triangle
+    }
+
+    let inv_det = 1.0 / det;
+    let s = origin - *a;
+    let u = s.dot(p) * inv_det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let q = s.cross(edge1);
+    let v = direction.dot(q) * inv_det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    let t = edge2.dot(q) * inv_det;
+    (t > 1e-5).then_some(t)
+}
+
+/// Cosine-weighted random direction in the hemisphere around `normal`
+fn sample_hemisphere(normal: Vec3, rng: &mut SmallRng) -> Vec3 {
+    let r1: f32 = rng.gen();
+    let r2: f32 = rng.gen();
+
+    let phi = 2.0 * PI * r1;
+    let r = r2.sqrt();
+    let local = Vec3::new(r * phi.cos(), r * phi.sin(), (1.0 - r2).sqrt());
+
+    let (tangent, bitangent) = normal.any_orthonormal_pair();
+    (tangent * local.x + bitangent * local.y + normal * local.z).normalize()
+}
+
+impl Mesh {
+    /// Bake ambient occlusion into vertex colors
+    ///
+    /// For each vertex, casts `samples` cosine-weighted rays into the hemisphere
+    /// around its normal and counts how many hit the mesh within `radius`.
+    /// The vertex color is then darkened by the occluded fraction
+    /// (`color *= 1 - occlusion`). Fully open vertices keep their color.
+    ///
+    /// This is compute-heavy (vertices × samples rays) and intended to run once,
+    /// e.g. before export, rather than per frame. Results are deterministic for
+    /// a given `seed`.
+    ///
+    /// # Arguments
+    /// * `samples` - Rays per vertex (16-64 typical)
+    /// * `radius` - Maximum occluder distance; larger values darken more broadly
+    /// * `seed` - Seed for the ray direction sampler
+    ///
+    /// # Panics
+    /// Panics if `samples == 0` or `radius <= 0`
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec2, Vec3};
+    ///
+    /// // Floor with a wall standing just beside it
+    /// let mut mesh = Mesh::new();
+    /// let corner = [
+    ///     Vec3::new(0.2, 0.0, 0.0),
+    ///     Vec3::new(1.2, 0.0, 0.0),
+    ///     Vec3::new(1.2, 0.0, 1.0),
+    ///     Vec3::new(0.2, 0.0, 1.0),
+    /// ];
+    /// let floor: Vec<u32> = corner
+    ///     .iter()
+    ///     .map(|&p| mesh.add_vertex(p, Vec3::Y, Vec2::ZERO, Vec3::ONE))
+    ///     .collect();
+    /// mesh.add_quad(floor[0], floor[3], floor[2], floor[1]);
+    ///
+    /// let wall: Vec<u32> = [(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)]
+    ///     .iter()
+    ///     .map(|&(y, z)| mesh.add_vertex(Vec3::new(0.0, y, z), Vec3::X, Vec2::ZERO, Vec3::ONE))
+    ///     .collect();
+    /// mesh.add_quad(wall[0], wall[1], wall[2], wall[3]);
+    ///
+    /// mesh.bake_ambient_occlusion(32, 1.0, 7);
+    ///
+    /// // Floor vertex next to the wall is darker than the far corner
+    /// assert!(mesh.colors[0].x < mesh.colors[2].x);
+    /// ```
+    pub fn bake_ambient_occlusion(&mut self, samples: usize, radius: f32, seed: u64) {
+        assert!(samples > 0, "Need at least 1 sample per vertex");
+        assert!(radius > 0.0, "Occlusion radius must be positive");
+
+        let bvh = Bvh::build(self);
+        let mut rng = SmallRng::seed_from_u64(seed);
+
+        // Offset ray origins slightly off the surface to avoid self-hits
+        let bias = radius * 1e-3;
+
+        for i in 0..self.positions.len() {
+            let normal = self.normals[i].try_normalize().unwrap_or(Vec3::Y);
+            let origin = self.positions[i] + normal * bias;
+
+            let hits = (0..samples)
+                .filter(|_| {
+                    let direction = sample_hemisphere(normal, &mut rng);
+                    bvh.occluded(origin, direction, radius)
+                })
+                .count();
+
+            let occlusion = hits as f32 / samples as f32;
+            self.colors[i] *= 1.0 - occlusion;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::surface_revolution::uv_sphere;
+    use crate::Vec2;
+
+    fn create_quad(mesh: &mut Mesh, corners: [Vec3; 4], normal: Vec3) {
+        let ids: Vec<u32> = corners
+            .iter()
+            .map(|&p| mesh.add_vertex(p, normal, Vec2::ZERO, Vec3::ONE))
+            .collect();
+        mesh.add_quad(ids[0], ids[1], ids[2], ids[3]);
+    }
+
+    #[test]
+    fn test_isolated_plane_unoccluded() {
+        let mut mesh = Mesh::new();
+        create_quad(
+            &mut mesh,
+            [
+                Vec3::ZERO,
+                Vec3::new(0.0, 0.0, 1.0),
+                Vec3::new(1.0, 0.0, 1.0),
+                Vec3::new(1.0, 0.0, 0.0),
+            ],
+            Vec3::Y,
+        );
+
+        mesh.bake_ambient_occlusion(16, 1.0, 1);
+
+        for c in &mesh.colors {
+            assert_eq!(*c, Vec3::ONE, "Open plane should not be darkened");
+        }
+    }
+
+    #[test]
+    fn test_inside_sphere_fully_occluded() {
+        // Flip normals inward: every vertex looks at the rest of the sphere
+        let mut mesh = uv_sphere(1.0, 12, 16, Vec3::ONE);
+        for (n, p) in mesh.normals.iter_mut().zip(&mesh.positions) {
+            *n = -p.normalize();
+        }
+
+        mesh.bake_ambient_occlusion(16, 3.0, 3);
+
+        let avg = mesh.colors.iter().map(|c| c.x).sum::<f32>() / mesh.colors.len() as f32;
+        assert!(avg < 0.1, "Interior should be almost black, got {}", avg);
+    }
+
+    #[test]
+    fn test_radius_limits_occlusion() {
+        // Two parallel plates 1.0 apart
+        let mut mesh = Mesh::new();
+        create_quad(
+            &mut mesh,
+            [
+                Vec3::new(-1.0, 0.0, -1.0),
+                Vec3::new(-1.0, 0.0, 1.0),
+                Vec3::new(1.0, 0.0, 1.0),
+                Vec3::new(1.0, 0.0, -1.0),
+            ],
+            Vec3::Y,
+        );
+        create_quad(
+            &mut mesh,
+            [
+                Vec3::new(-1.0, 1.0, -1.0),
+                Vec3::new(1.0, 1.0, -1.0),
+                Vec3::new(1.0, 1.0, 1.0),
+                Vec3::new(-1.0, 1.0, 1.0),
+            ],
+            -Vec3::Y,
+        );
+
+        let mut short = mesh.clone();
+        short.bake_ambient_occlusion(32, 0.5, 9);
+        assert!(short.colors.iter().all(|c| *c == Vec3::ONE));
+
+        mesh.bake_ambient_occlusion(32, 5.0, 9);
+        assert!(mesh.colors.iter().all(|c| c.x < 1.0));
+    }
+
+    #[test]
+    fn test_slab_parallel_rays() {
+        // Flat box, as around a single axis-aligned quad
+        let bounds = Aabb {
+            min: Vec3::new(0.0, 0.0, 0.0),
+            max: Vec3::new(1.0, 0.0, 1.0),
+        };
+        let hit = |origin: Vec3, direction: Vec3| bounds.hit(origin, direction.recip(), 5.0);
+
+        // Origins on slab planes: (lo - o) * inf would be NaN
+        assert!(hit(Vec3::new(0.0, 1.0, 0.5), -Vec3::Y));
+        assert!(hit(Vec3::new(1.0, 0.0, -1.0), Vec3::Z));
+        assert!(hit(Vec3::new(0.5, 0.0, 0.5), Vec3::X));
+
+        // Parallel rays outside a slab never hit
+        assert!(!hit(Vec3::new(0.5, 0.1, -1.0), Vec3::Z));
+        assert!(!hit(Vec3::new(2.0, 1.0, 0.5), -Vec3::Y));
+
+        // Behind the origin or beyond max_t
+        assert!(!hit(Vec3::new(0.5, 1.0, 0.5), Vec3::Y));
+        assert!(!hit(Vec3::new(0.5, 6.0, 0.5), -Vec3::Y));
+    }
+
+    #[test]
+    fn test_axis_aligned_bake_is_finite() {
+        // Axis-aligned plates: many rays are parallel to some box axis
+        let mut mesh = Mesh::new();
+        create_quad(
+            &mut mesh,
+            [
+                Vec3::ZERO,
+                Vec3::new(0.0, 0.0, 1.0),
+                Vec3::new(1.0, 0.0, 1.0),
+                Vec3::new(1.0, 0.0, 0.0),
+            ],
+            Vec3::Y,
+        );
+        create_quad(
+            &mut mesh,
+            [
+                Vec3::ZERO,
+                Vec3::new(0.0, 1.0, 0.0),
+                Vec3::new(0.0, 1.0, 1.0),
+                Vec3::new(0.0, 0.0, 1.0),
+            ],
+            Vec3::X,
+        );
+
+        mesh.bake_ambient_occlusion(32, 2.0, 11);
+
+        for c in &mesh.colors {
+            assert!(c.is_finite() && c.min_element() >= 0.0 && c.max_element() <= 1.0);
+        }
+        assert!(mesh.colors.iter().any(|c| c.x < 1.0));
+    }
+
+    #[test]
+    fn test_deterministic_with_seed() {
+        let mut a = uv_sphere(1.0, 8, 8, Vec3::ONE);
+        for (n, p) in a.normals.iter_mut().zip(&a.positions) {
+            *n = -p.normalize();
+        }
+        let mut b = a.clone();
+
+        a.bake_ambient_occlusion(8, 0.8, 42);
+        b.bake_ambient_occlusion(8, 0.8, 42);
+        assert_eq!(a.colors, b.colors);
+    }
+
+    #[test]
+    fn test_bvh_matches_brute_force() {
+        let mesh = uv_sphere(1.0, 10, 12, Vec3::ONE);
+        let bvh = Bvh::build(&mesh);
+        let mut rng = SmallRng::seed_from_u64(5);
+
+        for _ in 0..200 {
+            let origin = Vec3::new(
+                rng.gen_range(-2.0..2.0),
+                rng.gen_range(-2.0..2.0),
+                rng.gen_range(-2.0..2.0),
+            );
+            let direction = sample_hemisphere(Vec3::Y, &mut rng);
+            let max_t = rng.gen_range(0.1..4.0);
+
+            let brute = bvh
+                .triangles
+                .iter()
+                .any(|tri| ray_triangle(origin, direction, tri).is_some_and(|t| t <= max_t));
+            assert_eq!(bvh.occluded(origin, direction, max_t), brute);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 1 sample")]
+    fn test_zero_samples() {
+        Mesh::new().bake_ambient_occlusion(0, 1.0, 0);
+    }
+}
@@ -194,6 +194,104 @@ pub fn surface_of_revolution(profile: &[Vec2], segments: usize, color: Vec3) ->
     mesh
 }
 
+/// Generate a surface of revolution with optional flat end caps
+///
+/// Same as [`surface_of_revolution`], but seals open profile ends with a flat
+/// disc so that e.g. a receptacle does not look hollow from below. Each cap is a
+/// triangle fan around a new center vertex on the Y axis. Cap vertices are
+/// separate from the side vertices so the rim stays sharp: cap normals point
+/// straight down (bottom) or up (top), and cap UVs map the disc radially into
+/// the unit square with the center at (0.5, 0.5).
+///
+/// Ends whose radius is already zero (poles) are closed by the profile itself
+/// and are never capped.
+///
+/// Because the rim vertices are duplicated, the result only becomes watertight
+/// by index after [`Mesh::weld_vertices`].
+///
+/// # Arguments
+///
+/// * `profile` - Profile curve (x=radius, y=height), bottom to top
+/// * `segments` - Number of angular divisions around the axis
+/// * `color` - Vertex color
+/// * `cap_bottom` - Seal the first profile point's ring
+/// * `cap_top` - Seal the last profile point's ring
+///
+/// # Panics
+///
+/// Panics if the profile is empty or `segments < 3`
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::geometry::surface_revolution::surface_of_revolution_capped;
+/// use floraison_core::{Vec2, Vec3};
+///
+/// let profile = vec![Vec2::new(1.0, 0.0), Vec2::new(1.0, 2.0)];
+/// let mesh = surface_of_revolution_capped(&profile, 16, Vec3::ONE, true, true);
+///
+/// // 2 side rings + 2 cap rims + 2 cap centers
+/// assert_eq!(mesh.vertex_count(), 16 * 4 + 2);
+/// // Side quads + one fan triangle per segment and cap
+/// assert_eq!(mesh.triangle_count(), 16 * 2 + 16 * 2);
+/// ```
+pub fn surface_of_revolution_capped(
+    profile: &[Vec2],
+    segments: usize,
+    color: Vec3,
+    cap_bottom: bool,
+    cap_top: bool,
+) -> Mesh {
+    let mut mesh = surface_of_revolution(profile, segments, color);
+
+    let first = profile[0];
+    let last = profile[profile.len() - 1];
+
+    if cap_bottom && first.x.abs() >= 1e-6 {
+        add_cap(&mut mesh, first, segments, color, false);
+    }
+    if cap_top && last.x.abs() >= 1e-6 {
+        add_cap(&mut mesh, last, segments, color, true);
+    }
+
+    mesh
+}
+
+/// Append a flat triangle-fan cap for the ring at `point` (x=radius, y=height)
+fn add_cap(mesh: &mut Mesh, point: Vec2, segments: usize, color: Vec3, top: bool) {
+    let normal = if top { Vec3::Y } else { -Vec3::Y };
+    let angle_step = 2.0 * PI / segments as f32;
+
+    let center = mesh.add_vertex(
+        Vec3::new(0.0, point.y, 0.0),
+        normal,
+        Vec2::new(0.5, 0.5),
+        color,
+    );
+
+    let rim_start = mesh.vertex_count() as u32;
+    for seg in 0..segments {
+        let angle = seg as f32 * angle_step;
+        let (sin, cos) = angle.sin_cos();
+        let pos = Vec3::new(point.x * cos, point.y, point.x * sin);
+        let uv = Vec2::new(0.5 + 0.5 * cos, 0.5 + 0.5 * sin);
+        mesh.add_vertex(pos, normal, uv, color);
+    }
+
+    for seg in 0..segments {
+        let a = rim_start + seg as u32;
+        let b = rim_start + ((seg + 1) % segments) as u32;
+
+        // Increasing angle runs clockwise seen from above, so the bottom cap
+        // uses (center, a, b) to face -Y and the top cap reverses it
+        if top {
+            mesh.add_triangle(center, b, a);
+        } else {
+            mesh.add_triangle(center, a, b);
+        }
+    }
+}
+
 /// Create a cylinder mesh
 ///
 /// A convenience function for creating a simple cylinder.
@@ -284,6 +382,58 @@ mod tests {
 
     const EPSILON: f32 = 1e-5;
 
+    #[test]
+    fn test_capped_cylinder_watertight() {
+        let profile = vec![Vec2::new(1.0, 0.0), Vec2::new(1.0, 2.0)];
+        let mut mesh = surface_of_revolution_capped(&profile, 12, Vec3::ONE, true, true);
+
+        // Open without caps
+        let open = surface_of_revolution(&profile, 12, Vec3::ONE);
+        assert!(!open.boundary_edges().is_empty());
+
+        mesh.weld_vertices(1e-5);
+        assert!(
+            mesh.is_watertight(),
+            "Every edge should be shared by exactly two triangles"
+        );
+    }
+
+    #[test]
+    fn test_cap_normals_and_winding() {
+        let profile = vec![Vec2::new(0.5, 1.0), Vec2::new(1.0, 3.0)];
+        let mesh = surface_of_revolution_capped(&profile, 8, Vec3::ONE, true, true);
+
+        let side_tris = surface_of_revolution(&profile, 8, Vec3::ONE).triangle_count();
+        for (i, tri) in mesh.indices.chunks_exact(3).enumerate().skip(side_tris) {
+            let [a, b, c] = [tri[0] as usize, tri[1] as usize, tri[2] as usize];
+            let face = (mesh.positions[b] - mesh.positions[a])
+                .cross(mesh.positions[c] - mesh.positions[a])
+                .normalize();
+
+            let expected = if i < side_tris + 8 { -Vec3::Y } else { Vec3::Y };
+            assert!(
+                (face - expected).length() < EPSILON,
+                "Cap should face outward"
+            );
+            assert_eq!(mesh.normals[a], expected);
+        }
+
+        // Bottom cap rim UVs lie on the circle around (0.5, 0.5)
+        let rim_uv = mesh.uvs[16 + 1];
+        assert!(((rim_uv - Vec2::splat(0.5)).length() - 0.5).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_capped_skips_poles() {
+        let profile = vec![Vec2::new(1.0, 0.0), Vec2::new(0.0, 2.0)];
+        let capped = surface_of_revolution_capped(&profile, 8, Vec3::ONE, true, true);
+        let plain = surface_of_revolution(&profile, 8, Vec3::ONE);
+
+        // Only the bottom (non-pole) end gets a cap
+        assert_eq!(capped.vertex_count(), plain.vertex_count() + 9);
+        assert_eq!(capped.triangle_count(), plain.triangle_count() + 8);
+    }
+
     #[test]
     fn test_cylinder() {
         let mesh = cylinder(1.0, 2.0, 8, Vec3::ONE);
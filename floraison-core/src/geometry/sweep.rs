@@ -54,7 +54,64 @@ use std::f32::consts::PI;
 /// assert!(mesh.vertex_count() > 0);
 /// ```
 pub fn sweep_along_curve(profile: &[Vec2], curve: &[Vec3], segments: usize, color: Vec3) -> Mesh {
+    sweep_along_curve_tapered(profile, curve, &[1.0], segments, color)
+}
+
+/// Sweep a 2D profile along a 3D curve, scaling the profile along the way
+///
+/// Same as [`sweep_along_curve`], but the profile radius at curve point `i` is
+/// multiplied by `radii[i]`. If `radii` has a different length than `curve`,
+/// it is treated as evenly spaced samples over the curve and linearly
+/// interpolated, so `&[1.0, 0.5]` tapers to half radius regardless of how
+/// finely the curve is sampled.
+///
+/// # Arguments
+///
+/// * `profile` - 2D profile points where x=radius, y=offset along curve
+/// * `curve` - 3D curve path (should be smoothly sampled)
+/// * `radii` - Radius scale factors along the curve
+/// * `segments` - Number of angular divisions around the curve (8-32 typical)
+/// * `color` - Vertex color
+///
+/// # Returns
+///
+/// A mesh with the swept surface geometry
+///
+/// # Panics
+///
+/// Panics if:
+/// - `profile` or `radii` is empty
+/// - `curve` has fewer than 2 points
+/// - `segments` < 3
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::geometry::sweep::sweep_along_curve_tapered;
+/// use floraison_core::{Vec2, Vec3};
+///
+/// let profile = vec![Vec2::new(0.1, 0.0), Vec2::new(0.1, 1.0)];
+/// let curve = vec![
+///     Vec3::new(0.0, 0.0, 0.0),
+///     Vec3::new(0.0, 0.5, 0.0),
+///     Vec3::new(0.0, 1.0, 0.0),
+/// ];
+///
+/// // Taper from full radius at the base to half at the tip
+/// let mesh = sweep_along_curve_tapered(&profile, &curve, &[1.0, 0.5], 8, Vec3::ONE);
+///
+/// let tip = mesh.positions.last().unwrap();
+/// assert!((Vec3::new(tip.x, 0.0, tip.z).length() - 0.05).abs() < 1e-5);
+/// ```
+pub fn sweep_along_curve_tapered(
+    profile: &[Vec2],
+    curve: &[Vec3],
+    radii: &[f32],
+    segments: usize,
+    color: Vec3,
+) -> Mesh {
     assert!(!profile.is_empty(), "Profile cannot be empty");
+    assert!(!radii.is_empty(), "Radii cannot be empty");
     assert!(curve.len() >= 2, "Curve must have at least 2 points");
     assert!(segments >= 3, "Need at least 3 segments");
 
@@ -77,10 +134,11 @@ pub fn sweep_along_curve(profile: &[Vec2], curve: &[Vec3], segments: usize, colo
         // Interpolate profile radius at this curve position
         // Map curve_idx to profile y-coordinate
         let curve_t = curve_idx as f32 / (num_curve_points - 1) as f32;
+        let radius_scale = sample_radius_scale(radii, curve_idx, num_curve_points, curve_t);
 
         // For each profile point
         for profile_point in profile {
-            let radius = profile_point.x;
+            let radius = profile_point.x * radius_scale;
 
             // Create a ring of vertices around the curve
             for seg_idx in 0..segments {
@@ -131,6 +189,21 @@ pub fn sweep_along_curve(profile: &[Vec2], curve: &[Vec3], segments: usize, colo
     mesh
 }
 
+/// Radius scale at a curve point, interpolating `radii` if its length differs from the curve
+fn sample_radius_scale(radii: &[f32], curve_idx: usize, num_curve_points: usize, t: f32) -> f32 {
+    if radii.len() == num_curve_points {
+        return radii[curve_idx];
+    }
+    if radii.len() == 1 {
+        return radii[0];
+    }
+
+    let x = t * (radii.len() - 1) as f32;
+    let i = (x.floor() as usize).min(radii.len() - 2);
+    let frac = x - i as f32;
+    radii[i] + (radii[i + 1] - radii[i]) * frac
+}
+
 /// Compute tangent vectors at each point along a curve
 ///
 /// Uses central differences for interior points and forward/backward
@@ -201,15 +274,143 @@ pub fn sweep_tapered_cylinder(
     color: Vec3,
 ) -> Mesh {
     assert!(curve.len() >= 2, "Curve must have at least 2 points");
+
+    let num_curve_points = curve.len();
+    let radii: Vec<f32> = (0..num_curve_points)
+        .map(|i| {
+            let t = i as f32 / (num_curve_points - 1) as f32;
+            base_radius + (tip_radius - base_radius) * t
+        })
+        .collect();
+
+    sweep_with_radii(&radii, curve, segments, color)
+}
+
+/// Sweep a circular cross-section with a per-point radius along a 3D curve
+///
+/// Generalization of [`sweep_tapered_cylinder`] where the radius at each curve
+/// point is given explicitly, allowing arbitrary radius profiles (bulging nodes,
+/// non-linear taper, etc.). Uses rotation-minimizing frames to avoid twist.
+///
+/// # Arguments
+///
+/// * `radii` - Radius at each curve point (same length as `curve`)
+/// * `curve` - 3D curve path (should be smoothly sampled)
+/// * `segments` - Number of angular divisions around the curve (8-32 typical)
+/// * `color` - Vertex color
+///
+/// # Returns
+///
+/// A mesh with one ring of `segments` vertices per curve point
+///
+/// # Panics
+///
+/// Panics if:
+/// - `curve` has fewer than 2 points
+/// - `radii.len() != curve.len()`
+/// - `segments` < 3
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::geometry::sweep::sweep_with_radii;
+/// use floraison_core::Vec3;
+///
+/// let curve = vec![
+///     Vec3::new(0.0, 0.0, 0.0),
+///     Vec3::new(0.0, 1.0, 0.0),
+///     Vec3::new(0.0, 2.0, 0.0),
+/// ];
+///
+/// // Thicker in the middle
+/// let mesh = sweep_with_radii(&[0.1, 0.2, 0.1], &curve, 8, Vec3::ONE);
+/// assert_eq!(mesh.vertex_count(), 3 * 8);
+/// ```
+pub fn sweep_with_radii(radii: &[f32], curve: &[Vec3], segments: usize, color: Vec3) -> Mesh {
     assert!(segments >= 3, "Need at least 3 segments");
 
+    // Unit circle cross-section
+    let section: Vec<Vec2> = (0..segments)
+        .map(|seg_idx| {
+            let angle = seg_idx as f32 * 2.0 * PI / segments as f32;
+            Vec2::new(angle.cos(), angle.sin())
+        })
+        .collect();
+
+    sweep_cross_section(&section, radii, curve, color)
+}
+
+/// Sweep an arbitrary closed cross-section with a per-point scale along a 3D curve
+///
+/// Generalization of [`sweep_with_radii`] to non-circular cross-sections
+/// (square stems, ridged stems, etc.). `section` is a closed polygon in the
+/// plane perpendicular to the curve, given counter-clockwise in (right, up)
+/// frame coordinates; at curve point `i` it is scaled by `radii[i]`. Vertex
+/// normals are perpendicular to the polygon at each section point (averaging
+/// the two adjacent edges). Uses rotation-minimizing frames to avoid twist.
+///
+/// # Arguments
+///
+/// * `section` - Closed cross-section polygon, counter-clockwise, around the origin
+/// * `radii` - Section scale at each curve point (same length as `curve`)
+/// * `curve` - 3D curve path (should be smoothly sampled)
+/// * `color` - Vertex color
+///
+/// # Returns
+///
+/// A mesh with one ring of `section.len()` vertices per curve point
+///
+/// # Panics
+///
+/// Panics if:
+/// - `curve` has fewer than 2 points
+/// - `radii.len() != curve.len()`
+/// - `section` has fewer than 3 points
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::geometry::sweep::sweep_cross_section;
+/// use floraison_core::{Vec2, Vec3};
+///
+/// let square = vec![
+///     Vec2::new(1.0, -1.0),
+///     Vec2::new(1.0, 1.0),
+///     Vec2::new(-1.0, 1.0),
+///     Vec2::new(-1.0, -1.0),
+/// ];
+/// let curve = vec![Vec3::ZERO, Vec3::new(0.0, 1.0, 0.0)];
+///
+/// let mesh = sweep_cross_section(&square, &[0.1, 0.1], &curve, Vec3::ONE);
+/// assert_eq!(mesh.vertex_count(), 2 * 4);
+/// ```
+pub fn sweep_cross_section(section: &[Vec2], radii: &[f32], curve: &[Vec3], color: Vec3) -> Mesh {
+    assert!(curve.len() >= 2, "Curve must have at least 2 points");
+    assert_eq!(
+        radii.len(),
+        curve.len(),
+        "Need exactly one radius per curve point"
+    );
+    assert!(section.len() >= 3, "Need at least 3 segments");
+
     let num_curve_points = curve.len();
+    let segments = section.len();
 
     // Pre-allocate mesh capacity (one ring per curve point)
     let vertex_capacity = num_curve_points * segments;
     let triangle_capacity = (num_curve_points - 1) * segments * 2 * 3;
     let mut mesh = Mesh::with_capacity(vertex_capacity, triangle_capacity);
 
+    // Outward 2D normal at each section point, from the adjacent edges
+    let section_normals: Vec<Vec2> = (0..segments)
+        .map(|seg_idx| {
+            let prev = section[(seg_idx + segments - 1) % segments];
+            let next = section[(seg_idx + 1) % segments];
+            let edge = next - prev;
+            Vec2::new(edge.y, -edge.x).normalize_or_zero()
+        })
+        .collect();
+
     // Compute tangents at each curve point
     let tangents = compute_curve_tangents(curve);
 
@@ -217,23 +418,22 @@ pub fn sweep_tapered_cylinder(
     let frames = compute_rotation_minimizing_frames(&tangents);
 
     // For each point along the curve
-    for (curve_idx, (&curve_point, &(right, up))) in curve.iter().zip(frames.iter()).enumerate() {
-        // Interpolate radius based on position along curve
+    for (curve_idx, ((&curve_point, &(right, up)), &radius)) in curve
+        .iter()
+        .zip(frames.iter())
+        .zip(radii.iter())
+        .enumerate()
+    {
         let t = curve_idx as f32 / (num_curve_points - 1) as f32;
-        let radius = base_radius + (tip_radius - base_radius) * t;
 
         // Create a ring of vertices around the curve
-        for seg_idx in 0..segments {
-            let angle = seg_idx as f32 * 2.0 * PI / segments as f32;
-            let cos_a = angle.cos();
-            let sin_a = angle.sin();
-
-            // Position vertex in circular cross-section
-            let local_pos = right * (radius * cos_a) + up * (radius * sin_a);
+        for (seg_idx, (point, normal)) in section.iter().zip(&section_normals).enumerate() {
+            // Position vertex in the scaled cross-section
+            let local_pos = right * (radius * point.x) + up * (radius * point.y);
             let position = curve_point + local_pos;
 
-            // Normal points radially outward from curve
-            let normal = (right * cos_a + up * sin_a).normalize();
+            // Normal perpendicular to the cross-section outline
+            let normal = (right * normal.x + up * normal.y).normalize_or_zero();
 
             // UV coordinates
             let u = seg_idx as f32 / segments as f32;
@@ -477,6 +677,95 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sweep_with_radii_ring_sizes() {
+        let curve = vec![Vec3::ZERO, Vec3::Y, Vec3::new(0.0, 2.0, 0.0)];
+        let radii = [0.1, 0.3, 0.2];
+        let segments = 8;
+
+        let mesh = sweep_with_radii(&radii, &curve, segments, Vec3::ONE);
+        assert_eq!(mesh.vertex_count(), 3 * segments);
+
+        for (ring, &expected) in radii.iter().enumerate() {
+            for seg in 0..segments {
+                let p = mesh.positions[ring * segments + seg];
+                let r = (p.x * p.x + p.z * p.z).sqrt();
+                assert!((r - expected).abs() < 1e-5, "ring {} radius {}", ring, r);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sweep_cross_section_square() {
+        let square = vec![
+            Vec2::new(1.0, -1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(-1.0, 1.0),
+            Vec2::new(-1.0, -1.0),
+        ];
+        let curve = vec![
+            Vec3::ZERO,
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+        ];
+
+        let mesh = sweep_cross_section(&square, &[0.1, 0.2, 0.1], &curve, Vec3::ONE);
+        assert_eq!(mesh.vertex_count(), 3 * 4);
+        assert!(mesh.validate().is_ok());
+
+        // Corners sit at radius × √2 from the curve, normals point outward
+        for (position, normal) in mesh.positions.iter().zip(&mesh.normals) {
+            let radial = Vec3::new(position.x, 0.0, position.z);
+            let expected = if position.y == 1.0 { 0.2 } else { 0.1 };
+            assert!((radial.length() - expected * 2f32.sqrt()).abs() < 1e-5);
+            assert!(normal.dot(radial) > 0.0);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "one radius per curve point")]
+    fn test_sweep_with_radii_length_mismatch() {
+        let curve = vec![Vec3::ZERO, Vec3::Y];
+        sweep_with_radii(&[0.1], &curve, 8, Vec3::ONE);
+    }
+
+    #[test]
+    fn test_sweep_tapered_interpolates_radii() {
+        let profile = vec![Vec2::new(1.0, 0.0)];
+        let curve: Vec<Vec3> = (0..5).map(|i| Vec3::new(0.0, i as f32, 0.0)).collect();
+
+        // Two radii spread over five curve points
+        let mesh = sweep_along_curve_tapered(&profile, &curve, &[1.0, 0.5], 8, Vec3::ONE);
+
+        for (ring, expected) in [1.0, 0.875, 0.75, 0.625, 0.5].iter().enumerate() {
+            let p = mesh.positions[ring * 8];
+            let r = Vec3::new(p.x, 0.0, p.z).length();
+            assert!((r - expected).abs() < 1e-5, "ring {} radius {}", ring, r);
+        }
+    }
+
+    #[test]
+    fn test_sweep_tapered_matching_length() {
+        let profile = vec![Vec2::new(2.0, 0.0)];
+        let curve = vec![Vec3::ZERO, Vec3::Y, Vec3::Y * 2.0];
+
+        let mesh = sweep_along_curve_tapered(&profile, &curve, &[1.0, 0.1, 0.5], 6, Vec3::ONE);
+        let radius_at = |ring: usize| {
+            let p = mesh.positions[ring * 6];
+            Vec3::new(p.x, 0.0, p.z).length()
+        };
+
+        assert!((radius_at(1) - 0.2).abs() < 1e-5);
+        assert!((radius_at(2) - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Radii cannot be empty")]
+    fn test_sweep_tapered_empty_radii() {
+        let profile = vec![Vec2::new(1.0, 0.0)];
+        sweep_along_curve_tapered(&profile, &[Vec3::ZERO, Vec3::Y], &[], 8, Vec3::ONE);
+    }
+
     #[test]
     #[should_panic(expected = "Profile cannot be empty")]
     fn test_sweep_empty_profile() {
@@ -0,0 +1,450 @@
+//! Mesh tessellation
+//!
+//! Turns outlines and parametric surfaces into triangle meshes.
+//!
+//! - [`tessellate_polygon`] triangulates a simple 2D polygon (convex or concave)
+//!   with ear clipping, e.g. a flat petal outline with a notched tip.
+//! - [`tessellate_surface_adaptive`] samples a B-spline surface densely only
+//!   where it curves.
+
+use crate::{geometry::mesh::Mesh, math::bspline::BSplineSurface, Vec2, Vec3};
+
+/// Points closer than this are treated as duplicates
+const DUPLICATE_EPSILON: f32 = 1e-6;
+
+/// Maximum recursion depth per parametric direction (up to 2^8 = 256 spans)
+const MAX_ADAPTIVE_DEPTH: u32 = 8;
+
+/// Number of lines across the other direction probed when testing a span
+const ADAPTIVE_PROBES: usize = 9;
+
+/// Triangulate a simple polygon in the XY plane using ear clipping
+///
+/// The outline may be convex or concave and in either winding order, but must
+/// not self-intersect. Consecutive duplicate points and collinear points are
+/// skipped. The resulting triangles are wound counter-clockwise when seen from
+/// +Z, and every vertex has a +Z normal. UVs map the outline's bounding box to
+/// the unit square.
+///
+/// # Arguments
+/// * `outline` - Polygon vertices in order (the closing edge is implicit)
+/// * `color` - Vertex color
+///
+/// # Returns
+/// A flat mesh with one vertex per remaining outline point and
+/// `point_count - 2` triangles, or an empty mesh if fewer than three
+/// non-degenerate points remain
+///
+/// # Example
+/// ```
+/// use floraison_core::geometry::tessellation::tessellate_polygon;
+/// use floraison_core::{Vec2, Vec3};
+///
+/// // L-shaped (concave) outline
+/// let outline = [
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(2.0, 0.0),
+///     Vec2::new(2.0, 1.0),
+///     Vec2::new(1.0, 1.0),
+///     Vec2::new(1.0, 2.0),
+///     Vec2::new(0.0, 2.0),
+/// ];
+///
+/// let mesh = tessellate_polygon(&outline, Vec3::ONE);
+/// assert_eq!(mesh.triangle_count(), 4);
+/// assert!((mesh.surface_area() - 3.0).abs() < 1e-5);
+/// ```
+pub fn tessellate_polygon(outline: &[Vec2], color: Vec3) -> Mesh {
+    let mut points = clean_outline(outline);
+    if points.len() < 3 {
+        return Mesh::new();
+    }
+
+    // Work in counter-clockwise order so convex corners have positive cross products
+    if signed_area(&points) < 0.0 {
+        points.reverse();
+    }
+
+    let mut mesh = Mesh::with_capacity(points.len(), (points.len() - 2) * 3);
+
+    let min = points.iter().fold(Vec2::splat(f32::MAX), |m, &p| m.min(p));
+    let max = points.iter().fold(Vec2::splat(f32::MIN), |m, &p| m.max(p));
+    let size = (max - min).max(Vec2::splat(f32::EPSILON));
+
+    for &p in &points {
+        mesh.add_vertex(p.extend(0.0), Vec3::Z, (p - min) / size, color);
+    }
+
+    // Ear clipping over the remaining vertex ring
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let ear = (0..n)
+            .find(|&i| is_ear(&points, &remaining, i))
+            // Numerical trouble on nearly degenerate input: clip any convex corner
+            .or_else(|| (0..n).find(|&i| corner_cross(&points, &remaining, i) > 0.0))
+            .unwrap_or(0);
+
+        let prev = remaining[(ear + n - 1) % n];
+        let next = remaining[(ear + 1) % n];
+        mesh.add_triangle(prev as u32, remaining[ear] as u32, next as u32);
+        remaining.remove(ear);
+    }
+    mesh.add_triangle(
+        remaining[0] as u32,
+        remaining[1] as u32,
+        remaining[2] as u32,
+    );
+
+    mesh
+}
+
+/// Tessellate a B-spline surface with curvature-adaptive sampling
+///
+/// The parameter range `[0, 1]` in each direction is split recursively: a span
+/// is halved while the surface point at its midpoint deviates from the
+/// straight-line interpolation of its ends by more than `max_error`, probed
+/// along several lines across the other direction. Splits apply to the whole
+/// row or column, so the result is a non-uniform grid without T-junction
+/// cracks. Flat regions end up with few quads; strongly curved or ruffled
+/// regions get many.
+///
+/// Triangles use the same winding as the uniform petal tessellation, vertex
+/// colors are white, and UVs are the (u, v) parameters.
+///
+/// # Arguments
+/// * `surface` - Surface to tessellate
+/// * `max_error` - Maximum allowed deviation from the true surface
+///
+/// # Panics
+/// Panics if `max_error <= 0`
+///
+/// # Example
+/// ```
+/// use floraison_core::geometry::tessellation::tessellate_surface_adaptive;
+/// use floraison_core::math::bspline::{generate_knot_vector, BSplineSurface};
+/// use floraison_core::Vec3;
+///
+/// // Perfectly flat bilinear patch needs no subdivision
+/// let surface = BSplineSurface {
+///     control_points: vec![
+///         vec![Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0)],
+///         vec![Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 0.0)],
+///     ],
+///     degree_u: 1,
+///     degree_v: 1,
+///     knots_u: generate_knot_vector(2, 1, true),
+///     knots_v: generate_knot_vector(2, 1, true),
+/// };
+///
+/// let mesh = tessellate_surface_adaptive(&surface, 0.01);
+/// assert_eq!(mesh.triangle_count(), 2);
+/// ```
+pub fn tessellate_surface_adaptive(surface: &BSplineSurface, max_error: f32) -> Mesh {
+    assert!(max_error > 0.0, "Adaptive error bound must be positive");
+
+    let probes: Vec<f32> = (0..ADAPTIVE_PROBES)
+        .map(|i| i as f32 / (ADAPTIVE_PROBES - 1) as f32)
+        .collect();
+
+    let mut us = vec![0.0];
+    refine_span(
+        &mut us,
+        0.0,
+        1.0,
+        0,
+        &|a, b| span_deviation(&probes, |t, v| surface.evaluate_de_boor(t, v), a, b),
+        max_error,
+    );
+
+    let mut vs = vec![0.0];
+    refine_span(
+        &mut vs,
+        0.0,
+        1.0,
+        0,
+        &|a, b| span_deviation(&probes, |t, u| surface.evaluate_de_boor(u, t), a, b),
+        max_error,
+    );
+
+    let (nu, nv) = (us.len(), vs.len());
+    let mut mesh = Mesh::with_capacity(nu * nv, (nu - 1) * (nv - 1) * 6);
+
+    for &u in &us {
+        for &v in &vs {
+            mesh.add_vertex(
+                surface.evaluate_de_boor(u, v),
+                surface.normal(u, v),
+                Vec2::new(u, v),
+                Vec3::ONE,
+            );
+        }
+    }
+
+    for i in 0..nu - 1 {
+        for j in 0..nv - 1 {
+            let i0 = (i * nv + j) as u32;
+            let i1 = i0 + 1;
+            let i2 = i0 + nv as u32;
+            let i3 = i2 + 1;
+
+            mesh.add_triangle(i0, i2, i1);
+            mesh.add_triangle(i1, i2, i3);
+        }
+    }
+
+    mesh
+}
+
+/// Recursively split `[a, b]`, pushing the end of every accepted span onto `samples`
+fn refine_span(
+    samples: &mut Vec<f32>,
+    a: f32,
+    b: f32,
+    depth: u32,
+    deviation: &dyn Fn(f32, f32) -> f32,
+    max_error: f32,
+) {
+    if depth < MAX_ADAPTIVE_DEPTH && deviation(a, b) > max_error {
+        let mid = (a + b) * 0.5;
+        refine_span(samples, a, mid, depth + 1, deviation, max_error);
+        refine_span(samples, mid, b, depth + 1, deviation, max_error);
+    } else {
+        samples.push(b);
+    }
+}
+
+/// Largest midpoint deviation of span `[a, b]` along each probe line
+///
+/// `eval(t, s)` evaluates the surface at span parameter `t` on probe line `s`.
+fn span_deviation(probes: &[f32], eval: impl Fn(f32, f32) -> Vec3, a: f32, b: f32) -> f32 {
+    let mid = (a + b) * 0.5;
+    probes
+        .iter()
+        .map(|&s| {
+            let linear = (eval(a, s) + eval(b, s)) * 0.5;
+            eval(mid, s).distance(linear)
+        })
+        .fold(0.0, f32::max)
+}
+
+/// Drop consecutive duplicates (including across the closing edge) and collinear points
+fn clean_outline(outline: &[Vec2]) -> Vec<Vec2> {
+    let mut points: Vec<Vec2> = Vec::with_capacity(outline.len());
+    for &p in outline {
+        if points
+            .last()
+            .is_none_or(|last| last.distance(p) > DUPLICATE_EPSILON)
+        {
+            points.push(p);
+        }
+    }
+    while points.len() > 1 && points[0].distance(points[points.len() - 1]) <= DUPLICATE_EPSILON {
+        points.pop();
+    }
+
+    // Removing one collinear point can make its neighbour collinear, so repeat
+    loop {
+        let n = points.len();
+        if n < 3 {
+            return points;
+        }
+        let Some(i) = (0..n).find(|&i| {
+            let prev = points[(i + n - 1) % n];
+            let next = points[(i + 1) % n];
+            (points[i] - prev).perp_dot(next - points[i]).abs()
+                <= DUPLICATE_EPSILON * DUPLICATE_EPSILON
+        }) else {
+            return points;
+        };
+        points.remove(i);
+    }
+}
+
+/// Twice the signed area of a polygon (positive for counter-clockwise)
+fn signed_area(points: &[Vec2]) -> f32 {
+    let n = points.len();
+    (0..n)
+        .map(|i| points[i].perp_dot(points[(i + 1) % n]))
+        .sum()
+}
+
+/// Cross product at corner `i` of the remaining ring (positive when convex)
+fn corner_cross(points: &[Vec2], remaining: &[usize], i: usize) -> f32 {
+    let n = remaining.len();
+    let a = points[remaining[(i + n - 1) % n]];
+    let b = points[remaining[i]];
+    let c = points[remaining[(i + 1) % n]];
+    (b - a).perp_dot(c - b)
+}
+
+/// A corner is an ear if it is convex and no other remaining vertex lies inside it
+fn is_ear(points: &[Vec2], remaining: &[usize], i: usize) -> bool {
+    if corner_cross(points, remaining, i) <= 0.0 {
+        return false;
+    }
+
+    let n = remaining.len();
+    let ia = remaining[(i + n - 1) % n];
+    let ib = remaining[i];
+    let ic = remaining[(i + 1) % n];
+    let (a, b, c) = (points[ia], points[ib], points[ic]);
+
+    remaining
+        .iter()
+        .filter(|&&j| j != ia && j != ib && j != ic)
+        .all(|&j| !point_in_triangle(points[j], a, b, c))
+}
+
+/// Inclusive point-in-triangle test for a counter-clockwise triangle
+fn point_in_triangle(p: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    (b - a).perp_dot(p - a) >= 0.0
+        && (c - b).perp_dot(p - b) >= 0.0
+        && (a - c).perp_dot(p - c) >= 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 1e-5;
+
+    /// Every triangle has non-zero area and faces +Z
+    fn assert_valid_triangles(mesh: &Mesh) {
+        for tri in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [
+                mesh.positions[tri[0] as usize],
+                mesh.positions[tri[1] as usize],
+                mesh.positions[tri[2] as usize],
+            ];
+            let normal = (b - a).cross(c - a);
+            assert!(
+                normal.z > EPSILON,
+                "Triangle should be non-degenerate and face +Z"
+            );
+        }
+    }
+
+    #[test]
+    fn test_adaptive_curved_surface_subdivides() {
+        use crate::math::bspline::generate_knot_vector;
+
+        // Quadratic arch along u
+        let surface = BSplineSurface {
+            control_points: vec![
+                vec![Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0)],
+                vec![Vec3::new(1.0, 0.0, 2.0), Vec3::new(1.0, 1.0, 2.0)],
+                vec![Vec3::new(2.0, 0.0, 0.0), Vec3::new(2.0, 1.0, 0.0)],
+            ],
+            degree_u: 2,
+            degree_v: 1,
+            knots_u: generate_knot_vector(3, 2, true),
+            knots_v: generate_knot_vector(2, 1, true),
+        };
+
+        let coarse = tessellate_surface_adaptive(&surface, 0.1);
+        let fine = tessellate_surface_adaptive(&surface, 0.001);
+
+        assert!(coarse.triangle_count() > 2);
+        assert!(fine.triangle_count() > coarse.triangle_count());
+        assert!(fine.validate().is_ok());
+
+        // Straight along v: never split in that direction
+        let v_samples = fine.uvs.iter().filter(|uv| uv.x == 0.0).count();
+        assert_eq!(v_samples, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be positive")]
+    fn test_adaptive_zero_error() {
+        use crate::math::bspline::generate_knot_vector;
+
+        let surface = BSplineSurface {
+            control_points: vec![vec![Vec3::ZERO, Vec3::Y], vec![Vec3::X, Vec3::ONE]],
+            degree_u: 1,
+            degree_v: 1,
+            knots_u: generate_knot_vector(2, 1, true),
+            knots_v: generate_knot_vector(2, 1, true),
+        };
+        tessellate_surface_adaptive(&surface, 0.0);
+    }
+
+    #[test]
+    fn test_square() {
+        let outline = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        ];
+        let mesh = tessellate_polygon(&outline, Vec3::ONE);
+
+        assert_eq!(mesh.vertex_count(), 4);
+        assert_eq!(mesh.triangle_count(), 2);
+        assert_valid_triangles(&mesh);
+        assert!((mesh.surface_area() - 1.0).abs() < EPSILON);
+        assert!(mesh.normals.iter().all(|&n| n == Vec3::Z));
+        assert_eq!(mesh.uvs[2], Vec2::ONE);
+    }
+
+    #[test]
+    fn test_concave_arrow() {
+        // Arrow pointing up with a notch cut into its tail
+        let outline = [
+            Vec2::new(0.0, 3.0),
+            Vec2::new(-2.0, 1.0),
+            Vec2::new(-1.0, 1.0),
+            Vec2::new(-1.0, -1.0),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, -1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 1.0),
+        ];
+        let mesh = tessellate_polygon(&outline, Vec3::ONE);
+
+        assert_eq!(mesh.triangle_count(), outline.len() - 2);
+        assert_valid_triangles(&mesh);
+
+        // Head (4 * 2 / 2 = 4) + tail square (2 * 2 = 4) - notch (2 * 1 / 2 = 1)
+        assert!((mesh.surface_area() - 7.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_clockwise_input() {
+        let outline = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 0.0),
+        ];
+        let mesh = tessellate_polygon(&outline, Vec3::ONE);
+
+        assert_eq!(mesh.triangle_count(), 2);
+        assert_valid_triangles(&mesh);
+    }
+
+    #[test]
+    fn test_duplicate_and_collinear_points_skipped() {
+        let outline = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.5, 0.0), // Collinear
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+            Vec2::new(0.0, 0.0), // Closing duplicate
+        ];
+        let mesh = tessellate_polygon(&outline, Vec3::ONE);
+
+        assert_eq!(mesh.vertex_count(), 4);
+        assert_eq!(mesh.triangle_count(), 2);
+        assert_valid_triangles(&mesh);
+    }
+
+    #[test]
+    fn test_degenerate_outline() {
+        let line = [Vec2::ZERO, Vec2::X, Vec2::new(2.0, 0.0)];
+        assert!(tessellate_polygon(&line, Vec3::ONE).is_empty());
+        assert!(tessellate_polygon(&[], Vec3::ONE).is_empty());
+    }
+}
@@ -38,8 +38,15 @@ pub mod geometry {
     /// Sweep surface generator (extrude profile along curve)
     pub mod sweep;
 
-    pub mod tessellation {
-        //! Mesh tessellation and subdivision
-        // Will be implemented as needed
-    }
+    /// Ambient occlusion baking into vertex colors
+    pub mod occlusion;
+
+    /// Binary glTF (GLB) export
+    pub mod gltf;
+
+    /// Wavefront OBJ export
+    pub mod obj;
+
+    /// Polygon and surface tessellation
+    pub mod tessellation;
 }
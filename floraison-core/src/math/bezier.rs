@@ -9,6 +9,7 @@
 //! Bézier curves are parametric curves defined by control points:
 //! - **Quadratic** (3 control points): Simple curves with one interior control point
 //! - **Cubic** (4 control points): More flexible curves with two interior control points
+//! - **Arbitrary degree** (n control points): Evaluated with De Casteljau's algorithm
 //!
 //! The parameter `t` ranges from 0.0 to 1.0, where:
 //! - `t = 0.0` → start point (first control point)
@@ -35,6 +36,7 @@
 //! ```
 
 use crate::{Vec2, Vec3};
+use std::ops::{Add, Mul};
 
 /// Evaluate a quadratic Bézier curve at parameter t (2D)
 ///
@@ -165,6 +167,86 @@ pub fn cubic_bezier_3d(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Vec3 {
     p0 * mt3 + p1 * (3.0 * mt2 * t) + p2 * (3.0 * mt * t2) + p3 * t3
 }
 
+/// Run De Casteljau's algorithm on a working copy of the control points
+fn de_casteljau<T>(control_points: &[T], t: f32) -> T
+where
+    T: Copy + Add<Output = T> + Mul<f32, Output = T>,
+{
+    assert!(
+        control_points.len() >= 2,
+        "Bézier curve needs at least 2 control points, got {}",
+        control_points.len()
+    );
+
+    let mut points = control_points.to_vec();
+    for level in (1..points.len()).rev() {
+        for i in 0..level {
+            points[i] = points[i] * (1.0 - t) + points[i + 1] * t;
+        }
+    }
+    points[0]
+}
+
+/// Evaluate a Bézier curve of arbitrary degree at parameter t (2D)
+///
+/// Uses De Casteljau's algorithm: the control polygon is repeatedly
+/// linearly interpolated at t until a single point remains. A curve with
+/// n control points has degree n - 1, so 5- and 6-point profiles give
+/// quartic and quintic curves. For 3 or 4 points the result matches
+/// [`quadratic_bezier_2d`] and [`cubic_bezier_2d`].
+///
+/// # Arguments
+///
+/// * `control_points` - Control polygon, from start point to end point
+/// * `t` - Parameter in range [0, 1]
+///
+/// # Panics
+///
+/// Panics if fewer than 2 control points are given
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::bezier::bezier_2d;
+/// use floraison_core::Vec2;
+///
+/// // Quartic receptacle profile
+/// let profile = [
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(0.6, 0.1),
+///     Vec2::new(0.8, 0.5),
+///     Vec2::new(0.4, 0.9),
+///     Vec2::new(0.0, 1.0),
+/// ];
+///
+/// assert_eq!(bezier_2d(&profile, 0.0), profile[0]);
+/// assert_eq!(bezier_2d(&profile, 1.0), profile[4]);
+/// ```
+pub fn bezier_2d(control_points: &[Vec2], t: f32) -> Vec2 {
+    de_casteljau(control_points, t)
+}
+
+/// Evaluate a Bézier curve of arbitrary degree at parameter t (3D)
+///
+/// Same as [`bezier_2d`] but for 3D points.
+///
+/// # Panics
+///
+/// Panics if fewer than 2 control points are given
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::bezier::bezier_3d;
+/// use floraison_core::Vec3;
+///
+/// let line = [Vec3::ZERO, Vec3::new(2.0, 4.0, 6.0)];
+/// assert_eq!(bezier_3d(&line, 0.5), Vec3::new(1.0, 2.0, 3.0));
+/// ```
+pub fn bezier_3d(control_points: &[Vec3], t: f32) -> Vec3 {
+    de_casteljau(control_points, t)
+}
+
 /// Calculate the derivative (tangent) of a quadratic Bézier curve at parameter t (2D)
 ///
 /// The derivative gives the tangent direction at any point on the curve.
@@ -231,6 +313,32 @@ pub fn cubic_bezier_derivative_2d(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, t: f32
     (p1 - p0) * (3.0 * mt2) + (p2 - p1) * (6.0 * mt * t) + (p3 - p2) * (3.0 * t2)
 }
 
+/// Calculate the derivative (tangent) of a cubic Bézier curve at parameter t (3D)
+///
+/// Same as [`cubic_bezier_derivative_2d`] but for 3D curves.
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::bezier::cubic_bezier_derivative_3d;
+/// use floraison_core::Vec3;
+///
+/// let p0 = Vec3::ZERO;
+/// let p1 = Vec3::Y;
+/// let p2 = Vec3::new(1.0, 2.0, 0.0);
+/// let p3 = Vec3::new(1.0, 3.0, 0.0);
+///
+/// let tangent_start = cubic_bezier_derivative_3d(p0, p1, p2, p3, 0.0);
+/// assert_eq!(tangent_start, Vec3::new(0.0, 3.0, 0.0));
+/// ```
+pub fn cubic_bezier_derivative_3d(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Vec3 {
+    let t2 = t * t;
+    let mt = 1.0 - t;
+    let mt2 = mt * mt;
+
+    (p1 - p0) * (3.0 * mt2) + (p2 - p1) * (6.0 * mt * t) + (p3 - p2) * (3.0 * t2)
+}
+
 /// Sample a quadratic Bézier curve at evenly-spaced parameter values (2D)
 ///
 /// Generates `count` points along the curve by evaluating at t = 0, 1/(count-1), 2/(count-1), ..., 1.
@@ -350,6 +458,567 @@ pub fn sample_cubic_3d(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, count: usize) ->
         .collect()
 }
 
+/// Minimum number of dense samples in an arc-length lookup table
+const ARC_LENGTH_TABLE_SAMPLES: usize = 256;
+
+/// Number of dense samples used to build an arc-length table for `count` outputs
+fn arc_length_table_size(count: usize) -> usize {
+    ARC_LENGTH_TABLE_SAMPLES.max(count * 16)
+}
+
+/// Invert a cumulative arc-length table into `count` evenly spaced parameters
+///
+/// `cumulative[i]` is the curve length from t = 0 to t = i / (len - 1). Each
+/// target length is located by binary search and the parameter is linearly
+/// interpolated within the bracketing table entry.
+fn equal_arc_length_parameters(cumulative: &[f32], count: usize) -> Vec<f32> {
+    let last = cumulative.len() - 1;
+    let total = cumulative[last];
+
+    (0..count)
+        .map(|i| {
+            if i == 0 || total <= 0.0 {
+                return i as f32 / (count - 1) as f32;
+            }
+            if i == count - 1 {
+                return 1.0;
+            }
+
+            let target = total * i as f32 / (count - 1) as f32;
+            let upper = cumulative
+                .partition_point(|&len| len < target)
+                .clamp(1, last);
+            let (l0, l1) = (cumulative[upper - 1], cumulative[upper]);
+            let frac = if l1 > l0 {
+                (target - l0) / (l1 - l0)
+            } else {
+                0.0
+            };
+
+            (upper as f32 - 1.0 + frac) / last as f32
+        })
+        .collect()
+}
+
+/// Running total of the distances between consecutive points
+fn cumulative_lengths<I: Iterator<Item = f32>>(segment_lengths: I) -> Vec<f32> {
+    std::iter::once(0.0)
+        .chain(segment_lengths.scan(0.0, |total, len| {
+            *total += len;
+            Some(*total)
+        }))
+        .collect()
+}
+
+/// Sample a cubic Bézier curve at evenly-spaced arc length (2D)
+///
+/// Unlike [`sample_cubic_2d`], which spaces samples evenly in parameter space
+/// and therefore bunches them up where the curve moves slowly, this builds an
+/// arc-length lookup table from a dense sampling of the curve and inverts it,
+/// so consecutive output points are (nearly) equidistant along the curve.
+///
+/// # Arguments
+///
+/// * `p0` - Start point
+/// * `p1` - First control point
+/// * `p2` - Second control point
+/// * `p3` - End point
+/// * `count` - Number of samples (must be ≥ 2)
+///
+/// # Returns
+///
+/// Vector of sampled points, including start and end points
+///
+/// # Panics
+///
+/// Panics if count < 2
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::bezier::sample_cubic_2d_by_arclength;
+/// use floraison_core::Vec2;
+///
+/// let samples = sample_cubic_2d_by_arclength(
+///     Vec2::ZERO,
+///     Vec2::new(0.0, 1.0),
+///     Vec2::new(1.0, 0.0),
+///     Vec2::new(1.0, 1.0),
+///     10,
+/// );
+/// assert_eq!(samples.len(), 10);
+/// assert_eq!(samples[0], Vec2::ZERO);
+/// assert_eq!(samples[9], Vec2::new(1.0, 1.0));
+/// ```
+pub fn sample_cubic_2d_by_arclength(
+    p0: Vec2,
+    p1: Vec2,
+    p2: Vec2,
+    p3: Vec2,
+    count: usize,
+) -> Vec<Vec2> {
+    assert!(count >= 2, "Need at least 2 samples");
+
+    let dense = sample_cubic_2d(p0, p1, p2, p3, arc_length_table_size(count));
+    let cumulative = cumulative_lengths(dense.windows(2).map(|w| w[0].distance(w[1])));
+
+    equal_arc_length_parameters(&cumulative, count)
+        .into_iter()
+        .map(|t| cubic_bezier_2d(p0, p1, p2, p3, t))
+        .collect()
+}
+
+/// Sample a cubic Bézier curve at evenly-spaced arc length (3D)
+///
+/// Same as [`sample_cubic_2d_by_arclength`] but for 3D curves such as
+/// pedicels and stems.
+///
+/// # Panics
+///
+/// Panics if count < 2
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::bezier::sample_cubic_3d_by_arclength;
+/// use floraison_core::Vec3;
+///
+/// // Control points bunched at the start: parameter sampling would cluster there
+/// let samples = sample_cubic_3d_by_arclength(
+///     Vec3::ZERO,
+///     Vec3::new(0.0, 0.1, 0.0),
+///     Vec3::new(0.0, 0.2, 0.0),
+///     Vec3::new(0.0, 3.0, 0.0),
+///     4,
+/// );
+/// assert!((samples[1].y - 1.0).abs() < 0.01);
+/// assert!((samples[2].y - 2.0).abs() < 0.01);
+/// ```
+pub fn sample_cubic_3d_by_arclength(
+    p0: Vec3,
+    p1: Vec3,
+    p2: Vec3,
+    p3: Vec3,
+    count: usize,
+) -> Vec<Vec3> {
+    assert!(count >= 2, "Need at least 2 samples");
+
+    let dense = sample_cubic_3d(p0, p1, p2, p3, arc_length_table_size(count));
+    let cumulative = cumulative_lengths(dense.windows(2).map(|w| w[0].distance(w[1])));
+
+    equal_arc_length_parameters(&cumulative, count)
+        .into_iter()
+        .map(|t| cubic_bezier_3d(p0, p1, p2, p3, t))
+        .collect()
+}
+
+/// 5-point Gauss–Legendre abscissae on [-1, 1]
+const GAUSS_NODES: [f32; 5] = [0.0, -0.538_469_3, 0.538_469_3, -0.906_179_8, 0.906_179_8];
+
+/// 5-point Gauss–Legendre weights matching [`GAUSS_NODES`]
+const GAUSS_WEIGHTS: [f32; 5] = [
+    0.568_888_9,
+    0.478_628_67,
+    0.478_628_67,
+    0.236_926_88,
+    0.236_926_88,
+];
+
+/// Maximum subdivision depth for adaptive arc-length integration
+const MAX_ARC_LENGTH_DEPTH: u32 = 16;
+
+/// Integrate `speed` over [a, b] with a single 5-point Gauss–Legendre rule
+fn gauss_legendre(speed: &dyn Fn(f32) -> f32, a: f32, b: f32) -> f32 {
+    let half = 0.5 * (b - a);
+    let mid = 0.5 * (a + b);
+
+    GAUSS_NODES
+        .iter()
+        .zip(GAUSS_WEIGHTS.iter())
+        .map(|(&x, &w)| w * speed(mid + half * x))
+        .sum::<f32>()
+        * half
+}
+
+/// Adaptively integrate `speed` over [a, b] until halving the interval changes
+/// the estimate by less than `tolerance`
+fn adaptive_arc_length(
+    speed: &dyn Fn(f32) -> f32,
+    a: f32,
+    b: f32,
+    whole: f32,
+    tolerance: f32,
+    depth: u32,
+) -> f32 {
+    let mid = 0.5 * (a + b);
+    let left = gauss_legendre(speed, a, mid);
+    let right = gauss_legendre(speed, mid, b);
+
+    if depth >= MAX_ARC_LENGTH_DEPTH || (left + right - whole).abs() <= tolerance {
+        left + right
+    } else {
+        adaptive_arc_length(speed, a, mid, left, tolerance * 0.5, depth + 1)
+            + adaptive_arc_length(speed, mid, b, right, tolerance * 0.5, depth + 1)
+    }
+}
+
+/// Arc length of a curve with the given speed function between parameters a and b
+fn arc_length_between(speed: &dyn Fn(f32) -> f32, a: f32, b: f32, tolerance: f32) -> f32 {
+    if b <= a {
+        return 0.0;
+    }
+    let whole = gauss_legendre(speed, a, b);
+    adaptive_arc_length(speed, a, b, whole, tolerance, 0)
+}
+
+/// Find the parameter t at which the arc length from t = 0 equals `target`
+///
+/// Uses Newton iteration on L(t) - target, safeguarded by bisection.
+fn invert_arc_length(speed: &dyn Fn(f32) -> f32, target: f32, tolerance: f32) -> f32 {
+    if target <= 0.0 {
+        return 0.0;
+    }
+    let total = arc_length_between(speed, 0.0, 1.0, tolerance);
+    if target >= total {
+        return 1.0;
+    }
+
+    let (mut lo, mut hi) = (0.0_f32, 1.0_f32);
+    let mut t = target / total;
+
+    for _ in 0..32 {
+        let error = arc_length_between(speed, 0.0, t, tolerance) - target;
+        if error.abs() <= tolerance {
+            break;
+        }
+
+        // Keep a bracket around the root
+        if error > 0.0 {
+            hi = t;
+        } else {
+            lo = t;
+        }
+
+        // Newton step, falling back to bisection if it leaves the bracket
+        let v = speed(t);
+        let newton = if v > 1e-6 { t - error / v } else { f32::NAN };
+        t = if newton > lo && newton < hi {
+            newton
+        } else {
+            0.5 * (lo + hi)
+        };
+    }
+
+    t
+}
+
+/// Compute the arc length of a cubic Bézier curve (2D)
+///
+/// Integrates the curve speed |B'(t)| with adaptive Gauss–Legendre quadrature,
+/// subdividing until the estimate is within `tolerance`.
+///
+/// # Arguments
+///
+/// * `p0` - Start point
+/// * `p1` - First control point
+/// * `p2` - Second control point
+/// * `p3` - End point
+/// * `tolerance` - Maximum absolute error of the returned length (must be > 0)
+///
+/// # Returns
+///
+/// Total length of the curve from t = 0 to t = 1
+///
+/// # Panics
+///
+/// Panics if tolerance <= 0
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::bezier::cubic_bezier_arc_length_2d;
+/// use floraison_core::Vec2;
+///
+/// // Quarter circle approximation of radius 1 (length ≈ π/2)
+/// let k = 0.552_284_8;
+/// let length = cubic_bezier_arc_length_2d(
+///     Vec2::new(1.0, 0.0),
+///     Vec2::new(1.0, k),
+///     Vec2::new(k, 1.0),
+///     Vec2::new(0.0, 1.0),
+///     1e-5,
+/// );
+/// assert!((length - std::f32::consts::FRAC_PI_2).abs() < 1e-3);
+/// ```
+pub fn cubic_bezier_arc_length_2d(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, tolerance: f32) -> f32 {
+    assert!(tolerance > 0.0, "Tolerance must be positive");
+    let speed = |t: f32| cubic_bezier_derivative_2d(p0, p1, p2, p3, t).length();
+    arc_length_between(&speed, 0.0, 1.0, tolerance)
+}
+
+/// Compute the arc length of a cubic Bézier curve (3D)
+///
+/// Same as [`cubic_bezier_arc_length_2d`] but for 3D curves.
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::bezier::cubic_bezier_arc_length_3d;
+/// use floraison_core::Vec3;
+///
+/// // Straight control polygon: length equals the chord
+/// let length = cubic_bezier_arc_length_3d(
+///     Vec3::ZERO,
+///     Vec3::new(0.0, 1.0, 0.0),
+///     Vec3::new(0.0, 2.0, 0.0),
+///     Vec3::new(0.0, 3.0, 0.0),
+///     1e-5,
+/// );
+/// assert!((length - 3.0).abs() < 1e-4);
+/// ```
+pub fn cubic_bezier_arc_length_3d(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, tolerance: f32) -> f32 {
+    assert!(tolerance > 0.0, "Tolerance must be positive");
+    let speed = |t: f32| cubic_bezier_derivative_3d(p0, p1, p2, p3, t).length();
+    arc_length_between(&speed, 0.0, 1.0, tolerance)
+}
+
+/// Estimate the length of a cubic Bézier curve by polyline sampling (2D)
+///
+/// Evaluates the curve at `samples` evenly spaced parameters and sums the
+/// distances between consecutive points. The polyline always underestimates
+/// the true length of a curved segment; the error shrinks roughly with the
+/// square of the sample count, so 32 samples are usually within 0.1% for
+/// gently curved stems while tight hooks need a few hundred. Straight curves
+/// are exact for any count. Use [`cubic_bezier_length_adaptive`] when a
+/// guaranteed tolerance matters more than a fixed cost.
+///
+/// # Arguments
+///
+/// * `p0` - Start point
+/// * `p1` - First control point
+/// * `p2` - Second control point
+/// * `p3` - End point
+/// * `samples` - Number of points to sample (must be ≥ 2)
+///
+/// # Returns
+///
+/// Approximate curve length
+///
+/// # Panics
+///
+/// Panics if samples < 2
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::bezier::cubic_bezier_length_2d;
+/// use floraison_core::Vec2;
+///
+/// let length = cubic_bezier_length_2d(
+///     Vec2::ZERO,
+///     Vec2::new(0.0, 1.0),
+///     Vec2::new(1.0, 1.0),
+///     Vec2::new(1.0, 0.0),
+///     64,
+/// );
+/// assert!(length > 1.0 && length < 3.0);
+/// ```
+pub fn cubic_bezier_length_2d(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, samples: usize) -> f32 {
+    sample_cubic_2d(p0, p1, p2, p3, samples)
+        .windows(2)
+        .map(|w| w[0].distance(w[1]))
+        .sum()
+}
+
+/// Estimate the length of a cubic Bézier curve by polyline sampling (3D)
+///
+/// Same as [`cubic_bezier_length_2d`] but for 3D curves.
+///
+/// # Panics
+///
+/// Panics if samples < 2
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::bezier::cubic_bezier_length_3d;
+/// use floraison_core::Vec3;
+///
+/// let length = cubic_bezier_length_3d(
+///     Vec3::ZERO,
+///     Vec3::new(0.0, 1.0, 0.0),
+///     Vec3::new(0.0, 2.0, 0.0),
+///     Vec3::new(0.0, 3.0, 0.0),
+///     8,
+/// );
+/// assert!((length - 3.0).abs() < 1e-5);
+/// ```
+pub fn cubic_bezier_length_3d(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, samples: usize) -> f32 {
+    sample_cubic_3d(p0, p1, p2, p3, samples)
+        .windows(2)
+        .map(|w| w[0].distance(w[1]))
+        .sum()
+}
+
+/// Recursive step of [`cubic_bezier_length_adaptive`]
+fn adaptive_bezier_length(
+    p0: Vec3,
+    p1: Vec3,
+    p2: Vec3,
+    p3: Vec3,
+    tolerance: f32,
+    depth: u32,
+) -> f32 {
+    let chord = p0.distance(p3);
+    let polygon = p0.distance(p1) + p1.distance(p2) + p2.distance(p3);
+
+    // The true length lies between the chord and the control polygon
+    if depth >= MAX_ARC_LENGTH_DEPTH || polygon - chord <= tolerance {
+        return 0.5 * (chord + polygon);
+    }
+
+    // Split at t = 0.5 with De Casteljau
+    let p01 = (p0 + p1) * 0.5;
+    let p12 = (p1 + p2) * 0.5;
+    let p23 = (p2 + p3) * 0.5;
+    let p012 = (p01 + p12) * 0.5;
+    let p123 = (p12 + p23) * 0.5;
+    let mid = (p012 + p123) * 0.5;
+
+    adaptive_bezier_length(p0, p01, p012, mid, tolerance * 0.5, depth + 1)
+        + adaptive_bezier_length(mid, p123, p23, p3, tolerance * 0.5, depth + 1)
+}
+
+/// Compute the length of a cubic Bézier curve by adaptive subdivision (3D)
+///
+/// The curve length always lies between the chord |P₃ - P₀| and the length of
+/// the control polygon. The curve is split in half with De Casteljau's
+/// algorithm until the two bounds differ by less than the (halved per level)
+/// tolerance, then the average of the bounds is summed. Nearly straight
+/// regions terminate immediately while tight bends are refined further, so
+/// the cost follows the curve's shape rather than a fixed sample count.
+///
+/// # Arguments
+///
+/// * `p0` - Start point
+/// * `p1` - First control point
+/// * `p2` - Second control point
+/// * `p3` - End point
+/// * `tolerance` - Maximum absolute error of the returned length (must be > 0)
+///
+/// # Returns
+///
+/// Curve length within `tolerance`
+///
+/// # Panics
+///
+/// Panics if tolerance <= 0
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::bezier::cubic_bezier_length_adaptive;
+/// use floraison_core::Vec3;
+///
+/// // Quarter circle approximation of radius 1 (length ≈ π/2)
+/// let k = 0.552_284_8;
+/// let length = cubic_bezier_length_adaptive(
+///     Vec3::new(1.0, 0.0, 0.0),
+///     Vec3::new(1.0, k, 0.0),
+///     Vec3::new(k, 1.0, 0.0),
+///     Vec3::new(0.0, 1.0, 0.0),
+///     1e-4,
+/// );
+/// assert!((length - std::f32::consts::FRAC_PI_2).abs() < 1e-3);
+/// ```
+pub fn cubic_bezier_length_adaptive(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, tolerance: f32) -> f32 {
+    assert!(tolerance > 0.0, "Tolerance must be positive");
+    adaptive_bezier_length(p0, p1, p2, p3, tolerance, 0)
+}
+
+/// Find the parameter at a given arc length along a cubic Bézier curve (2D)
+///
+/// Inverts [`cubic_bezier_arc_length_2d`]: returns t such that the length of the
+/// curve from t = 0 to t equals `target` (within `tolerance`). Targets outside
+/// [0, length] are clamped to t = 0 or t = 1.
+///
+/// # Arguments
+///
+/// * `p0` - Start point
+/// * `p1` - First control point
+/// * `p2` - Second control point
+/// * `p3` - End point
+/// * `target` - Desired arc length from the start of the curve
+/// * `tolerance` - Maximum absolute arc-length error (must be > 0)
+///
+/// # Panics
+///
+/// Panics if tolerance <= 0
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::bezier::{cubic_bezier_2d, cubic_bezier_t_at_arc_length_2d};
+/// use floraison_core::Vec2;
+///
+/// let (p0, p1, p2, p3) = (
+///     Vec2::ZERO,
+///     Vec2::new(0.0, 1.0),
+///     Vec2::new(1.0, 1.0),
+///     Vec2::new(1.0, 0.0),
+/// );
+///
+/// let t = cubic_bezier_t_at_arc_length_2d(p0, p1, p2, p3, 0.5, 1e-5);
+/// assert!(t > 0.0 && t < 0.5);
+/// ```
+pub fn cubic_bezier_t_at_arc_length_2d(
+    p0: Vec2,
+    p1: Vec2,
+    p2: Vec2,
+    p3: Vec2,
+    target: f32,
+    tolerance: f32,
+) -> f32 {
+    assert!(tolerance > 0.0, "Tolerance must be positive");
+    let speed = |t: f32| cubic_bezier_derivative_2d(p0, p1, p2, p3, t).length();
+    invert_arc_length(&speed, target, tolerance)
+}
+
+/// Find the parameter at a given arc length along a cubic Bézier curve (3D)
+///
+/// Same as [`cubic_bezier_t_at_arc_length_2d`] but for 3D curves. Useful for
+/// placing nodes or pedicels at even spacing along swept stems.
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::bezier::cubic_bezier_t_at_arc_length_3d;
+/// use floraison_core::Vec3;
+///
+/// // Evenly spaced control points give a uniform-speed straight line
+/// let t = cubic_bezier_t_at_arc_length_3d(
+///     Vec3::ZERO,
+///     Vec3::new(0.0, 1.0, 0.0),
+///     Vec3::new(0.0, 2.0, 0.0),
+///     Vec3::new(0.0, 3.0, 0.0),
+///     1.5,
+///     1e-5,
+/// );
+/// assert!((t - 0.5).abs() < 1e-4);
+/// ```
+pub fn cubic_bezier_t_at_arc_length_3d(
+    p0: Vec3,
+    p1: Vec3,
+    p2: Vec3,
+    p3: Vec3,
+    target: f32,
+    tolerance: f32,
+) -> f32 {
+    assert!(tolerance > 0.0, "Tolerance must be positive");
+    let speed = |t: f32| cubic_bezier_derivative_3d(p0, p1, p2, p3, t).length();
+    invert_arc_length(&speed, target, tolerance)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -593,4 +1262,320 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_cubic_derivative_3d_matches_finite_difference() {
+        let p0 = Vec3::ZERO;
+        let p1 = Vec3::new(0.0, 1.0, 0.5);
+        let p2 = Vec3::new(1.0, 2.0, -0.5);
+        let p3 = Vec3::new(1.0, 3.0, 0.0);
+
+        let h = 1e-3;
+        for &t in &[0.2, 0.5, 0.8] {
+            let analytic = cubic_bezier_derivative_3d(p0, p1, p2, p3, t);
+            let numeric = (cubic_bezier_3d(p0, p1, p2, p3, t + h)
+                - cubic_bezier_3d(p0, p1, p2, p3, t - h))
+                / (2.0 * h);
+            assert!((analytic - numeric).length() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_arc_length_straight_line_equals_chord() {
+        // Collinear but unevenly spaced controls: speed varies, length doesn't
+        let p0 = Vec2::ZERO;
+        let p1 = Vec2::new(0.1, 0.2);
+        let p2 = Vec2::new(1.5, 3.0);
+        let p3 = Vec2::new(2.0, 4.0);
+
+        let length = cubic_bezier_arc_length_2d(p0, p1, p2, p3, 1e-5);
+        assert!((length - p3.length()).abs() < 1e-4);
+
+        let p3d = Vec3::new(1.0, 2.0, 2.0);
+        let length_3d = cubic_bezier_arc_length_3d(Vec3::ZERO, p3d * 0.25, p3d * 0.9, p3d, 1e-5);
+        assert!((length_3d - 3.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_arc_length_matches_dense_sampling() {
+        let p0 = Vec3::ZERO;
+        let p1 = Vec3::new(0.0, 1.0, 0.0);
+        let p2 = Vec3::new(1.0, 1.0, 1.0);
+        let p3 = Vec3::new(1.0, 0.0, 2.0);
+
+        let samples = sample_cubic_3d(p0, p1, p2, p3, 5000);
+        let polyline: f32 = samples.windows(2).map(|w| (w[1] - w[0]).length()).sum();
+
+        let length = cubic_bezier_arc_length_3d(p0, p1, p2, p3, 1e-5);
+        assert!((length - polyline).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_t_at_arc_length_inverts_length() {
+        let p0 = Vec2::ZERO;
+        let p1 = Vec2::new(0.0, 2.0);
+        let p2 = Vec2::new(0.5, 0.2);
+        let p3 = Vec2::new(2.0, 1.0);
+        let total = cubic_bezier_arc_length_2d(p0, p1, p2, p3, 1e-6);
+
+        for &fraction in &[0.1, 0.25, 0.5, 0.75, 0.9] {
+            let target = total * fraction;
+            let t = cubic_bezier_t_at_arc_length_2d(p0, p1, p2, p3, target, 1e-5);
+
+            // Length of the sub-curve [0, t], via dense polyline
+            let n = 4000;
+            let partial: f32 = (0..n)
+                .map(|i| {
+                    let a = t * i as f32 / n as f32;
+                    let b = t * (i + 1) as f32 / n as f32;
+                    (cubic_bezier_2d(p0, p1, p2, p3, b) - cubic_bezier_2d(p0, p1, p2, p3, a))
+                        .length()
+                })
+                .sum();
+            assert!(
+                (partial - target).abs() < 1e-3,
+                "fraction {}: got length {}, expected {}",
+                fraction,
+                partial,
+                target
+            );
+        }
+    }
+
+    #[test]
+    fn test_t_at_arc_length_clamps() {
+        let p0 = Vec3::ZERO;
+        let p1 = Vec3::Y;
+        let p2 = Vec3::new(1.0, 1.0, 0.0);
+        let p3 = Vec3::X;
+
+        assert_eq!(
+            cubic_bezier_t_at_arc_length_3d(p0, p1, p2, p3, -1.0, 1e-4),
+            0.0
+        );
+        assert_eq!(
+            cubic_bezier_t_at_arc_length_3d(p0, p1, p2, p3, 100.0, 1e-4),
+            1.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Tolerance must be positive")]
+    fn test_arc_length_zero_tolerance() {
+        cubic_bezier_arc_length_2d(Vec2::ZERO, Vec2::X, Vec2::Y, Vec2::ONE, 0.0);
+    }
+
+    /// Max/min ratio of the distances between consecutive points
+    fn gap_ratio_3d(points: &[Vec3]) -> f32 {
+        let gaps: Vec<f32> = points.windows(2).map(|w| w[0].distance(w[1])).collect();
+        let max = gaps.iter().cloned().fold(f32::MIN, f32::max);
+        let min = gaps.iter().cloned().fold(f32::MAX, f32::min);
+        max / min
+    }
+
+    #[test]
+    fn test_sample_by_arclength_s_curve_even_spacing() {
+        // S-curve with very uneven parameter speed
+        let (p0, p1, p2, p3) = (
+            Vec3::ZERO,
+            Vec3::new(2.0, 0.2, 0.0),
+            Vec3::new(-1.0, 2.8, 0.5),
+            Vec3::new(1.0, 3.0, 0.5),
+        );
+
+        let uniform = sample_cubic_3d(p0, p1, p2, p3, 20);
+        let even = sample_cubic_3d_by_arclength(p0, p1, p2, p3, 20);
+
+        assert_eq!(even.len(), 20);
+        assert_eq!(even[0], p0);
+        assert_eq!(even[19], p3);
+
+        let ratio = gap_ratio_3d(&even);
+        assert!(
+            ratio < 1.2,
+            "Arc-length gaps should be near-equal: ratio {}",
+            ratio
+        );
+        assert!(
+            gap_ratio_3d(&uniform) > ratio,
+            "Parameter sampling should be less even than arc-length sampling"
+        );
+    }
+
+    #[test]
+    fn test_sample_by_arclength_2d_matches_3d() {
+        let (p0, p1, p2, p3) = (
+            Vec2::ZERO,
+            Vec2::new(0.0, 1.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+        );
+
+        let flat = sample_cubic_2d_by_arclength(p0, p1, p2, p3, 12);
+        let lifted = sample_cubic_3d_by_arclength(
+            p0.extend(0.0),
+            p1.extend(0.0),
+            p2.extend(0.0),
+            p3.extend(0.0),
+            12,
+        );
+
+        for (a, b) in flat.iter().zip(&lifted) {
+            assert!((a.extend(0.0) - *b).length() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_sample_by_arclength_degenerate_point() {
+        let p = Vec3::new(1.0, 2.0, 3.0);
+        let samples = sample_cubic_3d_by_arclength(p, p, p, p, 5);
+        assert_eq!(samples.len(), 5);
+        assert!(samples.iter().all(|s| (*s - p).length() < EPSILON));
+    }
+
+    #[test]
+    fn test_length_straight_degenerate_curve_is_exact() {
+        // Control points on a line, unevenly spaced
+        let p0 = Vec3::new(1.0, 1.0, 1.0);
+        let p3 = Vec3::new(4.0, 5.0, 1.0);
+        let p1 = p0.lerp(p3, 0.1);
+        let p2 = p0.lerp(p3, 0.3);
+        let expected = p0.distance(p3);
+
+        for samples in [2, 7, 100] {
+            let length = cubic_bezier_length_3d(p0, p1, p2, p3, samples);
+            assert!(
+                (length - expected).abs() < EPSILON,
+                "Straight curve length with {} samples: {} != {}",
+                samples,
+                length,
+                expected
+            );
+        }
+
+        let flat = cubic_bezier_length_2d(
+            p0.truncate(),
+            p1.truncate(),
+            p2.truncate(),
+            p3.truncate(),
+            16,
+        );
+        assert!((flat - expected).abs() < EPSILON);
+
+        let adaptive = cubic_bezier_length_adaptive(p0, p1, p2, p3, 1e-4);
+        assert!((adaptive - expected).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_length_sampling_converges_from_below() {
+        let (p0, p1, p2, p3) = (
+            Vec3::ZERO,
+            Vec3::new(2.0, 0.2, 0.0),
+            Vec3::new(-1.0, 2.8, 0.5),
+            Vec3::new(1.0, 3.0, 0.5),
+        );
+        let reference = cubic_bezier_arc_length_3d(p0, p1, p2, p3, 1e-6);
+
+        let coarse = cubic_bezier_length_3d(p0, p1, p2, p3, 8);
+        let fine = cubic_bezier_length_3d(p0, p1, p2, p3, 512);
+        assert!(coarse < fine && fine <= reference + 1e-4);
+        assert!((reference - fine).abs() < (reference - coarse).abs());
+        assert!((fine - reference).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_length_adaptive_matches_quadrature() {
+        let (p0, p1, p2, p3) = (
+            Vec3::ZERO,
+            Vec3::new(0.0, 2.0, 1.0),
+            Vec3::new(3.0, -1.0, 0.0),
+            Vec3::new(2.0, 2.0, 2.0),
+        );
+        let reference = cubic_bezier_arc_length_3d(p0, p1, p2, p3, 1e-6);
+        let adaptive = cubic_bezier_length_adaptive(p0, p1, p2, p3, 1e-3);
+        assert!(
+            (adaptive - reference).abs() < 1e-3,
+            "Adaptive length {} should match quadrature {}",
+            adaptive,
+            reference
+        );
+    }
+
+    #[test]
+    fn test_bezier_n_matches_cubic() {
+        let points = [
+            Vec2::ZERO,
+            Vec2::new(0.3, 1.2),
+            Vec2::new(1.4, -0.6),
+            Vec2::new(2.0, 1.0),
+        ];
+
+        for i in 0..=20 {
+            let t = i as f32 / 20.0;
+            let general = bezier_2d(&points, t);
+            let cubic = cubic_bezier_2d(points[0], points[1], points[2], points[3], t);
+            assert!(
+                (general - cubic).length() < 1e-6,
+                "De Casteljau should match cubic_bezier_2d at t={}",
+                t
+            );
+
+            let points_3d: Vec<Vec3> = points.iter().map(|p| p.extend(0.5)).collect();
+            let general_3d = bezier_3d(&points_3d, t);
+            let cubic_3d =
+                cubic_bezier_3d(points_3d[0], points_3d[1], points_3d[2], points_3d[3], t);
+            assert!((general_3d - cubic_3d).length() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_bezier_n_degree_1_is_line() {
+        let a = Vec3::new(1.0, -2.0, 0.5);
+        let b = Vec3::new(3.0, 4.0, -1.5);
+
+        for i in 0..=10 {
+            let t = i as f32 / 10.0;
+            assert!((bezier_3d(&[a, b], t) - a.lerp(b, t)).length() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_bezier_n_degree_4() {
+        let points = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(2.0, -1.0),
+            Vec2::new(3.0, 2.0),
+            Vec2::new(4.0, 0.0),
+        ];
+
+        // Endpoints are interpolated
+        assert!((bezier_2d(&points, 0.0) - points[0]).length() < EPSILON);
+        assert!((bezier_2d(&points, 1.0) - points[4]).length() < EPSILON);
+
+        // Bernstein form of degree 4: binomial weights 1, 4, 6, 4, 1
+        let t: f32 = 0.3;
+        let mt = 1.0 - t;
+        let weights = [
+            mt.powi(4),
+            4.0 * mt.powi(3) * t,
+            6.0 * mt.powi(2) * t.powi(2),
+            4.0 * mt * t.powi(3),
+            t.powi(4),
+        ];
+        let expected = points
+            .iter()
+            .zip(weights)
+            .fold(Vec2::ZERO, |acc, (&p, w)| acc + p * w);
+        assert!((bezier_2d(&points, t) - expected).length() < EPSILON);
+
+        // Evenly spaced x control points give x(t) = 4t
+        assert!((bezier_2d(&points, 0.7).x - 2.8).abs() < EPSILON);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 2 control points")]
+    fn test_bezier_n_too_few_points() {
+        bezier_2d(&[Vec2::ZERO], 0.5);
+    }
 }
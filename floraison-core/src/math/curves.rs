@@ -5,6 +5,9 @@
 
 use crate::Vec3;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Evaluate a Catmull-Rom spline at parameter t ∈ [0, 1]
 ///
 /// Catmull-Rom splines pass through all control points (except the first and last)
@@ -105,6 +108,164 @@ pub fn catmull_rom_tangent(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Ve
     0.5 * (b0 * p0 + b1 * p1 + b2 * p2 + b3 * p3)
 }
 
+/// Evaluate a cardinal (Catmull-Rom family) spline with adjustable tension
+///
+/// The segment is the cubic Hermite curve from p1 to p2 with tangents
+/// `tension · (p2 - p0)` and `tension · (p3 - p1)`. A tension of 0.5 gives
+/// the standard Catmull-Rom spline of [`catmull_rom_point`]; lower values
+/// tighten the curve towards the control polygon (0.0 gives straight lines),
+/// higher values make it rounder and more prone to overshoot.
+///
+/// # Arguments
+///
+/// * `p0` - Control point before the segment
+/// * `p1` - Start point of the segment
+/// * `p2` - End point of the segment
+/// * `p3` - Control point after the segment
+/// * `t` - Parameter in range [0, 1] within segment [p1, p2]
+/// * `tension` - Tangent scale factor (0.5 = standard Catmull-Rom)
+///
+/// # Returns
+///
+/// Point on the curve at parameter t
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::curves::{catmull_rom_point, catmull_rom_point_tension};
+/// use floraison_core::Vec3;
+///
+/// let (p0, p1, p2, p3) = (Vec3::ZERO, Vec3::Y, Vec3::new(1.0, 2.0, 0.0), Vec3::new(1.0, 3.0, 0.0));
+///
+/// let standard = catmull_rom_point(p0, p1, p2, p3, 0.3);
+/// let same = catmull_rom_point_tension(p0, p1, p2, p3, 0.3, 0.5);
+/// assert!((standard - same).length() < 1e-5);
+///
+/// // Zero tension follows the straight chord
+/// let straight = catmull_rom_point_tension(p0, p1, p2, p3, 0.5, 0.0);
+/// assert!((straight - (p1 + p2) * 0.5).length() < 1e-5);
+/// ```
+pub fn catmull_rom_point_tension(
+    p0: Vec3,
+    p1: Vec3,
+    p2: Vec3,
+    p3: Vec3,
+    t: f32,
+    tension: f32,
+) -> Vec3 {
+    let m1 = (p2 - p0) * tension;
+    let m2 = (p3 - p1) * tension;
+
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    // Cubic Hermite basis
+    let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+    let h10 = t3 - 2.0 * t2 + t;
+    let h01 = -2.0 * t3 + 3.0 * t2;
+    let h11 = t3 - t2;
+
+    h00 * p1 + h10 * m1 + h01 * p2 + h11 * m2
+}
+
+/// Evaluate a non-uniform Catmull-Rom segment with knot spacing |Δp|^alpha
+///
+/// Uses the Barry–Goldman pyramid. Coincident control points get a tiny knot
+/// interval instead of zero so the evaluation never divides by zero.
+fn catmull_rom_point_alpha(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32, alpha: f32) -> Vec3 {
+    let interval = |a: Vec3, b: Vec3| a.distance(b).powf(alpha).max(1e-4);
+
+    let t0 = 0.0;
+    let t1 = t0 + interval(p0, p1);
+    let t2 = t1 + interval(p1, p2);
+    let t3 = t2 + interval(p2, p3);
+
+    let u = t1 + (t2 - t1) * t;
+    let lerp = |a: Vec3, b: Vec3, ta: f32, tb: f32| {
+        a * ((tb - u) / (tb - ta)) + b * ((u - ta) / (tb - ta))
+    };
+
+    let a1 = lerp(p0, p1, t0, t1);
+    let a2 = lerp(p1, p2, t1, t2);
+    let a3 = lerp(p2, p3, t2, t3);
+
+    let b1 = lerp(a1, a2, t0, t2);
+    let b2 = lerp(a2, a3, t1, t3);
+
+    lerp(b1, b2, t1, t2)
+}
+
+/// Evaluate a centripetal Catmull-Rom spline at parameter t ∈ [0, 1]
+///
+/// Knot intervals are the square root of the chord length between control
+/// points (alpha = 0.5). Unlike the uniform spline, the centripetal variant
+/// never forms cusps or self-intersections within a segment, even when
+/// control points are unevenly spaced, and it stays close to the control
+/// polygon.
+///
+/// # Arguments
+///
+/// * `p0` - Control point before the segment
+/// * `p1` - Start point of the segment
+/// * `p2` - End point of the segment
+/// * `p3` - Control point after the segment
+/// * `t` - Parameter in range [0, 1] within segment [p1, p2]
+///
+/// # Returns
+///
+/// Point on the curve at parameter t
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::curves::catmull_rom_point_centripetal;
+/// use floraison_core::Vec3;
+///
+/// let (p0, p1, p2, p3) = (Vec3::ZERO, Vec3::Y, Vec3::new(0.1, 1.1, 0.0), Vec3::new(3.0, 1.0, 0.0));
+///
+/// assert!((catmull_rom_point_centripetal(p0, p1, p2, p3, 0.0) - p1).length() < 1e-5);
+/// assert!((catmull_rom_point_centripetal(p0, p1, p2, p3, 1.0) - p2).length() < 1e-5);
+/// ```
+pub fn catmull_rom_point_centripetal(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Vec3 {
+    catmull_rom_point_alpha(p0, p1, p2, p3, t, 0.5)
+}
+
+/// Parameterization of a Catmull-Rom spline
+///
+/// Controls how the knot intervals between consecutive control points are
+/// chosen, which determines how the curve behaves around unevenly spaced
+/// points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CatmullRomKind {
+    /// Equal knot intervals (alpha = 0); may overshoot or loop on clustered points
+    #[default]
+    Uniform,
+    /// Knot intervals of √|Δp| (alpha = 0.5); free of cusps and self-intersections
+    Centripetal,
+    /// Knot intervals of |Δp| (alpha = 1); follows the chords most tightly
+    Chordal,
+}
+
+impl CatmullRomKind {
+    /// Evaluate one segment of this kind of spline at parameter t ∈ [0, 1]
+    ///
+    /// # Arguments
+    ///
+    /// * `p0` - Control point before the segment
+    /// * `p1` - Start point of the segment
+    /// * `p2` - End point of the segment
+    /// * `p3` - Control point after the segment
+    /// * `t` - Parameter in range [0, 1] within segment [p1, p2]
+    pub fn point(self, p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Vec3 {
+        match self {
+            CatmullRomKind::Uniform => catmull_rom_point(p0, p1, p2, p3, t),
+            CatmullRomKind::Centripetal => catmull_rom_point_centripetal(p0, p1, p2, p3, t),
+            CatmullRomKind::Chordal => catmull_rom_point_alpha(p0, p1, p2, p3, t, 1.0),
+        }
+    }
+}
+
 /// Sample N points along a Catmull-Rom spline through control points
 ///
 /// This function creates a smooth curve passing through all interior control points.
@@ -124,6 +285,9 @@ pub fn catmull_rom_tangent(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Ve
 ///
 /// Panics if `points.len() < 4` or `samples_per_segment < 2`
 ///
+/// Uses the uniform parameterization; see [`sample_catmull_rom_curve_with_kind`]
+/// for the centripetal and chordal variants.
+///
 /// # Example
 ///
 /// ```
@@ -142,6 +306,45 @@ pub fn catmull_rom_tangent(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Ve
 /// assert!(curve.len() > 0);
 /// ```
 pub fn sample_catmull_rom_curve(points: &[Vec3], samples_per_segment: usize) -> Vec<Vec3> {
+    sample_catmull_rom_curve_with_kind(points, samples_per_segment, CatmullRomKind::Uniform)
+}
+
+/// Sample N points along a Catmull-Rom spline with the given parameterization
+///
+/// Same as [`sample_catmull_rom_curve`], but each segment is evaluated with
+/// [`CatmullRomKind::point`].
+///
+/// # Arguments
+///
+/// * `points` - Control points defining the curve (minimum 4 points)
+/// * `samples_per_segment` - Number of samples between each pair of adjacent points
+/// * `kind` - Knot parameterization (uniform, centripetal or chordal)
+///
+/// # Panics
+///
+/// Panics if `points.len() < 4` or `samples_per_segment < 2`
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::curves::{sample_catmull_rom_curve_with_kind, CatmullRomKind};
+/// use floraison_core::Vec3;
+///
+/// let control_points = vec![
+///     Vec3::new(0.0, 0.0, 0.0),
+///     Vec3::new(0.0, 1.0, 0.0),
+///     Vec3::new(0.05, 1.05, 0.0),
+///     Vec3::new(0.0, 3.0, 1.0),
+/// ];
+///
+/// let curve = sample_catmull_rom_curve_with_kind(&control_points, 10, CatmullRomKind::Centripetal);
+/// assert_eq!(curve.len(), 11);
+/// ```
+pub fn sample_catmull_rom_curve_with_kind(
+    points: &[Vec3],
+    samples_per_segment: usize,
+    kind: CatmullRomKind,
+) -> Vec<Vec3> {
     assert!(
         points.len() >= 4,
         "Catmull-Rom spline requires at least 4 control points"
@@ -164,7 +367,7 @@ pub fn sample_catmull_rom_curve(points: &[Vec3], samples_per_segment: usize) ->
         // Sample points along this segment
         for i in 0..samples_per_segment {
             let t = i as f32 / samples_per_segment as f32;
-            let point = catmull_rom_point(p0, p1, p2, p3, t);
+            let point = kind.point(p0, p1, p2, p3, t);
             curve.push(point);
         }
     }
@@ -175,6 +378,70 @@ pub fn sample_catmull_rom_curve(points: &[Vec3], samples_per_segment: usize) ->
     curve
 }
 
+/// Sample a closed Catmull-Rom loop through control points
+///
+/// The point list is treated as cyclic: the segment from the last point back
+/// to the first is included, and the neighbours used for the tangents wrap
+/// around, so the curve is C1-continuous at the seam. Unlike the open
+/// variant, the curve passes through every control point.
+///
+/// # Arguments
+///
+/// * `points` - Control points around the loop (minimum 3 points)
+/// * `samples_per_segment` - Number of samples between each pair of adjacent points
+///
+/// # Returns
+///
+/// `points.len() * samples_per_segment + 1` points; the last repeats the
+/// first so the loop can be drawn or swept as a closed polyline
+///
+/// # Panics
+///
+/// Panics if `points.len() < 3` or `samples_per_segment < 2`
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::curves::sample_catmull_rom_closed;
+/// use floraison_core::Vec3;
+///
+/// let square = vec![Vec3::X, Vec3::Z, -Vec3::X, -Vec3::Z];
+/// let ring = sample_catmull_rom_closed(&square, 8);
+///
+/// assert_eq!(ring.len(), 4 * 8 + 1);
+/// assert_eq!(ring[0], ring[ring.len() - 1]);
+/// ```
+pub fn sample_catmull_rom_closed(points: &[Vec3], samples_per_segment: usize) -> Vec<Vec3> {
+    assert!(
+        points.len() >= 3,
+        "Closed Catmull-Rom spline requires at least 3 control points"
+    );
+    assert!(
+        samples_per_segment >= 2,
+        "Need at least 2 samples per segment"
+    );
+
+    let n = points.len();
+    let mut curve = Vec::with_capacity(n * samples_per_segment + 1);
+
+    for seg_idx in 0..n {
+        let p0 = points[(seg_idx + n - 1) % n];
+        let p1 = points[seg_idx];
+        let p2 = points[(seg_idx + 1) % n];
+        let p3 = points[(seg_idx + 2) % n];
+
+        for i in 0..samples_per_segment {
+            let t = i as f32 / samples_per_segment as f32;
+            curve.push(catmull_rom_point(p0, p1, p2, p3, t));
+        }
+    }
+
+    // Close the loop
+    curve.push(curve[0]);
+
+    curve
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,6 +575,156 @@ mod tests {
             "Tangent should have non-zero length"
         );
     }
+
+    /// Whether any two non-adjacent segments of a polyline in the XY plane cross
+    fn polyline_self_intersects(points: &[Vec3]) -> bool {
+        let cross =
+            |o: Vec3, a: Vec3, b: Vec3| (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x);
+        let crosses = |a: Vec3, b: Vec3, c: Vec3, d: Vec3| {
+            cross(a, b, c) * cross(a, b, d) < 0.0 && cross(c, d, a) * cross(c, d, b) < 0.0
+        };
+
+        for i in 0..points.len() - 1 {
+            for j in i + 2..points.len() - 1 {
+                if crosses(points[i], points[i + 1], points[j], points[j + 1]) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    #[test]
+    fn test_tension_half_matches_standard() {
+        let (p0, p1, p2, p3) = (
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.5),
+            Vec3::new(0.0, 3.0, 1.0),
+        );
+
+        for i in 0..=10 {
+            let t = i as f32 / 10.0;
+            let standard = catmull_rom_point(p0, p1, p2, p3, t);
+            let tension = catmull_rom_point_tension(p0, p1, p2, p3, t, 0.5);
+            assert!((standard - tension).length() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_non_uniform_kinds_interpolate_endpoints() {
+        let (p0, p1, p2, p3) = (
+            Vec3::new(-2.0, 0.0, 0.0),
+            Vec3::new(0.0, 0.5, 0.0),
+            Vec3::new(0.2, 0.6, 0.3),
+            Vec3::new(4.0, 1.0, 0.0),
+        );
+
+        for kind in [
+            CatmullRomKind::Uniform,
+            CatmullRomKind::Centripetal,
+            CatmullRomKind::Chordal,
+        ] {
+            assert!((kind.point(p0, p1, p2, p3, 0.0) - p1).length() < 1e-5);
+            assert!((kind.point(p0, p1, p2, p3, 1.0) - p2).length() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_centripetal_has_no_loop_on_clustered_points() {
+        // Two interior points very close together with far-away neighbours
+        let points = vec![
+            Vec3::new(-2.0, 0.0, 0.0),
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(0.1, 0.0, 0.0),
+            Vec3::new(2.1, -1.0, 0.0),
+        ];
+
+        let uniform = sample_catmull_rom_curve_with_kind(&points, 64, CatmullRomKind::Uniform);
+        let centripetal =
+            sample_catmull_rom_curve_with_kind(&points, 64, CatmullRomKind::Centripetal);
+
+        assert!(
+            polyline_self_intersects(&uniform),
+            "Uniform Catmull-Rom should loop on clustered points"
+        );
+        assert!(
+            !polyline_self_intersects(&centripetal),
+            "Centripetal Catmull-Rom should not loop"
+        );
+    }
+
+    #[test]
+    fn test_sample_curve_default_kind_is_uniform() {
+        let points = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(1.0, 2.0, 0.0),
+            Vec3::new(1.0, 3.0, 0.0),
+        ];
+
+        assert_eq!(CatmullRomKind::default(), CatmullRomKind::Uniform);
+        assert_eq!(
+            sample_catmull_rom_curve(&points, 8),
+            sample_catmull_rom_curve_with_kind(&points, 8, CatmullRomKind::Uniform)
+        );
+    }
+
+    #[test]
+    fn test_closed_curve_loops_through_all_points() {
+        let points = vec![
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 0.2, 1.5),
+            Vec3::new(-1.0, 0.0, 0.0),
+            Vec3::new(0.0, -0.2, -0.5),
+            Vec3::new(0.7, 0.1, -0.7),
+        ];
+        let samples_per_segment = 10;
+
+        let curve = sample_catmull_rom_closed(&points, samples_per_segment);
+
+        assert_eq!(curve.len(), points.len() * samples_per_segment + 1);
+        assert!(
+            (curve[0] - curve[curve.len() - 1]).length() < 1e-6,
+            "First and last samples should coincide"
+        );
+
+        for (i, point) in points.iter().enumerate() {
+            let nearest = curve
+                .iter()
+                .map(|c| c.distance(*point))
+                .fold(f32::MAX, f32::min);
+            assert!(
+                nearest < 1e-5,
+                "Curve should pass through control point {}",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn test_closed_curve_c1_at_seam() {
+        let points = vec![Vec3::X, Vec3::Z, -Vec3::X, -Vec3::Z];
+        let n = points.len();
+
+        // Tangent leaving the seam (start of first segment) equals the tangent
+        // arriving at it (end of last segment)
+        let leaving = catmull_rom_tangent(points[n - 1], points[0], points[1], points[2], 0.0);
+        let arriving = catmull_rom_tangent(points[n - 2], points[n - 1], points[0], points[1], 1.0);
+        assert!((leaving - arriving).length() < 1e-5);
+
+        // Sampled step sizes are symmetric across the seam
+        let curve = sample_catmull_rom_closed(&points, 16);
+        let before = curve[curve.len() - 2].distance(curve[curve.len() - 1]);
+        let after = curve[0].distance(curve[1]);
+        assert!((before - after).abs() < 1e-5);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 3 control points")]
+    fn test_closed_curve_too_few_points() {
+        sample_catmull_rom_closed(&[Vec3::ZERO, Vec3::X], 10);
+    }
 }
 
 // ============================================================================
@@ -511,6 +928,53 @@ pub fn determine_z_signs(points_2d: &[Vec2], dz2: &mut [f32]) {
 /// assert!(curve_3d[1].z.abs() < 0.01);
 /// ```
 pub fn reconstruct_3d_curve(points_2d: &[Vec2]) -> Vec<Vec3> {
+    reconstruct_3d_curve_scaled(points_2d, 1.0)
+}
+
+/// Reconstruct a 3D curve with an adjustable curvature constant
+///
+/// Same as [`reconstruct_3d_curve`], but the constant curvature is
+/// `curvature_scale × max |d²x/dy²|` instead of the maximum itself. This
+/// dials the spiral intensity: 0.0 keeps the curve flat in its plane,
+/// 1.0 reproduces [`reconstruct_3d_curve`], and larger values twist gentle
+/// sketches into tighter spirals. With a scale below 1.0 the regions whose
+/// in-plane curvature already exceeds the constant stay flat.
+///
+/// # Arguments
+///
+/// * `points_2d` - Input 2D curve points
+/// * `curvature_scale` - Multiplier on the maximum 2D curvature (≥ 0)
+///
+/// # Returns
+///
+/// Vector of 3D points forming a smooth spatial curve
+///
+/// # Panics
+///
+/// Panics if fewer than 3 points are given or `curvature_scale` is negative
+///
+/// # Example
+///
+/// ```
+/// use floraison_core::math::curves::reconstruct_3d_curve_scaled;
+/// use floraison_core::Vec2;
+///
+/// let sketch: Vec<Vec2> = (0..20)
+///     .map(|i| {
+///         let y = i as f32 * 0.5;
+///         Vec2::new((y * 0.8).sin(), y)
+///     })
+///     .collect();
+///
+/// // Scale 0 keeps the sketch in its plane
+/// let flat = reconstruct_3d_curve_scaled(&sketch, 0.0);
+/// assert!(flat.iter().all(|p| p.z.abs() < 1e-4));
+/// ```
+pub fn reconstruct_3d_curve_scaled(points_2d: &[Vec2], curvature_scale: f32) -> Vec<Vec3> {
+    assert!(
+        curvature_scale >= 0.0,
+        "Curvature scale must be non-negative"
+    );
     assert!(
         points_2d.len() >= 3,
         "Need at least 3 points for 3D reconstruction"
@@ -529,12 +993,13 @@ pub fn reconstruct_3d_curve(points_2d: &[Vec2]) -> Vec<Vec3> {
         .map(|&v| v.abs())
         .fold(0.0f32, f32::max)
         .max(1e-6); // Avoid division by zero for straight lines
+    let curvature = curvature_scale * max_curvature;
 
     // 4. Solve for |d²z/dy²| using constraint: dx2² + dz2² = k²
     let mut dz2: Vec<f32> = dx2
         .iter()
         .map(|&dx2_val| {
-            let val = max_curvature.powi(2) - dx2_val.powi(2);
+            let val = curvature.powi(2) - dx2_val.powi(2);
             if val > 0.0 {
                 val.sqrt()
             } else {
@@ -638,6 +1103,54 @@ impl AxisCurve {
         }
     }
 
+    /// Create an axis curve that interpolates the points with a smooth spline
+    ///
+    /// [`new`](Self::new) connects the points with straight segments, so a
+    /// coarse axis looks piecewise-linear once swept. This constructor first
+    /// densifies the points with a centripetal Catmull-Rom spline through
+    /// every point (the ends are extended by reflecting their neighbours),
+    /// then builds the arc-length table from the smoothed samples so that
+    /// `t` stays proportional to distance along the curved axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - Points the curve passes through (at least 2 required)
+    /// * `samples_per_segment` - Spline samples between consecutive points (at least 2)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points.len() < 2` or `samples_per_segment < 2`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use floraison_core::math::curves::AxisCurve;
+    /// use floraison_core::Vec3;
+    ///
+    /// let points = vec![Vec3::ZERO, Vec3::Y, Vec3::new(1.0, 1.0, 0.0)];
+    /// let sharp = AxisCurve::new(points.clone());
+    /// let smooth = AxisCurve::with_smoothing(points, 16);
+    ///
+    /// // Same endpoints, but the smooth curve cuts the corner
+    /// assert!((smooth.position_at_t(1.0) - Vec3::new(1.0, 1.0, 0.0)).length() < 1e-5);
+    /// assert!(smooth.length() > 1.5 && smooth.length() < sharp.length() + 0.2);
+    /// ```
+    pub fn with_smoothing(points: Vec<Vec3>, samples_per_segment: usize) -> Self {
+        assert!(points.len() >= 2, "Need at least 2 points for axis curve");
+
+        let n = points.len();
+        let mut padded = Vec::with_capacity(n + 2);
+        padded.push(2.0 * points[0] - points[1]);
+        padded.extend_from_slice(&points);
+        padded.push(2.0 * points[n - 1] - points[n - 2]);
+
+        Self::new(sample_catmull_rom_curve_with_kind(
+            &padded,
+            samples_per_segment,
+            CatmullRomKind::Centripetal,
+        ))
+    }
+
     /// Get total arc length of the curve
     pub fn length(&self) -> f32 {
         self.total_length
@@ -661,16 +1174,56 @@ impl AxisCurve {
         self.sample_at_arc_length(target_length)
     }
 
-    /// Sample curve at specific arc length
+    /// Position-only sample at normalized parameter t ∈ [0, 1]
+    ///
+    /// Uses the same arc-length interpolation as [`sample_at_t`](Self::sample_at_t)
+    /// but skips the tangent, normal and binormal computation. Useful when
+    /// only positions are needed, e.g. for debug polylines or bounding boxes.
     ///
     /// # Arguments
     ///
-    /// * `target_length` - Arc length from curve start
+    /// * `t` - Normalized parameter (0 = start, 1 = end)
     ///
-    /// # Returns
+    /// # Example
     ///
-    /// Sample with position and orientation
-    fn sample_at_arc_length(&self, target_length: f32) -> AxisSample {
+    /// ```
+    /// use floraison_core::math::curves::AxisCurve;
+    /// use floraison_core::Vec3;
+    ///
+    /// let axis = AxisCurve::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)]);
+    /// assert!((axis.position_at_t(0.25) - Vec3::new(0.0, 2.5, 0.0)).length() < 1e-5);
+    /// ```
+    pub fn position_at_t(&self, t: f32) -> Vec3 {
+        let target_length = t.clamp(0.0, 1.0) * self.total_length;
+        let (idx, local_t) = self.locate_arc_length(target_length);
+        self.points[idx].lerp(self.points[idx + 1], local_t)
+    }
+
+    /// Positions at N points evenly spaced by arc length
+    ///
+    /// Position-only counterpart of [`sample_uniform`](Self::sample_uniform).
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of positions to generate
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is 0
+    pub fn positions_uniform(&self, count: usize) -> Vec<Vec3> {
+        assert!(count >= 1, "Need at least 1 sample");
+
+        if count == 1 {
+            return vec![self.position_at_t(0.0)];
+        }
+
+        (0..count)
+            .map(|i| self.position_at_t(i as f32 / (count - 1) as f32))
+            .collect()
+    }
+
+    /// Find the segment index and local interpolation factor for an arc length
+    fn locate_arc_length(&self, target_length: f32) -> (usize, f32) {
         let n = self.points.len();
 
         // Find segment containing target arc length
@@ -695,6 +1248,21 @@ impl AxisCurve {
             0.0
         };
 
+        (idx, local_t)
+    }
+
+    /// Sample curve at specific arc length
+    ///
+    /// # Arguments
+    ///
+    /// * `target_length` - Arc length from curve start
+    ///
+    /// # Returns
+    ///
+    /// Sample with position and orientation
+    fn sample_at_arc_length(&self, target_length: f32) -> AxisSample {
+        let (idx, local_t) = self.locate_arc_length(target_length);
+
         let p0 = self.points[idx];
         let p1 = self.points[idx + 1];
         let position = p0.lerp(p1, local_t);
@@ -813,6 +1381,89 @@ impl AxisCurve {
 
         samples
     }
+
+    /// Sample curve uniformly at N points with rotation-minimizing frames
+    ///
+    /// Frenet frames follow the local curvature direction, so the normal can
+    /// flip by 180° where the curvature reverses (at inflection points) or
+    /// spin around straight sections, which twists swept geometry. This
+    /// method instead propagates a single frame along the curve using the
+    /// double-reflection method (Wang et al. 2008): each frame is reflected
+    /// across the bisector plane of consecutive positions, then across the
+    /// plane that maps the reflected tangent onto the next tangent. The
+    /// result rotates as little as possible around the tangent.
+    ///
+    /// Positions and tangents match [`sample_uniform`](Self::sample_uniform);
+    /// the first normal is an arbitrary perpendicular to the first tangent.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of samples to generate
+    ///
+    /// # Returns
+    ///
+    /// Vector of samples with position and twist-free orientation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use floraison_core::math::curves::AxisCurve;
+    /// use floraison_core::Vec3;
+    ///
+    /// // Planar S-curve: the Frenet normal flips at the inflection point
+    /// let points: Vec<Vec3> = (0..=40)
+    ///     .map(|i| {
+    ///         let y = i as f32 * 0.25;
+    ///         Vec3::new((y * 0.6).sin(), y, 0.0)
+    ///     })
+    ///     .collect();
+    /// let axis = AxisCurve::new(points);
+    ///
+    /// let frames = axis.sample_uniform_rmf(30);
+    /// for pair in frames.windows(2) {
+    ///     assert!(pair[0].binormal.dot(pair[1].binormal) > 0.99);
+    /// }
+    /// ```
+    pub fn sample_uniform_rmf(&self, count: usize) -> Vec<AxisSample> {
+        let mut samples = self.sample_uniform(count);
+
+        let first = &mut samples[0];
+        first.normal = self.arbitrary_perpendicular(&first.tangent);
+        first.binormal = first.tangent.cross(first.normal).normalize_or_zero();
+
+        for i in 1..samples.len() {
+            let (prev, next) = (samples[i - 1].clone(), &mut samples[i]);
+
+            // Reflect across the bisector plane of the two positions
+            let v1 = next.position - prev.position;
+            let c1 = v1.dot(v1);
+            let (reflected_normal, reflected_tangent) = if c1 > 1e-12 {
+                (
+                    prev.normal - (2.0 / c1) * v1.dot(prev.normal) * v1,
+                    prev.tangent - (2.0 / c1) * v1.dot(prev.tangent) * v1,
+                )
+            } else {
+                (prev.normal, prev.tangent)
+            };
+
+            // Reflect again so the tangent lands on the next tangent
+            let v2 = next.tangent - reflected_tangent;
+            let c2 = v2.dot(v2);
+            let normal = if c2 > 1e-12 {
+                reflected_normal - (2.0 / c2) * v2.dot(reflected_normal) * v2
+            } else {
+                reflected_normal
+            };
+
+            // Re-orthogonalize against the tangent to stop drift
+            let normal =
+                (normal - normal.dot(next.tangent) * next.tangent).normalize_or(prev.normal);
+            next.normal = normal;
+            next.binormal = next.tangent.cross(normal).normalize_or_zero();
+        }
+
+        samples
+    }
 }
 
 #[cfg(test)]
@@ -953,6 +1604,57 @@ mod reconstruction_tests {
         let points = vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0)];
         reconstruct_3d_curve(&points);
     }
+
+    fn gentle_sketch() -> Vec<Vec2> {
+        (0..20)
+            .map(|i| {
+                let y = i as f32 * 0.5;
+                Vec2::new((y * std::f32::consts::PI / 4.0).sin() * 0.3, y)
+            })
+            .collect()
+    }
+
+    fn max_abs_z(curve: &[Vec3]) -> f32 {
+        curve.iter().map(|p| p.z.abs()).fold(0.0f32, f32::max)
+    }
+
+    #[test]
+    fn test_reconstruct_3d_scaled_zero_is_flat() {
+        let curve = reconstruct_3d_curve_scaled(&gentle_sketch(), 0.0);
+        assert!(
+            max_abs_z(&curve) < 1e-4,
+            "Scale 0 should stay in plane, max Z = {}",
+            max_abs_z(&curve)
+        );
+    }
+
+    #[test]
+    fn test_reconstruct_3d_scaled_one_matches_default() {
+        let sketch = gentle_sketch();
+        assert_eq!(
+            reconstruct_3d_curve_scaled(&sketch, 1.0),
+            reconstruct_3d_curve(&sketch)
+        );
+    }
+
+    #[test]
+    fn test_reconstruct_3d_scaled_higher_is_stronger() {
+        let sketch = gentle_sketch();
+        let default_z = max_abs_z(&reconstruct_3d_curve(&sketch));
+        let strong_z = max_abs_z(&reconstruct_3d_curve_scaled(&sketch, 3.0));
+        assert!(
+            strong_z > default_z,
+            "Scale 3 should spiral more: {} vs {}",
+            strong_z,
+            default_z
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "non-negative")]
+    fn test_reconstruct_3d_scaled_negative() {
+        reconstruct_3d_curve_scaled(&gentle_sketch(), -1.0);
+    }
 }
 
 #[cfg(test)]
@@ -1101,4 +1803,164 @@ mod axis_tests {
 
         assert_eq!(samples.len(), 1);
     }
+
+    /// Helix of radius 1 around the Y axis
+    fn helix_points(turns: f32, count: usize) -> Vec<Vec3> {
+        (0..count)
+            .map(|i| {
+                let a = i as f32 / (count - 1) as f32 * turns * std::f32::consts::TAU;
+                Vec3::new(a.cos(), a * 0.3, a.sin())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rmf_helix_binormal_varies_smoothly() {
+        let axis = AxisCurve::new(helix_points(3.0, 300));
+        let frames = axis.sample_uniform_rmf(120);
+
+        assert_eq!(frames.len(), 120);
+        for (i, pair) in frames.windows(2).enumerate() {
+            let angle = pair[0]
+                .binormal
+                .dot(pair[1].binormal)
+                .clamp(-1.0, 1.0)
+                .acos();
+            assert!(
+                angle < 15f32.to_radians(),
+                "Binormal jumped by {}° between samples {} and {}",
+                angle.to_degrees(),
+                i,
+                i + 1
+            );
+        }
+    }
+
+    #[test]
+    fn test_rmf_frames_orthonormal() {
+        let axis = AxisCurve::new(helix_points(2.0, 100));
+
+        for frame in axis.sample_uniform_rmf(50) {
+            assert!((frame.normal.length() - 1.0).abs() < 1e-3);
+            assert!((frame.binormal.length() - 1.0).abs() < 1e-3);
+            assert!(frame.tangent.dot(frame.normal).abs() < 1e-3);
+            assert!(frame.tangent.dot(frame.binormal).abs() < 1e-3);
+            assert!(frame.normal.dot(frame.binormal).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_rmf_no_flip_at_inflection() {
+        // Planar S-curve in XY: curvature changes sign halfway up
+        let points: Vec<Vec3> = (0..=60)
+            .map(|i| {
+                let y = i as f32 * 0.1;
+                Vec3::new((y * 1.2).sin() * 0.5, y, 0.0)
+            })
+            .collect();
+        let axis = AxisCurve::new(points);
+
+        // Frenet normals flip across the inflection
+        let frenet = axis.sample_uniform(40);
+        let min_frenet = frenet
+            .windows(2)
+            .map(|p| p[0].normal.dot(p[1].normal))
+            .fold(f32::MAX, f32::min);
+        assert!(min_frenet < 0.0, "Frenet frame should flip on an S-curve");
+
+        // Rotation-minimizing frames keep a consistent orientation
+        let rmf = axis.sample_uniform_rmf(40);
+        for pair in rmf.windows(2) {
+            assert!(pair[0].normal.dot(pair[1].normal) > 0.95);
+        }
+    }
+
+    #[test]
+    fn test_position_at_t_matches_sample_at_t() {
+        let axis = AxisCurve::new(helix_points(1.5, 40));
+
+        for i in 0..=50 {
+            let t = i as f32 / 50.0;
+            assert_eq!(axis.position_at_t(t), axis.sample_at_t(t).position);
+        }
+
+        // Out-of-range parameters clamp like sample_at_t
+        assert_eq!(axis.position_at_t(-1.0), axis.sample_at_t(-1.0).position);
+        assert_eq!(axis.position_at_t(2.0), axis.sample_at_t(2.0).position);
+    }
+
+    #[test]
+    fn test_positions_uniform_matches_sample_uniform() {
+        let axis = AxisCurve::new(helix_points(1.0, 25));
+
+        for count in [1, 2, 17] {
+            let positions = axis.positions_uniform(count);
+            let samples = axis.sample_uniform(count);
+            assert_eq!(positions.len(), count);
+            for (p, s) in positions.iter().zip(&samples) {
+                assert_eq!(*p, s.position);
+            }
+        }
+    }
+
+    /// Largest angle between consecutive segments of a polyline, in degrees
+    fn max_turn_degrees(points: &[Vec3]) -> f32 {
+        points
+            .windows(3)
+            .map(|w| {
+                let a = (w[1] - w[0]).normalize();
+                let b = (w[2] - w[1]).normalize();
+                a.dot(b).clamp(-1.0, 1.0).acos().to_degrees()
+            })
+            .fold(0.0, f32::max)
+    }
+
+    #[test]
+    fn test_with_smoothing_rounds_l_shaped_corner() {
+        let points = vec![Vec3::ZERO, Vec3::Y, Vec3::new(1.0, 1.0, 0.0)];
+
+        let sharp = AxisCurve::new(points.clone()).positions_uniform(41);
+        let smooth = AxisCurve::with_smoothing(points.clone(), 16);
+        let rounded = smooth.positions_uniform(41);
+
+        assert!(
+            max_turn_degrees(&sharp) > 80.0,
+            "Linear axis should have a sharp corner"
+        );
+        assert!(
+            max_turn_degrees(&rounded) < 30.0,
+            "Smoothed axis should round the corner, max turn {}°",
+            max_turn_degrees(&rounded)
+        );
+
+        // Endpoints are preserved
+        assert!((rounded[0] - points[0]).length() < 1e-5);
+        assert!((rounded[40] - points[2]).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_with_smoothing_keeps_arc_length_parameterization() {
+        let smooth = AxisCurve::with_smoothing(
+            vec![
+                Vec3::ZERO,
+                Vec3::new(0.5, 1.0, 0.0),
+                Vec3::new(0.0, 2.0, 0.5),
+            ],
+            24,
+        );
+
+        let positions = smooth.positions_uniform(30);
+        let gaps: Vec<f32> = positions.windows(2).map(|w| w[0].distance(w[1])).collect();
+        let expected = smooth.length() / 29.0;
+        for gap in gaps {
+            assert!((gap - expected).abs() < expected * 0.05);
+        }
+    }
+
+    #[test]
+    fn test_with_smoothing_straight_line_unchanged() {
+        let smooth = AxisCurve::with_smoothing(vec![Vec3::ZERO, Vec3::new(0.0, 4.0, 0.0)], 8);
+        assert!((smooth.length() - 4.0).abs() < 1e-4);
+        assert!((smooth.position_at_t(0.5) - Vec3::new(0.0, 2.0, 0.0)).length() < 1e-4);
+    }
 }
@@ -25,8 +25,13 @@
 //! ```
 
 use crate::{Vec2, Vec3};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
 use std::f32::consts::PI;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Golden angle in radians (≈ 2.399963 rad ≈ 137.5078°)
 ///
 /// The golden angle is the angle that divides a circle in the golden ratio.
@@ -45,6 +50,14 @@ use std::f32::consts::PI;
 /// ```
 pub const GOLDEN_ANGLE: f32 = 2.399_963_1;
 
+/// Lucas angle in radians (≈ 1.736630 rad ≈ 99.5016°)
+///
+/// Divergence angle of the Lucas phyllotaxis series (parastichy numbers
+/// 1, 3, 4, 7, 11, ...), found in some cacti and composites.
+///
+/// Formula: 2π / (2 + φ) where φ is the golden ratio
+pub const LUCAS_ANGLE: f32 = 1.736_629_7;
+
 /// Alternate phyllotaxis: 180° divergence (opposite arrangement)
 ///
 /// Example: leaves on opposite sides of a stem
@@ -122,7 +135,42 @@ pub fn fibonacci_angle(index: usize) -> f32 {
 /// assert!((positions[99].length() - 5.0).abs() < 0.5);
 /// ```
 pub fn vogel_spiral(index: usize, count: usize, radius: f32) -> Vec2 {
-    let angle = index as f32 * GOLDEN_ANGLE;
+    vogel_spiral_divergence(index, count, radius, GOLDEN_ANGLE)
+}
+
+/// Calculate 2D position on a Vogel spiral with a custom divergence angle
+///
+/// Same as [`vogel_spiral`], but each successive element is rotated by
+/// `divergence_angle` instead of the golden angle. The radius still grows as
+/// √(i/n). Angles close to the golden angle pack well; rational fractions of
+/// a full turn (e.g. 90°, 120°) collapse into straight radial spokes.
+///
+/// # Arguments
+/// * `index` - Element index in sequence (0 to count-1)
+/// * `count` - Total number of elements
+/// * `radius` - Maximum radius of the arrangement
+/// * `divergence_angle` - Rotation between consecutive elements in radians
+///
+/// # Returns
+/// 2D position in the disc
+///
+/// # Example
+/// ```
+/// use floraison_core::math::phyllotaxis::{vogel_spiral_divergence, LUCAS_ANGLE};
+///
+/// // Lucas phyllotaxis (≈ 99.5°)
+/// let positions: Vec<_> = (0..50)
+///     .map(|i| vogel_spiral_divergence(i, 50, 1.0, LUCAS_ANGLE))
+///     .collect();
+/// assert!((positions[49].length() - 1.0).abs() < 1e-5);
+/// ```
+pub fn vogel_spiral_divergence(
+    index: usize,
+    count: usize,
+    radius: f32,
+    divergence_angle: f32,
+) -> Vec2 {
+    let angle = index as f32 * divergence_angle;
     let r = if count > 1 {
         radius * (index as f32 / (count - 1) as f32).sqrt()
     } else {
@@ -131,6 +179,194 @@ pub fn vogel_spiral(index: usize, count: usize, radius: f32) -> Vec2 {
     Vec2::new(r * angle.cos(), r * angle.sin())
 }
 
+/// Calculate 2D position on a Vogel spiral with seeded random jitter
+///
+/// Perturbs the [`vogel_spiral`] position radially and tangentially to break
+/// up the perfectly regular look. `jitter` is relative to the average
+/// spacing between neighbours, `radius · √(π / count)`: each offset is drawn
+/// from ±`jitter` × half that spacing, so a jitter of 1.0 moves an element
+/// at most about halfway towards its neighbours. The random offsets come
+/// from a `SmallRng` seeded with `seed + index`, so results are reproducible
+/// and independent of the order in which elements are generated.
+///
+/// # Arguments
+/// * `index` - Element index in sequence (0 to count-1)
+/// * `count` - Total number of elements
+/// * `radius` - Maximum radius of the arrangement
+/// * `jitter` - Offset amplitude relative to neighbour spacing (0 = none)
+/// * `seed` - Random seed
+///
+/// # Returns
+/// 2D position in the disc
+///
+/// # Example
+/// ```
+/// use floraison_core::math::phyllotaxis::{vogel_spiral, vogel_spiral_jittered};
+///
+/// let a = vogel_spiral_jittered(7, 50, 1.0, 0.5, 42);
+/// let b = vogel_spiral_jittered(7, 50, 1.0, 0.5, 42);
+/// assert_eq!(a, b);
+///
+/// assert_eq!(vogel_spiral_jittered(7, 50, 1.0, 0.0, 42), vogel_spiral(7, 50, 1.0));
+/// ```
+pub fn vogel_spiral_jittered(
+    index: usize,
+    count: usize,
+    radius: f32,
+    jitter: f32,
+    seed: u64,
+) -> Vec2 {
+    let base = vogel_spiral(index, count, radius);
+    if jitter <= 0.0 || count == 0 {
+        return base;
+    }
+
+    let mut rng = SmallRng::seed_from_u64(seed.wrapping_add(index as u64));
+    let max_offset = 0.5 * jitter * radius * (PI / count as f32).sqrt();
+
+    let radial = rng.gen_range(-max_offset..=max_offset);
+    let tangential = rng.gen_range(-max_offset..=max_offset);
+
+    let r = base.length();
+    let direction = if r > 1e-6 {
+        base / r
+    } else {
+        Vec2::new(
+            (index as f32 * GOLDEN_ANGLE).cos(),
+            (index as f32 * GOLDEN_ANGLE).sin(),
+        )
+    };
+    let perpendicular = direction.perp();
+
+    base + direction * radial + perpendicular * tangential
+}
+
+/// Calculate 3D position of a Vogel spiral on a paraboloid dome
+///
+/// The flat [`vogel_spiral`] position is placed in the XZ plane and lifted
+/// along Y by `dome_height · (1 - (r / radius)²)`, so the centre element
+/// sits at the apex and the outermost ones at y = 0. Useful for seating
+/// florets or stamens directly on a domed receptacle such as a sunflower
+/// capitulum. A negative `dome_height` gives a dish instead of a dome.
+///
+/// # Arguments
+/// * `index` - Element index in sequence (0 to count-1)
+/// * `count` - Total number of elements
+/// * `radius` - Maximum radius of the arrangement
+/// * `dome_height` - Height of the apex above the rim
+///
+/// # Returns
+/// 3D position on the dome
+///
+/// # Example
+/// ```
+/// use floraison_core::math::phyllotaxis::vogel_spiral_domed;
+///
+/// let apex = vogel_spiral_domed(0, 100, 2.0, 0.5);
+/// assert!((apex.y - 0.5).abs() < 1e-6);
+///
+/// let rim = vogel_spiral_domed(99, 100, 2.0, 0.5);
+/// assert!(rim.y.abs() < 1e-5);
+/// ```
+pub fn vogel_spiral_domed(index: usize, count: usize, radius: f32, dome_height: f32) -> Vec3 {
+    let flat = vogel_spiral(index, count, radius);
+    let y = if radius > 0.0 {
+        let r = flat.length() / radius;
+        dome_height * (1.0 - r * r)
+    } else {
+        dome_height
+    };
+    Vec3::new(flat.x, y, flat.y)
+}
+
+/// Radius constant for [`vogel_spiral_min_spacing`]
+///
+/// On a Vogel spiral with radius c·√i the closest pair is always elements
+/// 0 and 1, exactly c apart; every other pair is at least ≈1.6·c apart. A
+/// constant of 1.0 is therefore the smallest that keeps all elements at
+/// least one spacing apart. (The ideal hexagonal density √(√3/2π) ≈ 0.525
+/// would make the innermost elements overlap.)
+pub const VOGEL_PACKING_CONSTANT: f32 = 1.0;
+
+/// Calculate 2D position on a golden spiral with a guaranteed minimum spacing
+///
+/// Unlike [`vogel_spiral`], which squeezes a given number of elements into a
+/// disc of fixed radius, this grows the radius as
+/// `min_spacing · VOGEL_PACKING_CONSTANT · √index`, so elements keep a fixed
+/// size and the disc grows with the count. No two elements are closer than
+/// `min_spacing`, so discs of radius `min_spacing / 2` never overlap. The
+/// caller decides how many elements to place.
+///
+/// # Arguments
+/// * `index` - Element index in sequence (0, 1, 2, ...)
+/// * `min_spacing` - Minimum distance between any two elements
+///
+/// # Returns
+/// 2D position in the plane
+///
+/// # Example
+/// ```
+/// use floraison_core::math::phyllotaxis::vogel_spiral_min_spacing;
+///
+/// let a = vogel_spiral_min_spacing(10, 0.2);
+/// let b = vogel_spiral_min_spacing(11, 0.2);
+/// assert!(a.distance(b) >= 0.2);
+/// ```
+pub fn vogel_spiral_min_spacing(index: usize, min_spacing: f32) -> Vec2 {
+    let angle = index as f32 * GOLDEN_ANGLE;
+    let r = min_spacing * VOGEL_PACKING_CONSTANT * (index as f32).sqrt();
+    Vec2::new(r * angle.cos(), r * angle.sin())
+}
+
+/// Calculate 2D position in a multijugate spiral
+///
+/// In multijugate phyllotaxis, `jugacy` elements are initiated together at
+/// each step, evenly spread around the centre (360°/k apart), and each
+/// group is rotated by the golden angle divided by k from the previous one.
+/// This yields k interleaved golden spirals, as seen in some Asteraceae
+/// capitula. Elements of the same group share the same radius, which grows
+/// as √(step/steps) like [`vogel_spiral`]. A jugacy of 1 is the ordinary
+/// Vogel spiral.
+///
+/// # Arguments
+/// * `index` - Element index in sequence (0 to count-1)
+/// * `count` - Total number of elements
+/// * `radius` - Maximum radius of the arrangement
+/// * `jugacy` - Number of elements placed per step (k ≥ 1)
+///
+/// # Returns
+/// 2D position in the disc
+///
+/// # Panics
+/// Panics if `jugacy` is 0
+///
+/// # Example
+/// ```
+/// use floraison_core::math::phyllotaxis::jugate_spiral;
+///
+/// // Bijugate arrangement: elements come in opposite pairs
+/// let a = jugate_spiral(6, 40, 1.0, 2);
+/// let b = jugate_spiral(7, 40, 1.0, 2);
+/// assert!((a.length() - b.length()).abs() < 1e-5);
+/// assert!((a + b).length() < 1e-5);
+/// ```
+pub fn jugate_spiral(index: usize, count: usize, radius: f32, jugacy: usize) -> Vec2 {
+    assert!(jugacy >= 1, "Jugacy must be at least 1");
+
+    let step = index / jugacy;
+    let member = index % jugacy;
+    let steps = count.div_ceil(jugacy);
+
+    let angle =
+        step as f32 * GOLDEN_ANGLE / jugacy as f32 + member as f32 * 2.0 * PI / jugacy as f32;
+    let r = if steps > 1 {
+        radius * (step as f32 / (steps - 1) as f32).sqrt()
+    } else {
+        0.0
+    };
+    Vec2::new(r * angle.cos(), r * angle.sin())
+}
+
 /// Calculate positions for radial arrangement (evenly spaced around a circle)
 ///
 /// Places elements at equal angular intervals around a circle, optionally
@@ -245,6 +481,76 @@ pub fn fibonacci_spiral_3d(
     base_radius: f32,
     height: f32,
     radius_fn: Option<fn(f32) -> f32>,
+) -> Vec<Vec3> {
+    fibonacci_spiral_3d_with_profile(count, base_radius, height, radius_fn, HeightProfile::Linear)
+}
+
+/// Mapping from normalized position along a spiral to normalized height
+///
+/// Controls how internode spacing varies along [`fibonacci_spiral_3d_with_profile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HeightProfile {
+    /// Height = t; equal internodes
+    #[default]
+    Linear,
+    /// Height = t²; elements crowd near the base and internodes lengthen upward
+    Compressed,
+    /// Height = √t; internodes shorten toward the tip
+    Expanded,
+}
+
+impl HeightProfile {
+    /// Map normalized position t ∈ [0, 1] to normalized height in [0, 1]
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_core::math::phyllotaxis::HeightProfile;
+    ///
+    /// assert_eq!(HeightProfile::Linear.apply(0.5), 0.5);
+    /// assert_eq!(HeightProfile::Compressed.apply(0.5), 0.25);
+    /// assert!((HeightProfile::Expanded.apply(0.25) - 0.5).abs() < 1e-6);
+    /// ```
+    pub fn apply(self, t: f32) -> f32 {
+        match self {
+            HeightProfile::Linear => t,
+            HeightProfile::Compressed => t * t,
+            HeightProfile::Expanded => t.sqrt(),
+        }
+    }
+}
+
+/// Calculate 3D positions using a Fibonacci spiral with a height profile
+///
+/// Same as [`fibonacci_spiral_3d`], but the height of element i is
+/// `profile.apply(t) · height` instead of `t · height`, so the internode
+/// spacing can vary along the axis. `HeightProfile::Linear` matches
+/// [`fibonacci_spiral_3d`] exactly.
+///
+/// # Arguments
+/// * `count` - Number of elements
+/// * `base_radius` - Cylinder radius
+/// * `height` - Total height of the arrangement
+/// * `radius_fn` - Optional function to vary radius with normalized position [0,1]
+/// * `profile` - Mapping of normalized position to normalized height
+///
+/// # Returns
+/// Vector of 3D positions
+///
+/// # Example
+/// ```
+/// use floraison_core::math::phyllotaxis::{fibonacci_spiral_3d_with_profile, HeightProfile};
+///
+/// let leaves = fibonacci_spiral_3d_with_profile(9, 0.5, 4.0, None, HeightProfile::Expanded);
+/// assert!(leaves[4].y > 2.0);
+/// assert!((leaves[8].y - 4.0).abs() < 1e-5);
+/// ```
+pub fn fibonacci_spiral_3d_with_profile(
+    count: usize,
+    base_radius: f32,
+    height: f32,
+    radius_fn: Option<fn(f32) -> f32>,
+    profile: HeightProfile,
 ) -> Vec<Vec3> {
     if count == 0 {
         return Vec::new();
@@ -258,7 +564,7 @@ pub fn fibonacci_spiral_3d(
                 0.0
             };
             let angle = fibonacci_angle(i);
-            let y = t * height;
+            let y = profile.apply(t) * height;
 
             let radius = if let Some(f) = radius_fn {
                 base_radius * f(t)
@@ -545,4 +851,195 @@ mod tests {
         let spiral = fibonacci_spiral_3d(1, 1.0, 1.0, None);
         assert_eq!(spiral.len(), 1);
     }
+
+    #[test]
+    fn test_vogel_divergence_golden_matches_vogel() {
+        for i in 0..100 {
+            assert_eq!(
+                vogel_spiral_divergence(i, 100, 3.0, GOLDEN_ANGLE),
+                vogel_spiral(i, 100, 3.0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_vogel_divergence_137_5_degrees_close_to_vogel() {
+        let divergence = 137.5f32.to_radians();
+        for i in 0..20 {
+            let a = vogel_spiral_divergence(i, 20, 1.0, divergence);
+            let b = vogel_spiral(i, 20, 1.0);
+            assert!((a.length() - b.length()).abs() < 1e-5);
+            assert!((a - b).length() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_jugate_spiral_pairs_at_equal_radius() {
+        let count = 60;
+        for step in 0..count / 2 {
+            let a = jugate_spiral(2 * step, count, 2.0, 2);
+            let b = jugate_spiral(2 * step + 1, count, 2.0, 2);
+            assert!(
+                (a.length() - b.length()).abs() < 1e-5,
+                "Pair {} should share a radius",
+                step
+            );
+            if step > 0 {
+                // Opposite sides of the centre
+                assert!(a.normalize().dot(b.normalize()) < -0.999);
+            }
+        }
+
+        // Outermost pair reaches the full radius
+        assert!((jugate_spiral(count - 1, count, 2.0, 2).length() - 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_jugate_spiral_jugacy_one_is_vogel() {
+        for i in 0..30 {
+            let a = jugate_spiral(i, 30, 1.5, 1);
+            let b = vogel_spiral(i, 30, 1.5);
+            assert!((a - b).length() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_lucas_angle_value() {
+        assert!((LUCAS_ANGLE.to_degrees() - 99.5016).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_vogel_min_spacing_no_overlap() {
+        let spacing = 0.3;
+        let points: Vec<Vec2> = (0..200)
+            .map(|i| vogel_spiral_min_spacing(i, spacing))
+            .collect();
+
+        let mut min_distance = f32::MAX;
+        for i in 0..points.len() {
+            for j in i + 1..points.len() {
+                min_distance = min_distance.min(points[i].distance(points[j]));
+            }
+        }
+
+        assert!(
+            min_distance >= spacing - 1e-4,
+            "Minimum pairwise distance {} should be at least {}",
+            min_distance,
+            spacing
+        );
+    }
+
+    #[test]
+    fn test_vogel_min_spacing_grows_with_index() {
+        assert_eq!(vogel_spiral_min_spacing(0, 1.0), Vec2::ZERO);
+        let r100 = vogel_spiral_min_spacing(100, 0.5).length();
+        assert!((r100 - 0.5 * VOGEL_PACKING_CONSTANT * 10.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_vogel_domed_apex_and_rim() {
+        let count = 150;
+        let height = 0.8;
+
+        let apex = vogel_spiral_domed(0, count, 3.0, height);
+        assert_eq!(apex.y, height);
+        assert!(apex.x.abs() < 1e-6 && apex.z.abs() < 1e-6);
+
+        let rim = vogel_spiral_domed(count - 1, count, 3.0, height);
+        assert!(rim.y.abs() < 1e-4, "Outermost point should sit near y = 0");
+
+        // Heights decrease monotonically with radius
+        let mut previous = f32::MAX;
+        for i in 0..count {
+            let p = vogel_spiral_domed(i, count, 3.0, height);
+            assert!(p.y <= previous + 1e-6);
+            previous = p.y;
+        }
+    }
+
+    #[test]
+    fn test_vogel_domed_matches_flat_footprint() {
+        for i in 0..40 {
+            let flat = vogel_spiral(i, 40, 1.5);
+            let domed = vogel_spiral_domed(i, 40, 1.5, 0.3);
+            assert!((Vec2::new(domed.x, domed.z) - flat).length() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_vogel_jittered_reproducible() {
+        for i in 0..50 {
+            assert_eq!(
+                vogel_spiral_jittered(i, 50, 2.0, 0.8, 1234),
+                vogel_spiral_jittered(i, 50, 2.0, 0.8, 1234)
+            );
+        }
+    }
+
+    #[test]
+    fn test_vogel_jittered_zero_matches_vogel() {
+        for i in 0..50 {
+            assert_eq!(
+                vogel_spiral_jittered(i, 50, 2.0, 0.0, 99),
+                vogel_spiral(i, 50, 2.0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_vogel_jittered_bounded_and_seed_dependent() {
+        let count = 100;
+        let radius = 2.0;
+        let jitter = 0.6;
+        let max_offset = 0.5 * jitter * radius * (PI / count as f32).sqrt();
+
+        let mut differs = false;
+        for i in 0..count {
+            let base = vogel_spiral(i, count, radius);
+            let a = vogel_spiral_jittered(i, count, radius, jitter, 1);
+            let b = vogel_spiral_jittered(i, count, radius, jitter, 2);
+
+            // Radial and tangential offsets are each within max_offset
+            assert!((a - base).length() <= max_offset * 2f32.sqrt() + 1e-5);
+            differs |= a != b;
+        }
+        assert!(differs, "Different seeds should give different jitter");
+    }
+
+    #[test]
+    fn test_height_profile_linear_matches_default() {
+        assert_eq!(
+            fibonacci_spiral_3d_with_profile(
+                17,
+                0.7,
+                3.0,
+                Some(radius_linear),
+                HeightProfile::Linear
+            ),
+            fibonacci_spiral_3d(17, 0.7, 3.0, Some(radius_linear))
+        );
+    }
+
+    #[test]
+    fn test_height_profile_median_element() {
+        let count = 21;
+        let height = 10.0;
+        let median = count / 2;
+        let linear_mid = height * 0.5;
+
+        let compressed =
+            fibonacci_spiral_3d_with_profile(count, 0.5, height, None, HeightProfile::Compressed);
+        let expanded =
+            fibonacci_spiral_3d_with_profile(count, 0.5, height, None, HeightProfile::Expanded);
+
+        assert!(compressed[median].y < linear_mid);
+        assert!(expanded[median].y > linear_mid);
+
+        // Both still span the full height
+        for spiral in [&compressed, &expanded] {
+            assert!(spiral[0].y.abs() < 1e-6);
+            assert!((spiral[count - 1].y - height).abs() < 1e-5);
+        }
+    }
 }
@@ -3,7 +3,7 @@
 //! This module provides helper functions for common operations in flower generation,
 //! including coordinate system conversions and vector transformations.
 
-use glam::{Vec2, Vec3};
+use glam::{Quat, Vec2, Vec3};
 
 /// Extensions for Vec3 to support cylindrical and spherical coordinates
 pub trait Vec3Ext {
@@ -275,6 +275,42 @@ pub fn remap(value: f32, in_min: f32, in_max: f32, out_min: f32, out_max: f32) -
     lerp(out_min, out_max, t)
 }
 
+/// Shortest rotation that maps direction `from` onto direction `to`
+///
+/// A robust wrapper around [`Quat::from_rotation_arc`]:
+/// - Inputs don't need to be normalized
+/// - Antiparallel (and nearly antiparallel) inputs rotate 180° about an
+///   arbitrary axis perpendicular to `from`, instead of producing NaNs
+/// - Zero-length or non-finite inputs return the identity rotation
+///
+/// # Arguments
+/// * `from` - Direction to rotate from (e.g. a mesh's local up axis)
+/// * `to` - Direction to rotate onto (e.g. a surface normal)
+///
+/// # Example
+/// ```
+/// use floraison_core::math::vector::rotation_between;
+/// use glam::Vec3;
+///
+/// let q = rotation_between(Vec3::Y, Vec3::new(2.0, 0.0, 0.0));
+/// assert!((q * Vec3::Y - Vec3::X).length() < 1e-5);
+///
+/// // Antiparallel: Y flipped to -Y without NaNs
+/// let flip = rotation_between(Vec3::Y, -Vec3::Y);
+/// assert!((flip * Vec3::Y + Vec3::Y).length() < 1e-5);
+/// ```
+pub fn rotation_between(from: Vec3, to: Vec3) -> Quat {
+    let (Some(from), Some(to)) = (from.try_normalize(), to.try_normalize()) else {
+        return Quat::IDENTITY;
+    };
+
+    if from.dot(to) < -1.0 + 1e-6 {
+        Quat::from_axis_angle(from.any_orthonormal_vector(), std::f32::consts::PI)
+    } else {
+        Quat::from_rotation_arc(from, to)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -427,4 +463,38 @@ mod tests {
         assert_eq!(r, 0.0);
         assert_eq!(phi, 0.0);
     }
+
+    #[test]
+    fn test_rotation_between_basic() {
+        let q = rotation_between(Vec3::Y, Vec3::Z);
+        assert!((q * Vec3::Y - Vec3::Z).length() < EPSILON);
+
+        let same = rotation_between(Vec3::X, Vec3::X * 3.0);
+        assert!((same * Vec3::X - Vec3::X).length() < EPSILON);
+    }
+
+    #[test]
+    fn test_rotation_between_antiparallel() {
+        for dir in [Vec3::X, Vec3::Y, Vec3::Z, Vec3::new(1.0, 2.0, -3.0)] {
+            let q = rotation_between(dir, -dir);
+            assert!(q.is_finite());
+            assert!((q * dir.normalize() + dir.normalize()).length() < 1e-4);
+        }
+
+        // Nearly antiparallel
+        let almost = Vec3::new(1e-5, -1.0, 0.0);
+        let q = rotation_between(Vec3::Y, almost);
+        assert!(q.is_finite());
+        assert!((q * Vec3::Y - almost.normalize()).length() < 1e-3);
+    }
+
+    #[test]
+    fn test_rotation_between_degenerate() {
+        assert_eq!(rotation_between(Vec3::ZERO, Vec3::Y), Quat::IDENTITY);
+        assert_eq!(rotation_between(Vec3::Y, Vec3::ZERO), Quat::IDENTITY);
+        assert_eq!(
+            rotation_between(Vec3::Y, Vec3::new(f32::NAN, 0.0, 0.0)),
+            Quat::IDENTITY
+        );
+    }
 }
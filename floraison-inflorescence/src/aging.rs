@@ -1,7 +1,8 @@
 //! Flower aging system
 //!
 //! Provides support for age-based flower appearance, allowing inflorescences
-//! to display flowers at different developmental stages (bud, bloom, wilt).
+//! to display flowers at different developmental stages (bud, bloom, wilt,
+//! and optionally fruit).
 
 use floraison_core::geometry::mesh::Mesh;
 
@@ -21,11 +22,7 @@ use serde::{Deserialize, Serialize};
 /// let bloom = Mesh::new();  // Open flower mesh
 /// let wilt = Mesh::new();   // Wilted flower mesh
 ///
-/// let aging = FlowerAging {
-///     bud_mesh: bud,
-///     bloom_mesh: bloom,
-///     wilt_mesh: Some(wilt),
-/// };
+/// let aging = FlowerAging::with_wilt(bud, bloom, wilt);
 ///
 /// // Select appropriate mesh based on age (0.0-1.0)
 /// let young_flower = aging.select_mesh(0.2);  // Returns bud
@@ -45,6 +42,65 @@ pub struct FlowerAging {
     ///
     /// If `None`, `bloom_mesh` will be used for old flowers
     pub wilt_mesh: Option<Mesh>,
+
+    /// Age at which flowers switch from bud to bloom (default 0.3)
+    #[cfg_attr(feature = "serde", serde(default = "default_bud_threshold"))]
+    pub bud_threshold: f32,
+
+    /// Age at which flowers switch from bloom to wilt (default 0.8)
+    ///
+    /// Set above 1.0 to skip the wilt stage entirely.
+    #[cfg_attr(feature = "serde", serde(default = "default_wilt_threshold"))]
+    pub wilt_threshold: f32,
+
+    /// Optional mesh for the developing fruit/seed capsule
+    ///
+    /// If `None`, old flowers stay in the wilt (or bloom) stage
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fruit_mesh: Option<Mesh>,
+
+    /// Age at which wilted flowers are replaced by fruit (default 0.95)
+    ///
+    /// Only used when `fruit_mesh` is set.
+    #[cfg_attr(feature = "serde", serde(default = "default_fruit_threshold"))]
+    pub fruit_threshold: f32,
+}
+
+/// Developmental stage of a flower, as chosen by [`FlowerAging::stage`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlowerStage {
+    /// Young, unopened flower
+    Bud,
+    /// Mature, open flower
+    Bloom,
+    /// Old, wilted flower
+    Wilt,
+    /// Developing fruit/seed capsule
+    Fruit,
+}
+
+/// Default age at which buds open into blooms
+pub const DEFAULT_BUD_THRESHOLD: f32 = 0.3;
+
+/// Default age at which blooms start to wilt
+pub const DEFAULT_WILT_THRESHOLD: f32 = 0.8;
+
+/// Default age at which wilted flowers turn into fruit
+pub const DEFAULT_FRUIT_THRESHOLD: f32 = 0.95;
+
+#[cfg(feature = "serde")]
+fn default_bud_threshold() -> f32 {
+    DEFAULT_BUD_THRESHOLD
+}
+
+#[cfg(feature = "serde")]
+fn default_wilt_threshold() -> f32 {
+    DEFAULT_WILT_THRESHOLD
+}
+
+#[cfg(feature = "serde")]
+fn default_fruit_threshold() -> f32 {
+    DEFAULT_FRUIT_THRESHOLD
 }
 
 impl FlowerAging {
@@ -69,6 +125,10 @@ impl FlowerAging {
             bud_mesh,
             bloom_mesh,
             wilt_mesh: None,
+            bud_threshold: DEFAULT_BUD_THRESHOLD,
+            wilt_threshold: DEFAULT_WILT_THRESHOLD,
+            fruit_mesh: None,
+            fruit_threshold: DEFAULT_FRUIT_THRESHOLD,
         }
     }
 
@@ -95,15 +155,106 @@ impl FlowerAging {
             bud_mesh,
             bloom_mesh,
             wilt_mesh: Some(wilt_mesh),
+            bud_threshold: DEFAULT_BUD_THRESHOLD,
+            wilt_threshold: DEFAULT_WILT_THRESHOLD,
+            fruit_mesh: None,
+            fruit_threshold: DEFAULT_FRUIT_THRESHOLD,
+        }
+    }
+
+    /// Override the stage transition thresholds
+    ///
+    /// # Arguments
+    /// * `bud_threshold` - Age at which buds open (bud → bloom)
+    /// * `wilt_threshold` - Age at which blooms wilt (bloom → wilt); use a value
+    ///   above 1.0 to never wilt
+    ///
+    /// # Panics
+    /// Panics if `bud_threshold > wilt_threshold`
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_inflorescence::aging::FlowerAging;
+    /// use floraison_core::geometry::mesh::Mesh;
+    ///
+    /// // Buds persist longer, flowers never wilt
+    /// let aging = FlowerAging::new(Mesh::new(), Mesh::new()).with_thresholds(0.5, 1.1);
+    /// assert_eq!(aging.thresholds(), (0.5, 1.1));
+    /// ```
+    pub fn with_thresholds(mut self, bud_threshold: f32, wilt_threshold: f32) -> Self {
+        assert!(
+            bud_threshold <= wilt_threshold,
+            "Bud threshold must not exceed wilt threshold"
+        );
+        self.bud_threshold = bud_threshold;
+        self.wilt_threshold = wilt_threshold;
+        self
+    }
+
+    /// Add a fourth stage: fruit/seed capsule replacing the oldest flowers
+    ///
+    /// # Arguments
+    /// * `fruit_mesh` - Mesh representing the developing fruit
+    /// * `fruit_threshold` - Age at which flowers turn into fruit
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_inflorescence::aging::FlowerAging;
+    /// use floraison_core::geometry::mesh::Mesh;
+    ///
+    /// let aging = FlowerAging::with_wilt(Mesh::new(), Mesh::new(), Mesh::new())
+    ///     .with_fruit(Mesh::new(), 0.9);
+    /// assert!(aging.fruit_mesh.is_some());
+    /// ```
+    pub fn with_fruit(mut self, fruit_mesh: Mesh, fruit_threshold: f32) -> Self {
+        self.fruit_mesh = Some(fruit_mesh);
+        self.fruit_threshold = fruit_threshold;
+        self
+    }
+
+    /// Stage a flower of the given age is shown in
+    ///
+    /// Uses discrete thresholds for stage transitions (defaults shown):
+    /// - `age < bud_threshold` (0.3): bud stage
+    /// - `bud_threshold <= age < wilt_threshold` (0.3–0.8): bloom stage
+    /// - `wilt_threshold <= age < fruit_threshold` (0.8–0.95): wilt stage (if available)
+    /// - `age >= fruit_threshold` (0.95): fruit stage (if available)
+    ///
+    /// Missing wilt meshes fall back to bloom; without a fruit mesh old
+    /// flowers stay wilted (or in bloom).
+    ///
+    /// # Arguments
+    /// * `age` - Normalized age value (0.0 = youngest, 1.0 = oldest)
+    ///
+    /// # Returns
+    /// The stage whose mesh [`select_mesh`](Self::select_mesh) returns
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_inflorescence::aging::{FlowerAging, FlowerStage};
+    /// use floraison_core::geometry::mesh::Mesh;
+    ///
+    /// let aging = FlowerAging::new(Mesh::new(), Mesh::new()).with_fruit(Mesh::new(), 0.9);
+    ///
+    /// assert_eq!(aging.stage(0.1), FlowerStage::Bud);
+    /// assert_eq!(aging.stage(0.85), FlowerStage::Bloom); // No wilt mesh
+    /// assert_eq!(aging.stage(0.95), FlowerStage::Fruit);
+    /// ```
+    pub fn stage(&self, age: f32) -> FlowerStage {
+        if self.fruit_mesh.is_some() && age >= self.fruit_threshold {
+            FlowerStage::Fruit
+        } else if age < self.bud_threshold {
+            FlowerStage::Bud
+        } else if age < self.wilt_threshold || self.wilt_mesh.is_none() {
+            FlowerStage::Bloom
+        } else {
+            FlowerStage::Wilt
         }
     }
 
     /// Select appropriate mesh based on flower age
     ///
-    /// Uses discrete thresholds for stage transitions:
-    /// - `age < 0.3`: bud stage
-    /// - `0.3 <= age < 0.8`: bloom stage
-    /// - `age >= 0.8`: wilt stage (if available)
+    /// The stage is chosen by [`stage`](Self::stage).
     ///
     /// # Arguments
     /// * `age` - Normalized age value (0.0 = youngest, 1.0 = oldest)
@@ -121,14 +272,109 @@ impl FlowerAging {
     /// let mesh = aging.select_mesh(0.5); // Bloom stage
     /// ```
     pub fn select_mesh(&self, age: f32) -> &Mesh {
-        if age < 0.3 {
-            &self.bud_mesh
-        } else if age < 0.8 {
-            &self.bloom_mesh
+        match self.stage(age) {
+            FlowerStage::Bud => &self.bud_mesh,
+            FlowerStage::Bloom => &self.bloom_mesh,
+            FlowerStage::Wilt => self.wilt_mesh.as_ref().unwrap_or(&self.bloom_mesh),
+            FlowerStage::Fruit => self.fruit_mesh.as_ref().unwrap_or(&self.bloom_mesh),
+        }
+    }
+
+    /// Build a mesh smoothly morphed between the stages adjacent to `age`
+    ///
+    /// Unlike [`select_mesh`](Self::select_mesh), which snaps between stages,
+    /// this linearly interpolates vertex positions, normals, UVs and colors
+    /// so flowers don't visibly pop as ages change. Each stage is pure at its
+    /// center (bud: `bud_threshold / 2`, bloom: midway between the thresholds,
+    /// wilt: midway between `wilt_threshold` and 1.0) and adjacent stages are
+    /// mixed 50/50 exactly at the threshold between them, so the dominant
+    /// stage always matches `select_mesh`. The fruit stage is not blended:
+    /// fruit replaces the flower at `fruit_threshold`.
+    ///
+    /// # Topology requirement
+    /// Interpolation is only possible between meshes that share topology: the
+    /// same vertex count and identical triangle indices, with vertex `i` of one
+    /// stage corresponding to vertex `i` of the other (e.g. the same generator
+    /// run with different parameter values but equal resolutions). If two
+    /// adjacent stages differ in topology, this falls back to the nearest
+    /// stage, as returned by `select_mesh`.
+    ///
+    /// # Arguments
+    /// * `age` - Normalized age value (0.0 = youngest, 1.0 = oldest)
+    ///
+    /// # Returns
+    /// New mesh for this age
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_inflorescence::aging::FlowerAging;
+    /// use floraison_core::geometry::mesh::Mesh;
+    /// use floraison_core::{Vec2, Vec3};
+    ///
+    /// let point = |y: f32| {
+    ///     let mut mesh = Mesh::new();
+    ///     mesh.add_vertex(Vec3::new(0.0, y, 0.0), Vec3::Y, Vec2::ZERO, Vec3::ONE);
+    ///     mesh
+    /// };
+    /// let aging = FlowerAging::new(point(0.0), point(1.0));
+    ///
+    /// // Halfway at the bud threshold
+    /// assert!((aging.blend_mesh(0.3).positions[0].y - 0.5).abs() < 1e-5);
+    /// ```
+    pub fn blend_mesh(&self, age: f32) -> Mesh {
+        if let Some(fruit) = self
+            .fruit_mesh
+            .as_ref()
+            .filter(|_| age >= self.fruit_threshold)
+        {
+            return fruit.clone();
+        }
+
+        // A wilt threshold above 1.0 skips the wilt stage entirely
+        let wilt = self
+            .wilt_mesh
+            .as_ref()
+            .filter(|_| self.wilt_threshold <= 1.0);
+
+        let bud_center = self.bud_threshold * 0.5;
+        let bloom_center = (self.bud_threshold + self.wilt_threshold.min(1.0)) * 0.5;
+
+        let (from, to, t) = if age < bloom_center {
+            let t = stage_weight(age, bud_center, self.bud_threshold, bloom_center);
+            (&self.bud_mesh, &self.bloom_mesh, t)
+        } else if let Some(wilt) = wilt {
+            let wilt_center = (self.wilt_threshold + 1.0) * 0.5;
+            let t = stage_weight(age, bloom_center, self.wilt_threshold, wilt_center);
+            (&self.bloom_mesh, wilt, t)
         } else {
-            // Use wilt if available, otherwise fallback to bloom
-            self.wilt_mesh.as_ref().unwrap_or(&self.bloom_mesh)
+            return self.bloom_mesh.clone();
+        };
+
+        if t <= 0.0 {
+            return from.clone();
         }
+        if t >= 1.0 {
+            return to.clone();
+        }
+        if from.vertex_count() != to.vertex_count() || from.indices != to.indices {
+            return self.select_mesh(age).clone();
+        }
+
+        let mut mesh = from.clone();
+        let pairs = mesh
+            .positions
+            .iter_mut()
+            .zip(&to.positions)
+            .zip(mesh.normals.iter_mut().zip(&to.normals))
+            .zip(mesh.uvs.iter_mut().zip(&to.uvs))
+            .zip(mesh.colors.iter_mut().zip(&to.colors));
+        for (((position, normal), uv), color) in pairs {
+            *position.0 = position.0.lerp(*position.1, t);
+            *normal.0 = normal.0.lerp(*normal.1, t).normalize_or_zero();
+            *uv.0 = uv.0.lerp(*uv.1, t);
+            *color.0 = color.0.lerp(*color.1, t);
+        }
+        mesh
     }
 
     /// Get thresholds used for stage transitions
@@ -148,7 +394,23 @@ impl FlowerAging {
     /// assert_eq!(wilt_threshold, 0.8);
     /// ```
     pub fn thresholds(&self) -> (f32, f32) {
-        (0.3, 0.8)
+        (self.bud_threshold, self.wilt_threshold)
+    }
+}
+
+/// Weight of the later stage when blending between two adjacent stages
+///
+/// 0.0 at or before `from_center`, 0.5 at `threshold`, 1.0 at or after
+/// `to_center`, piecewise linear in between.
+fn stage_weight(age: f32, from_center: f32, threshold: f32, to_center: f32) -> f32 {
+    if age <= from_center {
+        0.0
+    } else if age >= to_center {
+        1.0
+    } else if age < threshold {
+        0.5 * (age - from_center) / (threshold - from_center)
+    } else {
+        0.5 + 0.5 * (age - threshold) / (to_center - threshold)
     }
 }
 
@@ -244,6 +506,68 @@ mod tests {
         assert_eq!(aging.select_mesh(1.0).vertex_count(), 5);
     }
 
+    /// Mesh with the same topology as `create_test_mesh`, shifted up by `y`
+    fn create_shifted_mesh(vertex_count: usize, y: f32, color: Vec3) -> Mesh {
+        let mut mesh = create_test_mesh(vertex_count);
+        for (position, c) in mesh.positions.iter_mut().zip(mesh.colors.iter_mut()) {
+            position.y = y;
+            *c = color;
+        }
+        mesh
+    }
+
+    #[test]
+    fn test_blend_mesh_between_bud_and_bloom() {
+        let bud = create_shifted_mesh(4, 0.0, Vec3::new(0.0, 1.0, 0.0));
+        let bloom = create_shifted_mesh(4, 2.0, Vec3::new(1.0, 0.0, 0.0));
+        let aging = FlowerAging::new(bud.clone(), bloom.clone());
+
+        // Exactly between bud and bloom: positions lie between the two source meshes
+        let blended = aging.blend_mesh(aging.bud_threshold);
+        assert_eq!(blended.vertex_count(), 4);
+        for ((p, b), o) in blended
+            .positions
+            .iter()
+            .zip(&bud.positions)
+            .zip(&bloom.positions)
+        {
+            assert!(p.y > b.y && p.y < o.y);
+            assert!((*p - (*b + *o) * 0.5).length() < 1e-5);
+        }
+        assert!((blended.colors[0] - Vec3::new(0.5, 0.5, 0.0)).length() < 1e-5);
+
+        // Pure stages at their centers
+        assert_eq!(aging.blend_mesh(0.15).positions, bud.positions);
+        assert_eq!(aging.blend_mesh(0.55).positions, bloom.positions);
+
+        // Continuous: no popping across the threshold
+        let below = aging.blend_mesh(0.299).positions[0];
+        let above = aging.blend_mesh(0.301).positions[0];
+        assert!(below.distance(above) < 0.05);
+    }
+
+    #[test]
+    fn test_blend_mesh_into_wilt() {
+        let aging = FlowerAging::with_wilt(
+            create_shifted_mesh(2, 0.0, Vec3::ONE),
+            create_shifted_mesh(2, 2.0, Vec3::ONE),
+            create_shifted_mesh(2, -1.0, Vec3::ONE),
+        );
+
+        let at_threshold = aging.blend_mesh(aging.wilt_threshold);
+        assert!((at_threshold.positions[0].y - 0.5).abs() < 1e-5);
+        assert_eq!(aging.blend_mesh(1.0).positions[0].y, -1.0);
+    }
+
+    #[test]
+    fn test_blend_mesh_topology_fallback() {
+        let aging = FlowerAging::new(create_test_mesh(3), create_test_mesh(5));
+
+        // Different vertex counts: snaps like select_mesh
+        assert_eq!(aging.blend_mesh(0.29).vertex_count(), 3);
+        assert_eq!(aging.blend_mesh(0.3).vertex_count(), 5);
+    }
+
     #[test]
     fn test_thresholds() {
         let aging = FlowerAging::new(create_test_mesh(1), create_test_mesh(1));
@@ -306,6 +630,107 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_custom_thresholds() {
+        let bud = create_test_mesh(3);
+        let bloom = create_test_mesh(5);
+        let wilt = create_test_mesh(4);
+        let default_aging = FlowerAging::with_wilt(bud.clone(), bloom.clone(), wilt.clone());
+        let late_aging = FlowerAging::with_wilt(bud, bloom, wilt).with_thresholds(0.5, 1.1);
+
+        // Same age, different stage
+        assert_eq!(default_aging.select_mesh(0.4).vertex_count(), 5);
+        assert_eq!(late_aging.select_mesh(0.4).vertex_count(), 3);
+
+        // Wilting skipped entirely
+        assert_eq!(default_aging.select_mesh(1.0).vertex_count(), 4);
+        assert_eq!(late_aging.select_mesh(1.0).vertex_count(), 5);
+    }
+
+    #[test]
+    fn test_raised_wilt_threshold_keeps_bloom() {
+        let aging = FlowerAging::with_wilt(
+            create_test_mesh(3),
+            create_test_mesh(5),
+            create_test_mesh(4),
+        );
+        assert_eq!(aging.select_mesh(0.9).vertex_count(), 4);
+
+        // Age 0.9 stays in bloom once wilting starts at 0.95
+        let aging = aging.with_thresholds(DEFAULT_BUD_THRESHOLD, 0.95);
+        assert_eq!(aging.select_mesh(0.9).vertex_count(), 5);
+        assert_eq!(aging.select_mesh(0.95).vertex_count(), 4);
+
+        // "All blooms" age follows the custom bloom range
+        let all_bloom =
+            crate::apply_age_distribution_with_thresholds(0.0, 1.0, aging.bud_threshold, 0.95);
+        assert_eq!(aging.select_mesh(all_bloom).vertex_count(), 5);
+        assert!((crate::apply_age_distribution(0.0, 1.0) - 0.55).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_select_mesh_fruit_stage() {
+        let three_stage = FlowerAging::with_wilt(
+            create_test_mesh(3),
+            create_test_mesh(5),
+            create_test_mesh(4),
+        );
+        assert!(three_stage.fruit_mesh.is_none());
+        assert_eq!(three_stage.select_mesh(1.0).vertex_count(), 4);
+
+        let aging = three_stage.with_fruit(create_test_mesh(2), 0.9);
+        assert_eq!(aging.select_mesh(0.5).vertex_count(), 5);
+        assert_eq!(aging.select_mesh(0.85).vertex_count(), 4);
+        assert_eq!(aging.select_mesh(0.9).vertex_count(), 2);
+        assert_eq!(aging.select_mesh(1.0).vertex_count(), 2);
+        assert_eq!(aging.blend_mesh(0.95).vertex_count(), 2);
+
+        // Fruit without a wilt stage
+        let aging = FlowerAging::new(create_test_mesh(3), create_test_mesh(5))
+            .with_fruit(create_test_mesh(2), DEFAULT_FRUIT_THRESHOLD);
+        assert_eq!(aging.select_mesh(0.9).vertex_count(), 5);
+        assert_eq!(aging.select_mesh(0.95).vertex_count(), 2);
+    }
+
+    #[test]
+    fn test_stage_matches_select_mesh() {
+        let aging = FlowerAging::with_wilt(
+            create_test_mesh(3),
+            create_test_mesh(5),
+            create_test_mesh(4),
+        )
+        .with_fruit(create_test_mesh(2), 0.9);
+
+        for (age, stage, vertices) in [
+            (0.0, FlowerStage::Bud, 3),
+            (0.3, FlowerStage::Bloom, 5),
+            (0.8, FlowerStage::Wilt, 4),
+            (0.9, FlowerStage::Fruit, 2),
+        ] {
+            assert_eq!(aging.stage(age), stage);
+            assert_eq!(aging.select_mesh(age).vertex_count(), vertices);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Bud threshold must not exceed wilt threshold")]
+    fn test_invalid_thresholds() {
+        FlowerAging::new(create_test_mesh(1), create_test_mesh(1)).with_thresholds(0.9, 0.5);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_default_thresholds() {
+        let aging = FlowerAging::new(create_test_mesh(1), create_test_mesh(1));
+        let mut value = serde_json::to_value(&aging).unwrap();
+        let obj = value.as_object_mut().unwrap();
+        obj.remove("bud_threshold");
+        obj.remove("wilt_threshold");
+
+        let deserialized: FlowerAging = serde_json::from_value(value).unwrap();
+        assert_eq!(deserialized.thresholds(), (0.3, 0.8));
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_serde_serialization() {
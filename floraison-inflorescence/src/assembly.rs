@@ -1,11 +1,20 @@
 //! Inflorescence assembly - combining axis, branches, and flowers into complete structure
 
-use floraison_core::math::curves::AxisCurve;
+use floraison_core::math::curves::{compute_arc_lengths, AxisCurve};
+use floraison_core::math::vector::rotation_between;
 use floraison_core::{
-    geometry::mesh::Mesh, geometry::sweep::sweep_along_curve, Mat4, Quat, Vec2, Vec3,
+    geometry::mesh::Mesh,
+    geometry::sweep::{sweep_along_curve_tapered, sweep_cross_section},
+    Mat3, Mat4, Quat, Vec2, Vec3,
 };
 
-use crate::{aging::FlowerAging, patterns, CurveMode, InflorescenceParams, PatternType};
+use crate::{
+    aging::FlowerAging, patterns, BranchPoint, CurveMode, InflorescenceParams, PatternType,
+    StemProfile,
+};
+use glam::IVec3;
+use std::borrow::Cow;
+use std::collections::HashMap;
 
 // ============================================================================
 // Curve Generation Utilities (Shared by Axis and Branches)
@@ -97,8 +106,13 @@ pub fn generate_curved_points(
 ///
 /// # Returns
 ///
-/// Vector of 3D points from origin (0,0,0) to axis top, curved if specified
+/// Vector of 3D points from origin (0,0,0) to axis top, curved if specified.
+/// Catkins always droop and hang below the origin instead.
 pub fn generate_axis_points(params: &InflorescenceParams) -> Vec<Vec3> {
+    if params.pattern == PatternType::Catkin {
+        return patterns::catkin::generate_axis_points(params);
+    }
+
     let start = Vec3::ZERO;
     let end = Vec3::new(0.0, params.axis_length, 0.0);
 
@@ -155,6 +169,89 @@ pub fn assemble_inflorescence(
     flower_mesh: &Mesh,
     stem_color: Vec3,
 ) -> Mesh {
+    assemble_with_flower_source(params, flower_mesh, stem_color, |_, _| {
+        Cow::Borrowed(flower_mesh)
+    })
+}
+
+/// Assemble an inflorescence with a separately built flower for each branch
+///
+/// Like [`assemble_inflorescence`], but asks `flower_at` for the flower of
+/// every branch point, passing the branch index and the branch itself. This
+/// lets callers vary flowers along the inflorescence, e.g. by using the
+/// index as a jitter seed offset so that no two flowers are identical, or
+/// the branch age to pick a developmental stage.
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters (pattern type, dimensions, angles, etc.)
+/// * `compound_flower` - Flower replicated by compound patterns, which don't call `flower_at`
+/// * `stem_color` - RGB color for stem and pedicel geometry
+/// * `flower_at` - Builds the untransformed flower mesh for (branch index, branch)
+///
+/// # Returns
+/// Complete inflorescence mesh with all components merged
+///
+/// # Example
+/// ```
+/// use floraison_inflorescence::{InflorescenceParams, PatternType, assembly::assemble_inflorescence_with_flowers};
+/// use floraison_core::{geometry::mesh::Mesh, Vec3};
+///
+/// let params = InflorescenceParams {
+///     pattern: PatternType::Raceme,
+///     branch_count: 4,
+///     ..Default::default()
+/// };
+///
+/// let mut seen = Vec::new();
+/// let inflorescence = assemble_inflorescence_with_flowers(
+///     &params,
+///     &Mesh::new(),
+///     Vec3::new(0.2, 0.6, 0.2),
+///     |index, _branch| {
+///         seen.push(index);
+///         Mesh::new() // e.g. generate_flower_seeded(&flower_params, index as u64)
+///     },
+/// );
+///
+/// assert_eq!(seen, vec![0, 1, 2, 3]);
+/// assert!(inflorescence.vertex_count() > 0);
+/// ```
+pub fn assemble_inflorescence_with_flowers<F>(
+    params: &InflorescenceParams,
+    compound_flower: &Mesh,
+    stem_color: Vec3,
+    mut flower_at: F,
+) -> Mesh
+where
+    F: FnMut(usize, &BranchPoint) -> Mesh,
+{
+    assemble_with_flower_source(params, compound_flower, stem_color, |index, branch| {
+        Cow::Owned(flower_at(index, branch))
+    })
+}
+
+/// Shared assembly for flowers that are either borrowed templates or built per branch
+///
+/// Borrowed flowers are transformed while being merged, so replicating a
+/// template never copies it first.
+fn assemble_with_flower_source<'m, F>(
+    params: &InflorescenceParams,
+    compound_flower: &Mesh,
+    stem_color: Vec3,
+    mut flower_at: F,
+) -> Mesh
+where
+    F: FnMut(usize, &BranchPoint) -> Cow<'m, Mesh>,
+{
+    // Keep runaway recursive patterns within the flower budget
+    let mut limited = patterns::limit_flower_count(params);
+
+    // Validation reports too few stem segments; build the coarsest usable stem instead
+    if limited.stem_segments < 3 {
+        limited.to_mut().stem_segments = 3;
+    }
+    let params = limited.as_ref();
+
     let mut final_mesh = Mesh::new();
 
     // 1. Generate axis curve (straight or curved based on params)
@@ -165,60 +262,341 @@ pub fn assemble_inflorescence(
     let branches = match params.pattern {
         PatternType::Raceme => patterns::raceme::generate_branch_points(params, &axis),
         PatternType::Spike => patterns::spike::generate_branch_points(params, &axis),
+        PatternType::Catkin => patterns::catkin::generate_branch_points(params, &axis),
+        PatternType::Spadix => patterns::spadix::generate_branch_points(params, &axis),
         PatternType::Umbel => patterns::umbel::generate_branch_points(params, &axis),
         PatternType::Corymb => patterns::corymb::generate_branch_points(params, &axis),
+        PatternType::Panicle => patterns::panicle::generate_branch_points(params, &axis),
+        PatternType::Verticillaster => {
+            patterns::verticillaster::generate_branch_points(params, &axis)
+        }
         PatternType::Dichasium => patterns::dichasium::generate_branch_points(params, &axis),
         PatternType::Drepanium => patterns::drepanium::generate_branch_points(params, &axis),
+        PatternType::Thyrse => patterns::thyrse::generate_branch_points(params, &axis),
         PatternType::CompoundRaceme => {
             // Compound patterns bypass branch points and generate mesh directly
             return patterns::compound_raceme::generate_compound_raceme(
                 params,
-                flower_mesh,
+                compound_flower,
                 stem_color,
             );
         }
         PatternType::CompoundUmbel => {
             return patterns::compound_umbel::generate_compound_umbel(
                 params,
-                flower_mesh,
+                compound_flower,
                 stem_color,
             );
         }
     };
 
-    // 3. Generate main stem mesh (cylinder along axis)
-    let stem_radius = 0.05; // Fixed radius for now
-    let stem_mesh = generate_stem_along_axis(&axis_points, stem_radius, stem_color);
+    // 3. Generate main stem mesh (cylinder along axis, optionally with nodes)
+    let stem_radius = params.stem_radius;
+    let stem_mesh = if params.pattern == PatternType::Spadix {
+        patterns::spadix::generate_spadix_stem(params, &axis_points, stem_radius, stem_color)
+    } else if params.stem_node_bulge > 0.0 {
+        generate_stem_with_nodes_tapered(
+            &axis_points,
+            &branches,
+            stem_radius,
+            params.stem_radius_top,
+            params.stem_node_bulge,
+            &params.stem_profile.cross_section(params.stem_segments),
+            stem_color,
+        )
+    } else {
+        generate_stem_tapered(
+            &axis_points,
+            stem_radius,
+            params.stem_radius_top,
+            params.stem_profile,
+            params.stem_segments,
+            stem_color,
+        )
+    };
+
+    // Bract template, generated once and placed at every branch base
+    let bract_mesh = params
+        .bract
+        .as_ref()
+        .map(floraison_components::petal::generate);
+
+    // Reserve room for the stem plus one flower (and bract) per branch up front,
+    // taking `compound_flower` as the size estimate; pedicels are comparatively small
+    let per_branch = |mesh: &Mesh| (mesh.vertex_count(), mesh.indices.len());
+    let (flower_vertices, flower_indices) = per_branch(compound_flower);
+    let (bract_vertices, bract_indices) = bract_mesh.as_ref().map_or((0, 0), per_branch);
+    final_mesh.reserve(
+        stem_mesh.vertex_count() + branches.len() * (flower_vertices + bract_vertices),
+        stem_mesh.indices.len() + branches.len() * (flower_indices + bract_indices),
+    );
     final_mesh.merge(&stem_mesh);
 
+    // 3b. Spathe bract wrapping the base of a spadix
+    if params.pattern == PatternType::Spadix && params.spathe {
+        let spathe =
+            patterns::spadix::generate_spathe(params, &axis, patterns::spadix::SPATHE_COLOR);
+        final_mesh.merge(&spathe);
+    }
+
+    // Branches bearing other branches don't sag, so their children stay attached
+    let carries_children = if params.gravity_sag > 0.0 {
+        branches_with_children(&branches)
+    } else {
+        Vec::new()
+    };
+    let rigid_params = carries_children
+        .contains(&true)
+        .then(|| InflorescenceParams {
+            gravity_sag: 0.0,
+            ..params.clone()
+        });
+
     // 4. For each branch, add pedicel and flower
-    for branch in &branches {
-        // 4a. Generate pedicel mesh if branch has length (with optional curvature)
+    for (index, branch) in branches.iter().enumerate() {
+        let branch_params = match &rigid_params {
+            Some(rigid) if carries_children[index] => rigid,
+            _ => params,
+        };
+
+        // 4a. Bract subtending the branch
+        if let Some(bract_mesh) = &bract_mesh {
+            final_mesh.merge_transformed(bract_mesh, &bract_transform(branch));
+        }
+
+        // 4b. Generate pedicel mesh if branch has length (with optional curvature)
         if branch.length > 0.01 {
-            let pedicel = generate_pedicel(branch, params, stem_radius * 0.6, stem_color);
+            let pedicel_radius = stem_radius * params.pedicel_radius_ratio;
+            let pedicel = generate_pedicel(branch, branch_params, pedicel_radius, stem_color);
             final_mesh.merge(&pedicel);
         }
 
-        // 4b. Clone and transform flower mesh
-        let mut flower = flower_mesh.clone();
+        // 4c. Fallen flowers leave only their pedicel
+        if params
+            .drop_after_age
+            .is_some_and(|drop_age| branch.age > drop_age)
+        {
+            continue;
+        }
 
-        // Compute transformation matrix
-        // 1. Scale by flower_scale
-        // 2. Rotate to align with branch direction
-        // 3. Translate to branch position
-        let scale = Vec3::splat(branch.flower_scale);
-        let rotation = Quat::from_rotation_arc(Vec3::Y, branch.direction);
-        let translation = branch.position;
+        // 4d. Build the flower for this branch
+        let flower = flower_at(index, branch);
 
-        let transform = Mat4::from_scale_rotation_translation(scale, rotation, translation);
-        flower.transform(&transform);
+        let transform = flower_transform(branch_params, branch);
+        let first_vertex = final_mesh.vertex_count();
+        final_mesh.merge_transformed(&flower, &transform);
 
-        final_mesh.merge(&flower);
+        // Tint along the axis gradient
+        if let Some(tint) = flower_color_tint(params, branch) {
+            for color in &mut final_mesh.colors[first_vertex..] {
+                *color *= tint;
+            }
+        }
     }
 
     final_mesh
 }
 
+/// Flower color tint for a branch from the axis color gradient
+///
+/// The position along the axis is taken as `1.0 - age` (0.0 at the base,
+/// 1.0 at the top for indeterminate patterns). If only one of
+/// `flower_tint_base`/`flower_tint_top` is set, it is used everywhere.
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters (uses `flower_tint_base` and `flower_tint_top`)
+/// * `branch` - Branch point carrying the flower
+///
+/// # Returns
+/// Multiplicative RGB tint, or `None` when no gradient is configured
+pub fn flower_color_tint(params: &InflorescenceParams, branch: &BranchPoint) -> Option<Vec3> {
+    let (base, top) = match (params.flower_tint_base, params.flower_tint_top) {
+        (None, None) => return None,
+        (Some(base), None) => (base, base),
+        (None, Some(top)) => (top, top),
+        (Some(base), Some(top)) => (base, top),
+    };
+
+    let t = (1.0 - branch.age).clamp(0.0, 1.0);
+    Some(base.lerp(top, t))
+}
+
+/// Transform placing a flower at the tip of a branch
+///
+/// The flower (growing along local +Y) is scaled by `flower_scale`, rotated
+/// so +Y points where `params.flower_facing` says, and moved to the branch
+/// position.
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters (uses `flower_facing`)
+/// * `branch` - Branch point carrying the flower
+///
+/// # Returns
+/// Transformation matrix for the flower mesh
+pub fn flower_transform(params: &InflorescenceParams, branch: &BranchPoint) -> Mat4 {
+    let (position, direction) = sagged_pedicel_tip(params, branch);
+    let facing = params.flower_facing.flower_direction(direction);
+    Mat4::from_scale_rotation_translation(
+        Vec3::splat(branch.flower_scale),
+        rotation_between(Vec3::Y, facing),
+        position,
+    )
+}
+
+/// Transform placing a nested sub-inflorescence at the tip of a branch
+///
+/// The sub-inflorescence (growing along local +Y from the origin) is scaled
+/// uniformly and placed at the end of the branch's pedicel, following it
+/// when it sags under `gravity_sag`.
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters (uses `gravity_sag`)
+/// * `branch` - Branch point carrying the sub-inflorescence
+/// * `scale` - Size of the sub-inflorescence relative to its parameters
+///
+/// # Returns
+/// Transformation matrix for the sub-inflorescence mesh
+pub fn sub_inflorescence_transform(
+    params: &InflorescenceParams,
+    branch: &BranchPoint,
+    scale: f32,
+) -> Mat4 {
+    let (position, direction) = sagged_pedicel_tip(params, branch);
+    Mat4::from_scale_rotation_translation(
+        Vec3::splat(scale),
+        rotation_between(Vec3::Y, direction),
+        position,
+    )
+}
+
+/// Find the branches that other branches grow from
+///
+/// A branch carries children when another branch's base lies on its pedicel
+/// beyond its own base (at its tip, as in cymes, or along it, as in panicle
+/// laterals). Bases are looked up in a grid with cells about one average
+/// branch length wide.
+///
+/// # Returns
+/// One flag per branch, true when it carries children
+fn branches_with_children(branches: &[BranchPoint]) -> Vec<bool> {
+    let bases: Vec<Vec3> = branches
+        .iter()
+        .map(|b| b.position - b.direction * b.length)
+        .collect();
+
+    let total_length: f32 = branches.iter().map(|b| b.length).sum();
+    let cell = (total_length / branches.len().max(1) as f32).max(1e-3);
+    let key = |p: Vec3| (p / cell).floor().as_ivec3();
+    let mut grid: HashMap<IVec3, Vec<usize>> = HashMap::new();
+    for (j, &base) in bases.iter().enumerate() {
+        grid.entry(key(base)).or_default().push(j);
+    }
+
+    branches
+        .iter()
+        .enumerate()
+        .map(|(i, branch)| {
+            if branch.length <= 0.0 {
+                return false;
+            }
+            let eps = 1e-4 * (1.0 + branch.length);
+            let lo = key(bases[i].min(branch.position) - Vec3::splat(eps));
+            let hi = key(bases[i].max(branch.position) + Vec3::splat(eps));
+
+            (lo.x..=hi.x)
+                .flat_map(|x| {
+                    (lo.y..=hi.y).flat_map(move |y| (lo.z..=hi.z).map(move |z| (x, y, z)))
+                })
+                .filter_map(|(x, y, z)| grid.get(&IVec3::new(x, y, z)))
+                .flatten()
+                .any(|&j| {
+                    let s = (bases[j] - bases[i]).dot(branch.direction);
+                    j != i
+                        && s > eps
+                        && s <= branch.length + eps
+                        && (bases[i] + branch.direction * s).distance(bases[j]) < eps
+                })
+        })
+        .collect()
+}
+
+/// Smallest cosine of half the sag turn, bounding the bent pedicel's bulge
+const MIN_SAG_COS: f32 = 0.1;
+
+/// Rotation bending a pedicel under `params.gravity_sag`
+///
+/// # Returns
+/// Rotation axis and total turn angle (radians) of the pedicel end, or None
+/// when the pedicel doesn't sag (no sag, sessile, or already hanging down)
+fn sag_rotation(params: &InflorescenceParams, branch: &BranchPoint) -> Option<(Vec3, f32)> {
+    let weight = (params.gravity_sag * branch.flower_scale).clamp(0.0, 1.0);
+    let to_down = branch.direction.angle_between(Vec3::NEG_Y);
+    if weight <= 0.0 || branch.length <= 0.0 || to_down < 1e-4 {
+        return None;
+    }
+
+    // Turn toward -Y in the vertical plane of the pedicel
+    let axis = branch
+        .direction
+        .cross(Vec3::NEG_Y)
+        .try_normalize()
+        .unwrap_or_else(|| branch.direction.any_orthonormal_vector());
+    Some((axis, weight * to_down))
+}
+
+/// Flower position and direction at the end of a pedicel bent by gravity
+///
+/// The pedicel keeps its base and leaves it along `branch.direction`, then
+/// bends down as a circular-like arc: the flower end turns by the flower's
+/// weight fraction of the angle to straight down, and the base-to-tip chord
+/// (of length `branch.length`) by half of that. Without sag this is exactly
+/// `(branch.position, branch.direction)`.
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters (uses `gravity_sag`)
+/// * `branch` - Branch point carrying the flower
+///
+/// # Returns
+/// Sagged flower position and unit direction of the pedicel at the flower
+pub fn sagged_pedicel_tip(params: &InflorescenceParams, branch: &BranchPoint) -> (Vec3, Vec3) {
+    let Some((axis, turn)) = sag_rotation(params, branch) else {
+        return (branch.position, branch.direction);
+    };
+
+    let base = branch.position - branch.direction * branch.length;
+    let chord = Quat::from_axis_angle(axis, turn * 0.5) * branch.direction;
+    let tangent = Quat::from_axis_angle(axis, turn) * branch.direction;
+    (base + chord * branch.length, tangent.normalize())
+}
+
+/// Transform placing a bract at the base of a branch
+///
+/// The bract (a petal mesh growing along local +Y with its face toward +Z)
+/// points downward-outward from the branch base, away from the axis, with
+/// its face turned upward toward the flower. It is scaled with the flower.
+///
+/// # Arguments
+/// * `branch` - Branch point whose base the bract subtends
+///
+/// # Returns
+/// Transformation matrix for the bract mesh
+pub fn bract_transform(branch: &BranchPoint) -> Mat4 {
+    let base = branch.position - branch.direction * branch.length;
+
+    // Outward: horizontal part of the branch direction (any horizontal if vertical)
+    let outward = Vec3::new(branch.direction.x, 0.0, branch.direction.z)
+        .try_normalize()
+        .unwrap_or(Vec3::X);
+    let local_y = (outward - Vec3::Y * 0.5).normalize();
+
+    // Face up: world up with the along-bract component removed
+    let local_z = (Vec3::Y - local_y * local_y.y).normalize();
+    let local_x = local_y.cross(local_z);
+
+    let rotation = Quat::from_mat3(&Mat3::from_cols(local_x, local_y, local_z));
+    Mat4::from_scale_rotation_translation(Vec3::splat(branch.flower_scale), rotation, base)
+}
+
 /// Generate a cylindrical stem mesh along an axis curve
 ///
 /// # Arguments
@@ -229,22 +607,256 @@ pub fn assemble_inflorescence(
 /// # Returns
 /// Mesh of the stem geometry
 pub fn generate_stem_along_axis(axis_points: &[Vec3], radius: f32, color: Vec3) -> Mesh {
+    generate_stem_tapered(axis_points, radius, radius, StemProfile::Round, 8, color)
+}
+
+/// Generate a stem mesh along an axis curve, tapering from base to top
+///
+/// The cross-section is built from `profile` (see [`StemProfile::cross_section`])
+/// before sweeping; `Round` produces a plain cylinder.
+///
+/// # Arguments
+/// * `axis_points` - Points defining the axis curve
+/// * `radius_base` - Radius of the stem at the first axis point
+/// * `radius_top` - Radius of the stem at the last axis point
+/// * `profile` - Cross-section shape of the stem
+/// * `segments` - Number of radial segments around the stem
+/// * `color` - RGB color for the stem
+///
+/// # Returns
+/// Mesh of the stem geometry
+///
+/// # Panics
+/// Panics if `segments` < 3
+///
+/// # Example
+/// ```
+/// use floraison_inflorescence::{assembly::generate_stem_tapered, StemProfile};
+/// use floraison_core::Vec3;
+///
+/// let axis = vec![Vec3::ZERO, Vec3::new(0.0, 4.0, 0.0)];
+/// let stem = generate_stem_tapered(&axis, 0.1, 0.02, StemProfile::Round, 8, Vec3::ONE);
+///
+/// let tip = stem.positions.last().unwrap();
+/// assert!((Vec3::new(tip.x, 0.0, tip.z).length() - 0.02).abs() < 1e-5);
+/// ```
+pub fn generate_stem_tapered(
+    axis_points: &[Vec3],
+    radius_base: f32,
+    radius_top: f32,
+    profile: StemProfile,
+    segments: usize,
+    color: Vec3,
+) -> Mesh {
+    if profile != StemProfile::Round {
+        // Shaped cross-section, scaled linearly from base to top
+        let n = axis_points.len();
+        let radii: Vec<f32> = (0..n)
+            .map(|i| {
+                let t = i as f32 / (n - 1).max(1) as f32;
+                radius_base + (radius_top - radius_base) * t
+            })
+            .collect();
+        return sweep_cross_section(&profile.cross_section(segments), &radii, axis_points, color);
+    }
+
     // Create cylindrical profile
-    let profile = vec![Vec2::new(radius, 0.0), Vec2::new(radius, 1.0)];
+    let profile = vec![Vec2::new(radius_base, 0.0), Vec2::new(radius_base, 1.0)];
+
+    // Sweep profile along axis, scaling toward the top radius
+    let taper = if radius_base > 0.0 {
+        radius_top / radius_base
+    } else {
+        1.0
+    };
+    sweep_along_curve_tapered(&profile, axis_points, &[1.0, taper], segments, color)
+}
+
+/// Number of rings along the stem between nodes
+const NODE_STEM_BASE_RINGS: usize = 32;
+
+/// Number of rings placed across each node bulge
+const NODE_STEM_RINGS_PER_NODE: usize = 9;
+
+/// Generate a stem mesh with radial bulges at branch attachment nodes
+///
+/// Each branch base lying on the main axis becomes a node: the stem radius
+/// follows a smooth Gaussian bump (about four radii wide) centered on the
+/// node's arc length, so the stem thickens where pedicels attach.
+/// Branches not attached to the main axis (e.g. on sub-branches of cymes)
+/// are ignored.
+///
+/// # Arguments
+/// * `axis_points` - Points defining the axis curve
+/// * `branches` - Branch points attached along the axis
+/// * `radius` - Base (internode) radius of the stem
+/// * `bulge` - Fraction of `radius` added at each node
+/// * `color` - RGB color for the stem
+///
+/// # Returns
+/// Mesh of the stem geometry
+///
+/// # Example
+/// ```
+/// use floraison_inflorescence::{assembly::generate_stem_with_nodes, BranchPoint};
+/// use floraison_core::Vec3;
+///
+/// let axis = vec![Vec3::ZERO, Vec3::new(0.0, 4.0, 0.0)];
+/// let node = BranchPoint {
+///     position: Vec3::new(1.0, 2.0, 0.0),
+///     direction: Vec3::X,
+///     length: 1.0,
+///     flower_scale: 1.0,
+///     age: 0.5,
+/// };
+///
+/// let stem = generate_stem_with_nodes(&axis, &[node], 0.05, 0.5, Vec3::ONE);
+/// assert!(stem.vertex_count() > 0);
+/// ```
+pub fn generate_stem_with_nodes(
+    axis_points: &[Vec3],
+    branches: &[BranchPoint],
+    radius: f32,
+    bulge: f32,
+    color: Vec3,
+) -> Mesh {
+    generate_stem_with_nodes_tapered(
+        axis_points,
+        branches,
+        radius,
+        radius,
+        bulge,
+        &StemProfile::Round.cross_section(8),
+        color,
+    )
+}
 
-    // Sweep profile along axis
-    sweep_along_curve(&profile, axis_points, 8, color)
+/// Generate a tapered stem mesh with radial bulges at branch attachment nodes
+///
+/// Same as [`generate_stem_with_nodes`], but the internode radius tapers
+/// linearly along the arc length from `radius_base` to `radius_top`. Node
+/// widths are based on `radius_base`.
+///
+/// # Arguments
+/// * `axis_points` - Points defining the axis curve
+/// * `branches` - Branch points attached along the axis
+/// * `radius_base` - Internode radius of the stem at the base
+/// * `radius_top` - Internode radius of the stem at the top
+/// * `bulge` - Fraction of the local radius added at each node
+/// * `section` - Unit cross-section polygon (see [`StemProfile::cross_section`])
+/// * `color` - RGB color for the stem
+///
+/// # Returns
+/// Mesh of the stem geometry
+pub fn generate_stem_with_nodes_tapered(
+    axis_points: &[Vec3],
+    branches: &[BranchPoint],
+    radius_base: f32,
+    radius_top: f32,
+    bulge: f32,
+    section: &[Vec2],
+    color: Vec3,
+) -> Mesh {
+    let radius = radius_base;
+    let axis = AxisCurve::new(axis_points.to_vec());
+    let total_length = axis.length();
+    let node_width = radius * 4.0;
+
+    // Locate nodes: arc length of each branch base projected onto the axis
+    let axis_arc_lengths = compute_arc_lengths(axis_points);
+    let nodes: Vec<f32> = branches
+        .iter()
+        .filter_map(|branch| {
+            let base = branch.position - branch.direction * branch.length;
+            project_onto_polyline(axis_points, &axis_arc_lengths, base, node_width)
+        })
+        .collect();
+
+    // Ring placement: uniform along the stem, plus dense rings across each node
+    let mut ring_arc_lengths: Vec<f32> = (0..NODE_STEM_BASE_RINGS)
+        .map(|i| total_length * i as f32 / (NODE_STEM_BASE_RINGS - 1) as f32)
+        .collect();
+    for &node in &nodes {
+        let half = (NODE_STEM_RINGS_PER_NODE / 2) as f32;
+        for k in 0..NODE_STEM_RINGS_PER_NODE {
+            let offset = (k as f32 - half) / half * node_width;
+            ring_arc_lengths.push((node + offset).clamp(0.0, total_length));
+        }
+    }
+    ring_arc_lengths.sort_by(|a, b| a.total_cmp(b));
+    ring_arc_lengths.dedup_by(|a, b| (*a - *b).abs() < 1e-4);
+
+    // Radius profile: Gaussian bump at each node (max, so close nodes don't stack)
+    let radii: Vec<f32> = ring_arc_lengths
+        .iter()
+        .map(|&s| {
+            let peak = nodes
+                .iter()
+                .map(|&node| {
+                    let d = (s - node) / (node_width * 0.5);
+                    (-d * d).exp()
+                })
+                .fold(0.0_f32, f32::max);
+            let t = if total_length > 0.0 {
+                s / total_length
+            } else {
+                0.0
+            };
+            (radius_base + (radius_top - radius_base) * t) * (1.0 + bulge * peak)
+        })
+        .collect();
+
+    let positions: Vec<Vec3> = ring_arc_lengths
+        .iter()
+        .map(|&s| axis.position_at_t(s / total_length))
+        .collect();
+
+    sweep_cross_section(section, &radii, &positions, color)
+}
+
+/// Project a point onto a polyline, returning its arc length along the polyline
+///
+/// Returns `None` if the point is farther than `max_distance` from the polyline.
+fn project_onto_polyline(
+    points: &[Vec3],
+    arc_lengths: &[f32],
+    point: Vec3,
+    max_distance: f32,
+) -> Option<f32> {
+    let mut best: Option<(f32, f32)> = None; // (distance, arc length)
+
+    for i in 0..points.len() - 1 {
+        let a = points[i];
+        let segment = points[i + 1] - a;
+        let len_sq = segment.length_squared();
+        let t = if len_sq > 1e-12 {
+            ((point - a).dot(segment) / len_sq).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        let distance = (a + segment * t - point).length();
+        let arc_length = arc_lengths[i] + (arc_lengths[i + 1] - arc_lengths[i]) * t;
+
+        if best.is_none_or(|(d, _)| distance < d) {
+            best = Some((distance, arc_length));
+        }
+    }
+
+    best.filter(|&(d, _)| d <= max_distance).map(|(_, s)| s)
 }
 
 /// Generate a pedicel (branch stem) mesh with optional curvature
 ///
-/// Creates a thin cylindrical stem from the axis attachment point to the flower position,
-/// optionally curved based on branch curvature parameters.
+/// Creates a thin stem from the axis attachment point to the flower position,
+/// optionally curved based on branch curvature parameters and bent down by
+/// `gravity_sag` (see [`sagged_pedicel_tip`]). The stem tapers from `radius`
+/// at the base to half that at the flower.
 ///
 /// # Arguments
 /// * `branch` - Branch point containing position, direction, and length
 /// * `params` - Inflorescence parameters (for branch curve settings)
-/// * `radius` - Radius of the pedicel
+/// * `radius` - Radius of the pedicel at its base
 /// * `color` - RGB color for the pedicel
 ///
 /// # Returns
@@ -257,7 +869,7 @@ pub fn generate_pedicel(
 ) -> Mesh {
     // Base position: work backwards from flower position using direction and length
     let base = branch.position - branch.direction * branch.length;
-    let tip = branch.position;
+    let (tip, _) = sagged_pedicel_tip(params, branch);
 
     // Calculate effective curve amount based on mode
     // Use (1.0 - age) as proxy for position along axis (0=bottom, 1=top)
@@ -291,20 +903,34 @@ pub fn generate_pedicel(
     // For natural droop, also add downward component
     let curve_direction = (curve_dir + Vec3::new(0.0, -0.5, 0.0)).normalize();
 
+    // Gravity: move the control point out along the original direction so the
+    // pedicel leaves the axis unchanged and bends down to the sagged tip
+    let (curve_amount, curve_direction) = match sag_rotation(params, branch) {
+        None => (effective_curve_amount, curve_direction),
+        Some((_, turn)) => {
+            let half_chord = branch.length * 0.5;
+            let control = base + branch_dir * (half_chord / (turn * 0.5).cos().max(MIN_SAG_COS));
+            let offset = control - (base + tip) * 0.5
+                + curve_direction * effective_curve_amount * half_chord;
+            (offset.length() / half_chord, offset.normalize_or_zero())
+        }
+    };
+
     // Create curved path from base to tip
     let curve_points = generate_curved_points(
         base,
         tip,
-        effective_curve_amount,
+        curve_amount,
         curve_direction,
-        if effective_curve_amount > 0.01 { 6 } else { 2 },
+        if curve_amount > 0.01 { 6 } else { 2 },
     );
 
     // Create cylindrical profile
     let profile = vec![Vec2::new(radius, 0.0), Vec2::new(radius, 1.0)];
 
-    // Sweep profile along curve
-    sweep_along_curve(&profile, &curve_points, 6, color)
+    // Sweep profile along curve, tapering to half radius at the flower
+    let segments = (params.stem_segments * 3 / 4).max(3);
+    sweep_along_curve_tapered(&profile, &curve_points, &[1.0, 0.5], segments, color)
 }
 
 /// Assemble an inflorescence with age-based flower variation
@@ -314,7 +940,7 @@ pub fn generate_pedicel(
 ///
 /// # Arguments
 /// * `params` - Inflorescence parameters (pattern type, dimensions, angles, etc.)
-/// * `aging` - Flower aging configuration with bud/bloom/wilt meshes
+/// * `aging` - Flower aging configuration with bud/bloom/wilt (and optional fruit) meshes
 /// * `stem_color` - RGB color for stem and pedicel geometry
 ///
 /// # Returns
@@ -348,73 +974,16 @@ pub fn assemble_inflorescence_with_aging(
     aging: &FlowerAging,
     stem_color: Vec3,
 ) -> Mesh {
-    let mut final_mesh = Mesh::new();
-
-    // 1. Generate axis curve (straight or curved based on params)
-    let axis_points = generate_axis_points(params);
-    let axis = AxisCurve::new(axis_points.clone());
-
-    // 2. Generate branch points based on pattern type
-    let branches = match params.pattern {
-        PatternType::Raceme => patterns::raceme::generate_branch_points(params, &axis),
-        PatternType::Spike => patterns::spike::generate_branch_points(params, &axis),
-        PatternType::Umbel => patterns::umbel::generate_branch_points(params, &axis),
-        PatternType::Corymb => patterns::corymb::generate_branch_points(params, &axis),
-        PatternType::Dichasium => patterns::dichasium::generate_branch_points(params, &axis),
-        PatternType::Drepanium => patterns::drepanium::generate_branch_points(params, &axis),
-        PatternType::CompoundRaceme => {
-            // Compound patterns use bloom mesh (aging not fully supported for compound patterns)
-            return patterns::compound_raceme::generate_compound_raceme(
-                params,
-                &aging.bloom_mesh,
-                stem_color,
-            );
-        }
-        PatternType::CompoundUmbel => {
-            return patterns::compound_umbel::generate_compound_umbel(
-                params,
-                &aging.bloom_mesh,
-                stem_color,
-            );
-        }
-    };
-
-    // 3. Generate main stem mesh
-    let stem_radius = 0.05;
-    let stem_mesh = generate_stem_along_axis(&axis_points, stem_radius, stem_color);
-    final_mesh.merge(&stem_mesh);
-
-    // 4. For each branch, add pedicel and age-appropriate flower
-    for branch in &branches {
-        // 4a. Generate pedicel mesh if branch has length (with optional curvature)
-        if branch.length > 0.01 {
-            let pedicel = generate_pedicel(branch, params, stem_radius * 0.6, stem_color);
-            final_mesh.merge(&pedicel);
-        }
-
-        // 4b. Select age-appropriate flower mesh
-        let flower_mesh = aging.select_mesh(branch.age);
-
-        // 4c. Clone and transform flower mesh
-        let mut flower = flower_mesh.clone();
-
-        let scale = Vec3::splat(branch.flower_scale);
-        let rotation = Quat::from_rotation_arc(Vec3::Y, branch.direction);
-        let translation = branch.position;
-
-        let transform = Mat4::from_scale_rotation_translation(scale, rotation, translation);
-        flower.transform(&transform);
-
-        final_mesh.merge(&flower);
-    }
-
-    final_mesh
+    // Compound patterns use bloom mesh (aging not fully supported for compound patterns)
+    assemble_with_flower_source(params, &aging.bloom_mesh, stem_color, |_, branch| {
+        Cow::Borrowed(aging.select_mesh(branch.age))
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::InflorescenceParams;
+    use crate::{FlowerFacing, InflorescenceParams};
 
     fn create_simple_flower() -> Mesh {
         // Create a simple triangle mesh for testing
@@ -510,6 +1079,454 @@ mod tests {
         assert!(inflorescence.triangle_count() > 0);
     }
 
+    #[test]
+    fn test_assemble_panicle() {
+        let params = InflorescenceParams {
+            pattern: PatternType::Panicle,
+            branch_count: 5,
+            axis_length: 10.0,
+            recursion_depth: Some(2),
+            ..Default::default()
+        };
+
+        let flower = create_simple_flower();
+        let stem_color = Vec3::new(0.2, 0.6, 0.2);
+
+        let inflorescence = assemble_inflorescence(&params, &flower, stem_color);
+
+        // 5 primary branches, each with a terminal flower and 3 laterals
+        let flower_vertices = inflorescence
+            .colors
+            .iter()
+            .filter(|c| **c == Vec3::new(1.0, 0.5, 0.5))
+            .count();
+        assert_eq!(flower_vertices, 5 * 4 * 3);
+    }
+
+    #[test]
+    fn test_assemble_verticillaster() {
+        let params = InflorescenceParams {
+            pattern: PatternType::Verticillaster,
+            branch_count: 12,
+            flowers_per_node: Some(4),
+            ..Default::default()
+        };
+
+        let flower = create_simple_flower();
+        let inflorescence = assemble_inflorescence(&params, &flower, Vec3::new(0.2, 0.6, 0.2));
+
+        // 3 whorls of 4 flowers
+        let flower_vertices = inflorescence
+            .colors
+            .iter()
+            .filter(|c| **c == Vec3::new(1.0, 0.5, 0.5))
+            .count();
+        assert_eq!(flower_vertices, 12 * 3);
+    }
+
+    #[test]
+    fn test_assemble_catkin_hangs() {
+        let params = InflorescenceParams {
+            pattern: PatternType::Catkin,
+            ..Default::default()
+        };
+
+        let inflorescence =
+            assemble_inflorescence(&params, &create_simple_flower(), Vec3::new(0.2, 0.6, 0.2));
+
+        // Everything hangs from the attachment point at the origin
+        let (min, max) = inflorescence.bounding_box().unwrap();
+        assert!(max.y < 0.5);
+        assert!(min.y < -0.5 * params.axis_length * patterns::catkin::CATKIN_LENGTH_RATIO);
+    }
+
+    #[test]
+    fn test_assemble_thyrse() {
+        let params = InflorescenceParams {
+            pattern: PatternType::Thyrse,
+            branch_count: 4,
+            recursion_depth: Some(1),
+            ..Default::default()
+        };
+
+        let inflorescence =
+            assemble_inflorescence(&params, &create_simple_flower(), Vec3::new(0.2, 0.6, 0.2));
+
+        // 4 cymes of 3 flowers each
+        let flower_vertices = inflorescence
+            .colors
+            .iter()
+            .filter(|c| **c == Vec3::new(1.0, 0.5, 0.5))
+            .count();
+        assert_eq!(flower_vertices, 4 * 3 * 3);
+    }
+
+    #[test]
+    fn test_assemble_spadix_with_spathe() {
+        use patterns::spadix::SPATHE_COLOR;
+
+        let params = InflorescenceParams {
+            pattern: PatternType::Spadix,
+            branch_count: 8,
+            ..Default::default()
+        };
+        let flower = create_simple_flower();
+        let stem_color = Vec3::new(0.2, 0.6, 0.2);
+
+        let bare = assemble_inflorescence(&params, &flower, stem_color);
+        assert!(!bare.colors.contains(&SPATHE_COLOR));
+
+        let with_spathe = assemble_inflorescence(
+            &InflorescenceParams {
+                spathe: true,
+                ..params.clone()
+            },
+            &flower,
+            stem_color,
+        );
+        assert!(with_spathe.colors.contains(&SPATHE_COLOR));
+        assert!(with_spathe.vertex_count() > bare.vertex_count());
+
+        // Spadix club is much thicker than a regular stem
+        let max_stem_radius = bare
+            .positions
+            .iter()
+            .zip(&bare.colors)
+            .filter(|(_, c)| **c == stem_color)
+            .map(|(p, _)| Vec2::new(p.x, p.z).length())
+            .fold(0.0, f32::max);
+        assert!(max_stem_radius > 0.05 * 2.0);
+    }
+
+    #[test]
+    fn test_flower_color_gradient() {
+        let params = InflorescenceParams {
+            pattern: PatternType::Raceme,
+            branch_count: 5,
+            flower_tint_base: Some(Vec3::new(1.0, 1.0, 1.0)),
+            flower_tint_top: Some(Vec3::new(0.2, 0.2, 1.0)),
+            ..Default::default()
+        };
+        let flower = create_simple_flower(); // 3 vertices per flower
+        let stem_color = Vec3::new(0.2, 0.6, 0.2);
+
+        let mesh = assemble_inflorescence_with_flowers(&params, &flower, stem_color, |_, _| {
+            flower.clone()
+        });
+
+        // Everything but the stem and pedicels is flower, merged bottom to top
+        let flower_colors: Vec<Vec3> = mesh
+            .colors
+            .iter()
+            .copied()
+            .filter(|c| *c != stem_color)
+            .collect();
+        assert_eq!(flower_colors.len(), 5 * 3);
+        let bottom = flower_colors[0];
+        let top = flower_colors[flower_colors.len() - 1];
+        assert!(bottom.distance(top) > 0.1);
+        // Bottom keeps the flower color, top is shifted toward blue
+        assert!(bottom.distance(Vec3::new(1.0, 0.5, 0.5)) < 0.1);
+        assert!(top.x < bottom.x && top.z >= top.x);
+
+        // No gradient: colors untouched
+        let plain = assemble_inflorescence(
+            &InflorescenceParams {
+                flower_tint_base: None,
+                flower_tint_top: None,
+                ..params
+            },
+            &flower,
+            stem_color,
+        );
+        assert!(plain
+            .colors
+            .iter()
+            .all(|c| *c == stem_color || *c == Vec3::new(1.0, 0.5, 0.5)));
+    }
+
+    #[test]
+    fn test_assemble_with_fruit_at_oldest_position() {
+        let params = InflorescenceParams {
+            pattern: PatternType::Raceme,
+            branch_count: 6,
+            ..Default::default()
+        };
+        let fruit_color = Vec3::new(0.6, 0.3, 0.1);
+        let mut fruit = Mesh::new();
+        fruit.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, fruit_color);
+
+        let aging = FlowerAging::with_wilt(
+            create_simple_flower(),
+            create_simple_flower(),
+            create_simple_flower(),
+        )
+        .with_fruit(fruit, crate::aging::DEFAULT_FRUIT_THRESHOLD);
+        let mesh = assemble_inflorescence_with_aging(&params, &aging, Vec3::new(0.2, 0.6, 0.2));
+
+        // Only the bottom (oldest) flower has turned into fruit
+        let fruit_positions: Vec<Vec3> = mesh
+            .positions
+            .iter()
+            .zip(&mesh.colors)
+            .filter(|(_, c)| **c == fruit_color)
+            .map(|(p, _)| *p)
+            .collect();
+        assert_eq!(fruit_positions.len(), 1);
+
+        let branches = patterns::raceme::generate_branch_points(
+            &params,
+            &AxisCurve::new(generate_axis_points(&params)),
+        );
+        let oldest = branches
+            .iter()
+            .max_by(|a, b| a.age.total_cmp(&b.age))
+            .unwrap();
+        assert!(fruit_positions[0].distance(oldest.position) < 1e-5);
+        assert!(branches.iter().all(|b| b.position.y >= oldest.position.y));
+    }
+
+    #[test]
+    fn test_drop_after_age_removes_oldest_flowers() {
+        let params = InflorescenceParams {
+            pattern: PatternType::Raceme,
+            branch_count: 8,
+            ..Default::default()
+        };
+        let aging = FlowerAging::new(create_simple_flower(), create_simple_flower());
+        let stem_color = Vec3::new(0.2, 0.6, 0.2);
+        let flower_color = Vec3::new(1.0, 0.5, 0.5);
+
+        let kept = assemble_inflorescence_with_aging(&params, &aging, stem_color);
+        let dropped = assemble_inflorescence_with_aging(
+            &InflorescenceParams {
+                drop_after_age: Some(0.85),
+                ..params.clone()
+            },
+            &aging,
+            stem_color,
+        );
+        assert!(dropped.vertex_count() < kept.vertex_count());
+
+        // Exactly the flowers older than 0.85 are gone
+        let branches = patterns::raceme::generate_branch_points(
+            &params,
+            &AxisCurve::new(generate_axis_points(&params)),
+        );
+        let old = branches.iter().filter(|b| b.age > 0.85).count();
+        assert!(old > 0 && old < branches.len());
+        let flower_count =
+            |mesh: &Mesh| mesh.colors.iter().filter(|c| **c == flower_color).count() / 3;
+        assert_eq!(flower_count(&kept), branches.len());
+        assert_eq!(flower_count(&dropped), branches.len() - old);
+
+        // Pedicels remain
+        let stem_count = |mesh: &Mesh| mesh.colors.iter().filter(|c| **c == stem_color).count();
+        assert_eq!(stem_count(&dropped), stem_count(&kept));
+    }
+
+    #[test]
+    fn test_assemble_with_bracts() {
+        use floraison_components::petal::PetalParams;
+
+        let params = InflorescenceParams {
+            pattern: PatternType::Raceme,
+            branch_count: 5,
+            ..Default::default()
+        };
+        let flower = create_simple_flower();
+        let stem_color = Vec3::new(0.2, 0.6, 0.2);
+
+        let plain = assemble_inflorescence(&params, &flower, stem_color);
+
+        let bract = PetalParams::bract();
+        let bract_vertices = floraison_components::petal::generate(&bract).vertex_count();
+        let with_bracts = assemble_inflorescence(
+            &InflorescenceParams {
+                bract: Some(bract.clone()),
+                ..params
+            },
+            &flower,
+            stem_color,
+        );
+
+        assert_eq!(
+            with_bracts.vertex_count(),
+            plain.vertex_count() + 5 * bract_vertices
+        );
+        let bract_colored = with_bracts
+            .colors
+            .iter()
+            .filter(|c| **c == bract.color)
+            .count();
+        assert_eq!(bract_colored, 5 * bract_vertices);
+    }
+
+    #[test]
+    fn test_flower_facing_upward() {
+        let params = InflorescenceParams {
+            flower_facing: FlowerFacing::Upward,
+            ..Default::default()
+        };
+
+        for angle in [0.0_f32, 30.0, 60.0, 90.0, 120.0, 180.0] {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            let branch = BranchPoint {
+                position: Vec3::new(1.0, 3.0, 0.0),
+                direction: Vec3::new(sin, cos, 0.0),
+                length: 1.0,
+                flower_scale: 0.5,
+                age: 0.5,
+            };
+
+            let transform = flower_transform(&params, &branch);
+            let local_y = transform.transform_vector3(Vec3::Y).normalize();
+            assert!(
+                local_y.dot(Vec3::Y) > 0.999,
+                "Pedicel at {angle}°: {local_y}"
+            );
+            assert!(
+                transform
+                    .transform_point3(Vec3::ZERO)
+                    .distance(branch.position)
+                    < 1e-5
+            );
+        }
+    }
+
+    #[test]
+    fn test_flower_facing_nodding_and_default() {
+        let branch = BranchPoint {
+            position: Vec3::new(1.0, 3.0, 0.0),
+            direction: Vec3::new(0.0, 1.0, 1.0).normalize(),
+            length: 1.0,
+            flower_scale: 1.0,
+            age: 0.5,
+        };
+
+        // Default follows the pedicel
+        let along = flower_transform(&InflorescenceParams::default(), &branch);
+        assert!(along.transform_vector3(Vec3::Y).distance(branch.direction) < 1e-5);
+
+        // Nodding bells hang away from the axis
+        let nodding = InflorescenceParams {
+            flower_facing: FlowerFacing::Nodding(60.0),
+            ..Default::default()
+        };
+        let local_y = flower_transform(&nodding, &branch).transform_vector3(Vec3::Y);
+        assert!(local_y.y < -0.8 && local_y.z > 0.4);
+    }
+
+    #[test]
+    fn test_gravity_sag_lowers_heavy_flowers() {
+        let params = InflorescenceParams {
+            gravity_sag: 0.5,
+            ..Default::default()
+        };
+        let branch = |flower_scale| BranchPoint {
+            position: Vec3::new(1.0, 3.0, 0.0),
+            direction: Vec3::new(1.0, 1.0, 0.0).normalize(),
+            length: 2.0_f32.sqrt(),
+            flower_scale,
+            age: 0.5,
+        };
+        let (small, large) = (branch(0.2), branch(1.0));
+
+        // Equal pedicels, but the heavier flower ends lower and tilts further down
+        let (small_tip, small_dir) = sagged_pedicel_tip(&params, &small);
+        let (large_tip, large_dir) = sagged_pedicel_tip(&params, &large);
+        assert!(large_tip.y < small_tip.y && small_tip.y < small.position.y);
+        assert!(large_dir.y < small_dir.y && small_dir.y < small.direction.y);
+        assert!((large_tip.distance(Vec3::new(0.0, 2.0, 0.0)) - large.length).abs() < 1e-5);
+
+        let flower_tip = |b: &BranchPoint| flower_transform(&params, b).transform_point3(Vec3::Y).y;
+        assert!(flower_tip(&large) < flower_tip(&small));
+
+        // The pedicel mesh follows the flower down
+        let pedicel = generate_pedicel(&large, &params, 0.02, Vec3::ONE);
+        let lowest_far_end = pedicel
+            .positions
+            .iter()
+            .filter(|p| p.x > 1.0)
+            .map(|p| p.y)
+            .fold(f32::INFINITY, f32::min);
+        assert!(lowest_far_end < large.position.y - 0.2);
+    }
+
+    #[test]
+    fn test_gravity_sag_skips_branches_with_children() {
+        let axis = AxisCurve::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)]);
+
+        // Depth-2 dichasium: the root and its two children each bear a pair
+        let params = InflorescenceParams {
+            pattern: PatternType::Dichasium,
+            recursion_depth: Some(2),
+            ..Default::default()
+        };
+        let branches = patterns::dichasium::generate_branch_points(&params, &axis);
+        let carriers = branches_with_children(&branches);
+        assert_eq!(carriers.iter().filter(|&&c| c).count(), 3);
+
+        // Panicle primaries carry laterals along their length; laterals carry none
+        let params = InflorescenceParams {
+            pattern: PatternType::Panicle,
+            branch_count: 5,
+            recursion_depth: Some(2),
+            ..Default::default()
+        };
+        let branches = patterns::panicle::generate_branch_points(&params, &axis);
+        let carriers = branches_with_children(&branches);
+        assert_eq!(carriers.iter().filter(|&&c| c).count(), 5);
+
+        // Sibling racemes share no bases along their pedicels
+        let raceme =
+            patterns::raceme::generate_branch_points(&InflorescenceParams::default(), &axis);
+        assert!(!branches_with_children(&raceme).contains(&true));
+    }
+
+    #[test]
+    fn test_zero_gravity_sag_keeps_geometry() {
+        let params = InflorescenceParams::default();
+        let branch = BranchPoint {
+            position: Vec3::new(1.0, 3.0, 0.0),
+            direction: Vec3::new(1.0, 1.0, 0.0).normalize(),
+            length: 2.0_f32.sqrt(),
+            flower_scale: 1.0,
+            age: 0.5,
+        };
+
+        assert_eq!(
+            sagged_pedicel_tip(&params, &branch),
+            (branch.position, branch.direction)
+        );
+        let straight = generate_pedicel(&branch, &params, 0.02, Vec3::ONE);
+        let (min, max) = straight.bounding_box().unwrap();
+        assert!(max.y <= branch.position.y + 0.02 && min.y >= 2.0 - 0.02);
+    }
+
+    #[test]
+    fn test_bract_points_down_and_out() {
+        let branch = BranchPoint {
+            position: Vec3::new(1.0, 3.0, 0.0),
+            direction: Vec3::new(1.0, 1.0, 0.0).normalize(),
+            length: 2.0_f32.sqrt(),
+            flower_scale: 1.0,
+            age: 0.5,
+        };
+
+        let transform = bract_transform(&branch);
+        let base = transform.transform_point3(Vec3::ZERO);
+        let tip = transform.transform_point3(Vec3::Y);
+        let face = transform.transform_vector3(Vec3::Z);
+
+        // Attached at the branch base on the axis
+        assert!(base.distance(Vec3::new(0.0, 2.0, 0.0)) < 1e-5);
+        // Tip is outward (+X) and below the base
+        assert!(tip.x > base.x && tip.y < base.y);
+        // Face turned upward
+        assert!(face.y > 0.0);
+    }
+
     #[test]
     fn test_generate_stem() {
         let axis = vec![Vec3::ZERO, Vec3::new(0.0, 5.0, 0.0)];
@@ -527,6 +1544,278 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_stem_radius_widens_stem() {
+        let stem_color = Vec3::new(0.2, 0.6, 0.2);
+        let flower = create_simple_flower();
+
+        // Max radial distance of stem-colored vertices from the (straight, vertical) axis
+        let stem_spread = |params: &InflorescenceParams| {
+            let mesh = assemble_inflorescence(params, &flower, stem_color);
+            mesh.positions
+                .iter()
+                .zip(&mesh.colors)
+                .filter(|(p, c)| **c == stem_color && p.y <= 0.0)
+                .map(|(p, _)| Vec2::new(p.x, p.z).length())
+                .fold(0.0, f32::max)
+        };
+
+        let thin = InflorescenceParams {
+            branch_count: 4,
+            ..Default::default()
+        };
+        let thick = InflorescenceParams {
+            stem_radius: 0.2,
+            stem_radius_top: 0.2,
+            ..thin.clone()
+        };
+        assert!((stem_spread(&thin) - crate::DEFAULT_STEM_RADIUS).abs() < 1e-4);
+        assert!((stem_spread(&thick) - 0.2).abs() < 1e-4);
+        assert!(stem_spread(&thick) > stem_spread(&thin));
+
+        // Pedicel radius follows the ratio
+        let branch = patterns::raceme::generate_branch_points(
+            &thin,
+            &AxisCurve::new(generate_axis_points(&thin)),
+        )[0]
+        .clone();
+        let pedicel_spread = |ratio: f32| {
+            let base = branch.position - branch.direction * branch.length;
+            let pedicel = generate_pedicel(&branch, &thin, thin.stem_radius * ratio, stem_color);
+            pedicel
+                .positions
+                .iter()
+                .map(|p| p.distance(base))
+                .fold(f32::INFINITY, f32::min)
+        };
+        assert!((pedicel_spread(0.6) - 0.03).abs() < 1e-3);
+        assert!(pedicel_spread(1.2) > pedicel_spread(0.6));
+    }
+
+    #[test]
+    fn test_stem_segments_scale_vertex_count() {
+        let stem_color = Vec3::new(0.2, 0.6, 0.2);
+        let flower = create_simple_flower();
+        let stem_vertices = |segments: usize| {
+            let params = InflorescenceParams {
+                branch_count: 4,
+                stem_segments: segments,
+                ..Default::default()
+            };
+            assemble_inflorescence(&params, &flower, stem_color)
+                .colors
+                .iter()
+                .filter(|c| **c == stem_color)
+                .count()
+        };
+
+        // One vertex per segment per ring, on stem and pedicels (6 → 12 segments)
+        assert_eq!(stem_vertices(16), 2 * stem_vertices(8));
+    }
+
+    #[test]
+    fn test_stem_segments_minimum() {
+        let flower = create_simple_flower();
+        let assemble = |stem_segments| {
+            let params = InflorescenceParams {
+                stem_segments,
+                ..Default::default()
+            };
+            assemble_inflorescence(&params, &flower, Vec3::ONE)
+        };
+
+        // Too few segments are clamped to a triangular stem instead of panicking
+        let triangular = assemble(3);
+        for segments in [0, 1, 2] {
+            assert_eq!(assemble(segments).positions, triangular.positions);
+        }
+    }
+
+    #[test]
+    fn test_square_stem_has_four_flat_sides() {
+        let section = StemProfile::Square.cross_section(12);
+        let n = section.len();
+
+        // Corners: points where the outline turns
+        let corners: Vec<f32> = (0..n)
+            .filter(|&i| {
+                let prev = section[(i + n - 1) % n];
+                let next = section[(i + 1) % n];
+                (section[i] - prev).perp_dot(next - section[i]).abs() > 1e-5
+            })
+            .map(|i| section[i].y.atan2(section[i].x))
+            .collect();
+        assert_eq!(corners.len(), 4);
+        for (k, a) in corners.iter().enumerate() {
+            for b in &corners[k + 1..] {
+                assert!((a - b).abs() > 1.0);
+            }
+        }
+
+        // Swept stem is square: vertices of a ring lie on the four sides
+        let axis = vec![Vec3::ZERO, Vec3::new(0.0, 4.0, 0.0)];
+        let stem = generate_stem_tapered(&axis, 0.1, 0.1, StemProfile::Square, 12, Vec3::ONE);
+        assert_eq!(stem.vertex_count(), 2 * 12);
+        for p in &stem.positions {
+            let chebyshev = p.x.abs().max(p.z.abs());
+            assert!((chebyshev - 0.1).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_ridged_stem_profile() {
+        let section = StemProfile::Ridged {
+            count: 6,
+            depth: 0.3,
+        }
+        .cross_section(8);
+        assert_eq!(section.len(), 24);
+
+        let radii: Vec<f32> = section.iter().map(|p| p.length()).collect();
+        let max = radii.iter().cloned().fold(0.0, f32::max);
+        let min = radii.iter().cloned().fold(f32::INFINITY, f32::min);
+        assert!((max - 1.0).abs() < 1e-5);
+        assert!((min - 0.7).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_stem_tapers_from_base_to_top() {
+        let params = InflorescenceParams {
+            branch_count: 4,
+            stem_radius: 0.12,
+            stem_radius_top: 0.03,
+            ..Default::default()
+        };
+        let stem_color = Vec3::new(0.2, 0.6, 0.2);
+        let mesh = assemble_inflorescence(&params, &create_simple_flower(), stem_color);
+
+        // The stem is merged first
+        let axis_points = generate_axis_points(&params);
+        let stem = &mesh.positions
+            [..generate_stem_along_axis(&axis_points, 0.05, stem_color).vertex_count()];
+        let radial = |p: &Vec3| Vec2::new(p.x, p.z).length();
+        let ring_radius = |y: f32| {
+            stem.iter()
+                .filter(|p| (p.y - y).abs() < 1e-4)
+                .map(radial)
+                .fold(0.0, f32::max)
+        };
+
+        let lowest = ring_radius(0.0);
+        let highest = ring_radius(params.axis_length);
+        assert!((lowest - 0.12).abs() < 1e-4);
+        assert!((highest - 0.03).abs() < 1e-4);
+        assert!(lowest > highest);
+
+        // Nodes taper the same way between bulges
+        let noded = generate_stem_with_nodes_tapered(
+            &axis_points,
+            &[],
+            0.12,
+            0.03,
+            0.5,
+            &StemProfile::Round.cross_section(8),
+            stem_color,
+        );
+        let (bottom, top) = (
+            noded.positions.first().unwrap(),
+            noded.positions.last().unwrap(),
+        );
+        assert!(radial(bottom) > radial(top));
+    }
+
+    #[test]
+    fn test_generate_stem_with_nodes_bulges_at_node() {
+        let axis = vec![Vec3::ZERO, Vec3::new(0.0, 4.0, 0.0)];
+        let radius = 0.05;
+        let node = BranchPoint {
+            position: Vec3::new(1.0, 2.0, 0.0),
+            direction: Vec3::X,
+            length: 1.0,
+            flower_scale: 1.0,
+            age: 0.5,
+        };
+
+        let stem = generate_stem_with_nodes(&axis, &[node], radius, 0.5, Vec3::ONE);
+
+        // Radial distance of each vertex from the (vertical) axis
+        let radial = |p: &Vec3| (p.x * p.x + p.z * p.z).sqrt();
+
+        let at_node = stem
+            .positions
+            .iter()
+            .filter(|p| (p.y - 2.0).abs() < 1e-3)
+            .map(radial)
+            .fold(0.0_f32, f32::max);
+        let internode = stem
+            .positions
+            .iter()
+            .filter(|p| p.y < 1.0)
+            .map(radial)
+            .fold(0.0_f32, f32::max);
+
+        assert!(
+            (at_node - radius * 1.5).abs() < 1e-3,
+            "Node should be 50% thicker, got radius {}",
+            at_node
+        );
+        assert!(
+            (internode - radius).abs() < 1e-3,
+            "Internode should keep base radius, got {}",
+            internode
+        );
+    }
+
+    #[test]
+    fn test_generate_stem_with_nodes_ignores_detached_branches() {
+        let axis = vec![Vec3::ZERO, Vec3::new(0.0, 4.0, 0.0)];
+        let far_branch = BranchPoint {
+            position: Vec3::new(3.0, 2.0, 0.0),
+            direction: Vec3::X,
+            length: 1.0, // Base at x = 2.0, well away from the axis
+            flower_scale: 1.0,
+            age: 0.5,
+        };
+
+        let stem = generate_stem_with_nodes(&axis, &[far_branch], 0.05, 0.5, Vec3::ONE);
+        let max_radius = stem
+            .positions
+            .iter()
+            .map(|p| (p.x * p.x + p.z * p.z).sqrt())
+            .fold(0.0_f32, f32::max);
+
+        assert!((max_radius - 0.05).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_assemble_stem_node_bulge_default_unchanged() {
+        let flower = create_simple_flower();
+        let stem_color = Vec3::new(0.2, 0.6, 0.2);
+
+        let smooth = InflorescenceParams {
+            branch_count: 5,
+            ..Default::default()
+        };
+        let noded = InflorescenceParams {
+            stem_node_bulge: 0.4,
+            ..smooth.clone()
+        };
+
+        let smooth_mesh = assemble_inflorescence(&smooth, &flower, stem_color);
+        let noded_mesh = assemble_inflorescence(&noded, &flower, stem_color);
+
+        // Default matches the plain cylindrical stem
+        let axis_points = generate_axis_points(&smooth);
+        let plain_stem = generate_stem_along_axis(&axis_points, 0.05, stem_color);
+        assert_eq!(
+            smooth_mesh.positions[..plain_stem.vertex_count()],
+            plain_stem.positions[..]
+        );
+
+        // Nodes add rings to the stem
+        assert!(noded_mesh.vertex_count() > smooth_mesh.vertex_count());
+    }
+
     #[test]
     fn test_generate_pedicel() {
         let branch = crate::BranchPoint {
@@ -550,6 +1839,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pedicel_tapers() {
+        let branch = crate::BranchPoint {
+            position: Vec3::new(0.0, 3.0, 0.0),
+            direction: Vec3::Y,
+            length: 2.0,
+            flower_scale: 1.0,
+            age: 0.5,
+        };
+        let params = InflorescenceParams {
+            branch_curve_amount: 0.0,
+            ..Default::default()
+        };
+
+        let pedicel = generate_pedicel(&branch, &params, 0.1, Vec3::ONE);
+
+        // Rings of 6 vertices; first is the base, last is the tip
+        let ring_radius = |ring: &[Vec3]| {
+            ring.iter()
+                .map(|p| Vec3::new(p.x, 0.0, p.z).length())
+                .sum::<f32>()
+                / ring.len() as f32
+        };
+        let n = pedicel.positions.len();
+        let base = ring_radius(&pedicel.positions[0..6]);
+        let top = ring_radius(&pedicel.positions[n - 6..n]);
+
+        assert!((base - 0.1).abs() < 1e-4);
+        assert!((top - 0.05).abs() < 1e-4);
+        assert!(top < base, "Top ring should be narrower than base");
+    }
+
     #[test]
     fn test_assemble_empty_flower() {
         let params = InflorescenceParams {
@@ -589,6 +1910,54 @@ mod tests {
         assert!(inflorescence.vertex_count() >= original_vertex_count);
     }
 
+    #[test]
+    fn test_assemble_with_flowers_per_branch() {
+        let params = InflorescenceParams {
+            pattern: PatternType::Raceme,
+            branch_count: 5,
+            axis_length: 10.0,
+            ..Default::default()
+        };
+
+        // Tag each flower with its branch index in the red channel
+        let mut indices = Vec::new();
+        let inflorescence =
+            assemble_inflorescence_with_flowers(&params, &Mesh::new(), Vec3::ZERO, |index, _| {
+                indices.push(index);
+                let mut flower = create_simple_flower();
+                flower.colors.fill(Vec3::new(index as f32 + 1.0, 0.0, 0.0));
+                flower
+            });
+
+        assert_eq!(indices, vec![0, 1, 2, 3, 4]);
+        for index in 0..5 {
+            let tagged = inflorescence
+                .colors
+                .iter()
+                .filter(|c| c.x == index as f32 + 1.0)
+                .count();
+            assert_eq!(tagged, 3, "Flower {index} should appear exactly once");
+        }
+
+        // Compound patterns replicate the compound flower without calling back
+        let compound = InflorescenceParams {
+            pattern: PatternType::CompoundUmbel,
+            ..params
+        };
+        let mut calls = 0;
+        let mesh = assemble_inflorescence_with_flowers(
+            &compound,
+            &create_simple_flower(),
+            Vec3::ZERO,
+            |_, _| {
+                calls += 1;
+                Mesh::new()
+            },
+        );
+        assert_eq!(calls, 0);
+        assert!(mesh.vertex_count() > 0);
+    }
+
     #[test]
     fn test_assemble_with_aging_raceme() {
         use crate::aging::FlowerAging;
@@ -8,7 +8,9 @@
 //! - **Determinate**: Upper/central flowers bloom first (dichasium, drepanium)
 //! - **Compound**: Recursive combinations of the above patterns
 
-use floraison_core::Vec3;
+use floraison_components::petal::PetalParams;
+use floraison_core::{Vec2, Vec3};
+use std::f32::consts::TAU;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -33,6 +35,197 @@ pub enum CurveMode {
     GradientDown,
 }
 
+/// Direction a drepanium coils around its axis, seen from the axis tip
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SpiralHandedness {
+    /// Positive rotation around the axis tangent (default)
+    #[default]
+    CounterClockwise,
+
+    /// Negative rotation around the axis tangent
+    Clockwise,
+}
+
+/// Distribution of branch points along the main axis (Raceme and Spike)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SpacingProfile {
+    /// Evenly spaced branch points (default)
+    #[default]
+    Uniform,
+
+    /// Internodes shorten toward the top, crowding flowers near the tip
+    CrowdedTop,
+
+    /// Internodes shorten toward the base, crowding flowers near the bottom
+    CrowdedBase,
+}
+
+impl SpacingProfile {
+    /// Map a branch's rank along the axis to its position on the axis
+    ///
+    /// # Arguments
+    /// * `rank` - Branch index normalized to 0.0 (bottom) to 1.0 (top)
+    ///
+    /// # Returns
+    /// Axis parameter `t` in 0.0 to 1.0; the ends stay fixed
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_inflorescence::SpacingProfile;
+    ///
+    /// assert_eq!(SpacingProfile::Uniform.position(0.5), 0.5);
+    /// assert!(SpacingProfile::CrowdedTop.position(0.5) > 0.5);
+    /// assert!(SpacingProfile::CrowdedBase.position(0.5) < 0.5);
+    /// ```
+    pub fn position(self, rank: f32) -> f32 {
+        match self {
+            SpacingProfile::Uniform => rank,
+            SpacingProfile::CrowdedTop => 1.0 - (1.0 - rank) * (1.0 - rank),
+            SpacingProfile::CrowdedBase => rank * rank,
+        }
+    }
+}
+
+/// Which way each flower faces, relative to its pedicel
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FlowerFacing {
+    /// Flower continues the pedicel direction (default)
+    #[default]
+    AlongPedicel,
+
+    /// Flower faces straight up, as in tulips on curved stalks
+    Upward,
+
+    /// Flower hangs away from the axis, tipped below horizontal by the given
+    /// angle (degrees; 90° = straight down), as in fritillaria bells
+    Nodding(f32),
+
+    /// Flower faces horizontally away from the axis
+    Outward,
+}
+
+impl FlowerFacing {
+    /// Direction the flower's local +Y is aligned to
+    ///
+    /// "Away from the axis" is the horizontal part of the pedicel direction.
+    /// For a vertical pedicel, `Outward` keeps the pedicel direction and
+    /// `Nodding` tips toward +X.
+    ///
+    /// # Arguments
+    /// * `pedicel` - Unit direction of the pedicel
+    ///
+    /// # Returns
+    /// Unit direction the flower faces
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_inflorescence::FlowerFacing;
+    /// use glam::Vec3;
+    ///
+    /// let pedicel = Vec3::new(1.0, 1.0, 0.0).normalize();
+    /// assert_eq!(FlowerFacing::AlongPedicel.flower_direction(pedicel), pedicel);
+    /// assert_eq!(FlowerFacing::Upward.flower_direction(pedicel), Vec3::Y);
+    /// assert!((FlowerFacing::Nodding(90.0).flower_direction(pedicel) + Vec3::Y).length() < 1e-6);
+    /// assert!(FlowerFacing::Outward.flower_direction(pedicel).abs_diff_eq(Vec3::X, 1e-6));
+    /// ```
+    pub fn flower_direction(self, pedicel: Vec3) -> Vec3 {
+        let outward = Vec3::new(pedicel.x, 0.0, pedicel.z).try_normalize();
+        match self {
+            FlowerFacing::AlongPedicel => pedicel,
+            FlowerFacing::Upward => Vec3::Y,
+            FlowerFacing::Nodding(angle) => {
+                let (sin, cos) = angle.clamp(0.0, 90.0).to_radians().sin_cos();
+                outward.unwrap_or(Vec3::X) * cos - Vec3::Y * sin
+            }
+            FlowerFacing::Outward => outward.unwrap_or(pedicel),
+        }
+    }
+}
+
+/// Cross-section shape of the main stem
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StemProfile {
+    /// Circular cross-section (default)
+    #[default]
+    Round,
+
+    /// Square cross-section, as in mint stems
+    ///
+    /// The square's half-width equals the stem radius.
+    Square,
+
+    /// Circular cross-section with longitudinal ridges (fluted stem)
+    Ridged {
+        /// Number of ridges around the stem
+        count: usize,
+        /// Depth of the grooves between ridges, as a fraction of the radius (0.0-1.0)
+        depth: f32,
+    },
+}
+
+impl StemProfile {
+    /// Build the unit cross-section polygon of this profile
+    ///
+    /// Points are counter-clockwise around the origin, scaled so the stem
+    /// radius is 1.0. `Round` uses exactly `segments` points; `Square` uses
+    /// `segments` rounded down to a multiple of 4 (at least 4) with corners
+    /// included; `Ridged` uses at least 4 points per ridge.
+    ///
+    /// # Arguments
+    /// * `segments` - Requested number of points around the stem
+    ///
+    /// # Returns
+    /// Cross-section polygon, suitable for
+    /// [`sweep_cross_section`](floraison_core::geometry::sweep::sweep_cross_section)
+    ///
+    /// # Example
+    /// ```
+    /// use floraison_inflorescence::StemProfile;
+    ///
+    /// assert_eq!(StemProfile::Round.cross_section(8).len(), 8);
+    /// assert_eq!(StemProfile::Square.cross_section(10).len(), 8);
+    /// ```
+    pub fn cross_section(&self, segments: usize) -> Vec<Vec2> {
+        let circle = |n: usize, radius: &dyn Fn(f32) -> f32| -> Vec<Vec2> {
+            (0..n)
+                .map(|i| {
+                    let angle = i as f32 * TAU / n as f32;
+                    Vec2::new(angle.cos(), angle.sin()) * radius(angle)
+                })
+                .collect()
+        };
+
+        match *self {
+            StemProfile::Round => circle(segments, &|_| 1.0),
+            StemProfile::Square => {
+                let per_side = (segments / 4).max(1);
+                let corners = [
+                    Vec2::new(1.0, -1.0),
+                    Vec2::new(1.0, 1.0),
+                    Vec2::new(-1.0, 1.0),
+                    Vec2::new(-1.0, -1.0),
+                ];
+                (0..4)
+                    .flat_map(|side| {
+                        let (a, b) = (corners[side], corners[(side + 1) % 4]);
+                        (0..per_side).map(move |k| a.lerp(b, k as f32 / per_side as f32))
+                    })
+                    .collect()
+            }
+            StemProfile::Ridged { count, depth } => {
+                let depth = depth.clamp(0.0, 1.0);
+                circle(segments.max(count * 4), &|angle| {
+                    1.0 - depth * 0.5 * (1.0 - (count as f32 * angle).cos())
+                })
+            }
+        }
+    }
+}
+
 /// Inflorescence pattern type
 ///
 /// Defines the branching and arrangement pattern for multi-flower structures.
@@ -45,11 +238,16 @@ pub enum CurveMode {
 /// - [`Spike`](PatternType::Spike): Flowers sessile (no pedicels) on main axis
 /// - [`Umbel`](PatternType::Umbel): All pedicels from single point (umbrella-like)
 /// - [`Corymb`](PatternType::Corymb): Varied pedicel lengths creating flat top
+/// - [`Panicle`](PatternType::Panicle): Branched raceme whose branches branch again
+/// - [`Verticillaster`](PatternType::Verticillaster): Rings of flowers at nodes along axis
+/// - [`Catkin`](PatternType::Catkin): Dense, drooping spike of tiny sessile flowers
+/// - [`Spadix`](PatternType::Spadix): Thick fleshy spike, optionally with a spathe bract
 ///
 /// ## Determinate (Basipetal)
 /// Central/terminal flower blooms first:
 /// - [`Dichasium`](PatternType::Dichasium): Two branches per node (Y-shaped)
 /// - [`Drepanium`](PatternType::Drepanium): Single branch per node, spiraling
+/// - [`Thyrse`](PatternType::Thyrse): Indeterminate axis bearing small dichasial cymes
 ///
 /// ## Compound
 /// Recursive patterns where each branch ends in a sub-pattern:
@@ -78,6 +276,26 @@ pub enum PatternType {
     /// Example: Hawthorn, Yarrow
     Corymb,
 
+    /// Panicle: Raceme whose lateral branches themselves bear branches
+    ///
+    /// Example: Oats, Lilac
+    Panicle,
+
+    /// Verticillaster: Dense false whorls of flowers at nodes along the axis
+    ///
+    /// Example: Mint, Sage
+    Verticillaster,
+
+    /// Catkin: Dense, pendulous spike of small sessile flowers
+    ///
+    /// Example: Willow, Birch
+    Catkin,
+
+    /// Spadix: Thick, club-shaped spike densely covered in tiny flowers
+    ///
+    /// Example: Peace lily, Calla (see [`InflorescenceParams::spathe`])
+    Spadix,
+
     /// Dichasium: Two opposite branches at each node
     ///
     /// Example: Many carnations
@@ -88,6 +306,11 @@ pub enum PatternType {
     /// Example: Forget-me-not
     Drepanium,
 
+    /// Thyrse: Raceme-like main axis with a small dichasial cyme at each node
+    ///
+    /// Example: Grape, Horse chestnut
+    Thyrse,
+
     /// Compound Raceme: Raceme where each flower is replaced by sub-raceme
     ///
     /// Example: Lilac, Astilbe
@@ -99,6 +322,62 @@ pub enum PatternType {
     CompoundUmbel,
 }
 
+/// Default radius of the main stem
+pub const DEFAULT_STEM_RADIUS: f32 = 0.05;
+
+/// Default number of radial segments around the main stem
+pub const DEFAULT_STEM_SEGMENTS: usize = 8;
+
+/// Default pedicel radius relative to the stem radius
+pub const DEFAULT_PEDICEL_RADIUS_RATIO: f32 = 0.6;
+
+/// Default cap on the number of flowers in one inflorescence
+pub const DEFAULT_MAX_FLOWERS: usize = 10_000;
+
+/// Default corymb flatness (perfectly flat top)
+pub const DEFAULT_CORYMB_FLATNESS: f32 = 1.0;
+
+/// Default per-level dichasium divergence factor (same spread at every level)
+pub const DEFAULT_DIVERGENCE_DECAY: f32 = 1.0;
+
+/// Default seed for pedicel jitter
+pub const DEFAULT_JITTER_SEED: u64 = 42;
+
+#[cfg(feature = "serde")]
+fn default_stem_radius() -> f32 {
+    DEFAULT_STEM_RADIUS
+}
+
+#[cfg(feature = "serde")]
+fn default_pedicel_radius_ratio() -> f32 {
+    DEFAULT_PEDICEL_RADIUS_RATIO
+}
+
+#[cfg(feature = "serde")]
+fn default_max_flowers() -> usize {
+    DEFAULT_MAX_FLOWERS
+}
+
+#[cfg(feature = "serde")]
+fn default_jitter_seed() -> u64 {
+    DEFAULT_JITTER_SEED
+}
+
+#[cfg(feature = "serde")]
+fn default_corymb_flatness() -> f32 {
+    DEFAULT_CORYMB_FLATNESS
+}
+
+#[cfg(feature = "serde")]
+fn default_divergence_decay() -> f32 {
+    DEFAULT_DIVERGENCE_DECAY
+}
+
+#[cfg(feature = "serde")]
+fn default_stem_segments() -> usize {
+    DEFAULT_STEM_SEGMENTS
+}
+
 /// Parameters defining an inflorescence structure
 ///
 /// Controls the overall shape and arrangement of flowers along the main axis.
@@ -136,16 +415,59 @@ pub struct InflorescenceParams {
     /// - 180° for opposite arrangement
     pub rotation_angle: f32,
 
+    /// Spacing of branch points along the axis (Raceme and Spike only)
+    ///
+    /// Only the positions move; angles, lengths, sizes and ages still
+    /// interpolate by branch index.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub internode_spacing: SpacingProfile,
+
+    /// Maximum random deviation of each pedicel's direction (degrees, 0 = none)
+    ///
+    /// Applies to Raceme, Umbel and Corymb pedicels.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pedicel_angle_jitter: f32,
+
+    /// Maximum random change of each pedicel's length, as a fraction (0 = none)
+    ///
+    /// Applies to Raceme, Umbel and Corymb pedicels.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pedicel_length_jitter: f32,
+
+    /// Seed for pedicel jitter (same seed = same inflorescence)
+    #[cfg_attr(feature = "serde", serde(default = "default_jitter_seed"))]
+    pub jitter_seed: u64,
+
     /// Flower scale factor at top
     pub flower_size_top: f32,
 
     /// Flower scale factor at bottom
     pub flower_size_bottom: f32,
 
+    /// Multiplicative flower color tint at the base of the axis (None = untinted)
+    ///
+    /// Flower vertex colors are multiplied component-wise by a tint
+    /// interpolated between `flower_tint_base` and `flower_tint_top` (e.g.
+    /// lupines fading toward the tip), so white leaves a flower unchanged and
+    /// a tint can only darken or filter its colors. If only one end is set,
+    /// it tints every flower.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub flower_tint_base: Option<Vec3>,
+
+    /// Multiplicative flower color tint at the top of the axis (None = untinted)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub flower_tint_top: Option<Vec3>,
+
+    /// Flower orientation relative to its pedicel (default: along the pedicel)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub flower_facing: FlowerFacing,
+
     // --- Optional parameters for recursive patterns ---
-    /// Recursion depth for recursive patterns (Dichasium, Drepanium, Compound)
+    /// Recursion depth for recursive patterns (Dichasium, Drepanium, Panicle, Compound)
     ///
     /// - Dichasium/Drepanium: number of branching levels (default: 3-5)
+    /// - Panicle: branch orders including the primary branches (default: 2)
+    /// - Thyrse: depth of the cyme at each node (default: 1)
     /// - Compound patterns: depth of nesting (default: 2)
     pub recursion_depth: Option<usize>,
 
@@ -157,9 +479,57 @@ pub struct InflorescenceParams {
     /// Angle divergence for recursive branching (degrees)
     ///
     /// For Dichasium: angle between Y-shaped branches (default: 30°)
-    /// For Drepanium: spiral angle increment (uses rotation_angle if None)
+    /// For Drepanium: spiral angle increment (uses rotation_angle if None, 0°
+    /// or a multiple of 360°)
     pub angle_divergence: Option<f32>,
 
+    /// Factor applied to the dichasium divergence at each deeper level
+    /// (Dichasium and Thyrse cymes, default: 1.0)
+    ///
+    /// Level `d` branches spread by `angle_divergence × divergence_decay^d`.
+    /// Values below 1.0 narrow deeper forks, which keeps the sub-branches of
+    /// deep trees from folding back onto each other; above 1.0 widens them.
+    #[cfg_attr(feature = "serde", serde(default = "default_divergence_decay"))]
+    pub divergence_decay: f32,
+
+    /// Coil direction of a drepanium (Drepanium only)
+    ///
+    /// The spiral increment is reduced to a step in (0°, 180°], so the coil
+    /// always turns this way, however large `angle_divergence` is.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub spiral_handedness: SpiralHandedness,
+
+    /// Flowers per node for whorled patterns (Verticillaster, default: 6)
+    ///
+    /// The number of nodes is `branch_count / flowers_per_node`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub flowers_per_node: Option<usize>,
+
+    /// How flat the top of a corymb is (Corymb only, default: 1.0)
+    ///
+    /// - `0.0`: pedicel lengths interpolate from `branch_length_bottom` to
+    ///   `branch_length_top`, as for a raceme
+    /// - `1.0`: pedicels are lengthened so every flower reaches the top of the axis
+    #[cfg_attr(feature = "serde", serde(default = "default_corymb_flatness"))]
+    pub corymb_flatness: f32,
+
+    /// How domed an umbel head is (Umbel only, 0.0 to 1.0, default: 0.0)
+    ///
+    /// - `0.0`: every pedicel has the same length and angle
+    /// - `> 0.0`: central pedicels stand more upright and outer pedicels are
+    ///   shortened, so the head rises toward its centre
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub umbel_dome: f32,
+
+    /// Maximum number of flowers generated (default: [`DEFAULT_MAX_FLOWERS`])
+    ///
+    /// Deep or wide recursive and compound patterns grow exponentially. When
+    /// the projected flower count exceeds this cap, the recursion depth and
+    /// then the branch count are reduced until it fits
+    /// (see [`patterns::limit_flower_count`]).
+    #[cfg_attr(feature = "serde", serde(default = "default_max_flowers"))]
+    pub max_flowers: usize,
+
     /// Age distribution control for flower maturity
     ///
     /// Controls the age range of flowers in the inflorescence:
@@ -174,6 +544,13 @@ pub struct InflorescenceParams {
     /// Age thresholds: bud < 0.3, bloom 0.3-0.8, wilt > 0.8
     pub age_distribution: f32,
 
+    /// Age above which flowers have fallen off (None = keep all flowers)
+    ///
+    /// Flowers on branch points older than this are omitted; their pedicels
+    /// (and bracts) remain, as on late-season inflorescences.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub drop_after_age: Option<f32>,
+
     // --- Curvature parameters ---
     /// Main axis curvature amount (0.0 = straight, 1.0 = dramatic curve)
     pub axis_curve_amount: f32,
@@ -194,6 +571,76 @@ pub struct InflorescenceParams {
     /// - GradientUp: Top branches curve more
     /// - GradientDown: Bottom branches curve more
     pub branch_curve_mode: CurveMode,
+
+    /// Bending of pedicels under the weight of their flowers (0 = rigid)
+    ///
+    /// Each flower's weight is `gravity_sag * flower_scale` (clamped to 1.0):
+    /// the fraction of the way its pedicel turns from its direction toward
+    /// straight down. The flower tilts with the end of the pedicel.
+    /// Branches that other branches grow from (cyme and panicle branches)
+    /// stay rigid so their children remain attached, and compound patterns
+    /// place each sub-inflorescence at its sagged pedicel tip.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub gravity_sag: f32,
+
+    // --- Stem parameters ---
+    /// Radius of the main stem at the base of the axis
+    ///
+    /// Thin grasses use small values, succulent stalks large ones. Compound
+    /// patterns use 1.6× this radius for their main stem and this radius
+    /// for their primary rays.
+    ///
+    /// Formerly `stem_radius_base`; that name is still accepted when
+    /// deserializing.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "default_stem_radius", alias = "stem_radius_base")
+    )]
+    pub stem_radius: f32,
+
+    /// Radius of the main stem at the top of the axis
+    ///
+    /// The stem tapers linearly from `stem_radius` to this radius.
+    #[cfg_attr(feature = "serde", serde(default = "default_stem_radius"))]
+    pub stem_radius_top: f32,
+
+    /// Pedicel base radius relative to `stem_radius` (default: 0.6)
+    #[cfg_attr(feature = "serde", serde(default = "default_pedicel_radius_ratio"))]
+    pub pedicel_radius_ratio: f32,
+
+    /// Cross-section shape of the main stem (default: Round)
+    ///
+    /// Pedicels are always round.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stem_profile: StemProfile,
+
+    /// Radial segments around the main stem (minimum 3, default: 8; smaller values use 3)
+    ///
+    /// Pedicels use 3/4 as many segments (at least 3), the spadix club 3/2.
+    #[cfg_attr(feature = "serde", serde(default = "default_stem_segments"))]
+    pub stem_segments: usize,
+
+    /// Radial bulge of the main stem at branch attachment nodes
+    ///
+    /// Fraction of the stem radius added at each node (0.0 = smooth stem,
+    /// 0.5 = nodes 50% thicker than the internodes).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stem_node_bulge: f32,
+
+    /// Wrap a spathe (large curled bract) around the base of a spadix
+    ///
+    /// Only used by [`PatternType::Spadix`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub spathe: bool,
+
+    // --- Bract parameters ---
+    /// Bract (small modified leaf) subtending each branch (None = no bracts)
+    ///
+    /// The bract is generated with the petal geometry, attached at each
+    /// branch base and points downward-outward. See [`PetalParams::bract`]
+    /// for a small green default.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bract: Option<PetalParams>,
 }
 
 impl Default for InflorescenceParams {
@@ -207,18 +654,42 @@ impl Default for InflorescenceParams {
             branch_length_top: 0.5,
             branch_length_bottom: 1.5,
             rotation_angle: 137.5, // Golden angle
+            internode_spacing: SpacingProfile::Uniform,
+            pedicel_angle_jitter: 0.0, // Exact pedicels
+            pedicel_length_jitter: 0.0,
+            jitter_seed: DEFAULT_JITTER_SEED,
             flower_size_top: 0.8,
             flower_size_bottom: 1.0,
+            flower_tint_base: None, // Untinted flowers by default
+            flower_tint_top: None,
+            flower_facing: FlowerFacing::AlongPedicel,
             // Optional recursive parameters (None = use defaults in pattern)
             recursion_depth: None,
             branch_ratio: None,
             angle_divergence: None,
+            divergence_decay: DEFAULT_DIVERGENCE_DECAY,
+            spiral_handedness: SpiralHandedness::CounterClockwise,
+            flowers_per_node: None,
+            corymb_flatness: DEFAULT_CORYMB_FLATNESS,
+            umbel_dome: 0.0, // Uniform pedicels
+            max_flowers: DEFAULT_MAX_FLOWERS,
             age_distribution: 0.5, // 0.5 = natural gradient (default behavior)
+            drop_after_age: None,  // Keep all flowers
             // Curvature parameters
             axis_curve_amount: 0.0, // Straight by default
             axis_curve_direction: Vec3::new(0.0, -1.0, 0.0), // Downward droop
             branch_curve_amount: 0.0, // Straight by default
             branch_curve_mode: CurveMode::Uniform,
+            gravity_sag: 0.0, // Rigid pedicels
+            // Stem parameters
+            stem_radius: DEFAULT_STEM_RADIUS, // Untapered by default
+            stem_radius_top: DEFAULT_STEM_RADIUS,
+            pedicel_radius_ratio: DEFAULT_PEDICEL_RADIUS_RATIO,
+            stem_profile: StemProfile::Round,
+            stem_segments: DEFAULT_STEM_SEGMENTS,
+            stem_node_bulge: 0.0, // Smooth stem by default
+            spathe: false,
+            bract: None, // No bracts by default
         }
     }
 }
@@ -286,17 +757,55 @@ pub struct BranchPoint {
 /// assert!((apply_age_distribution(0.5, 1.0) - 0.55).abs() < 0.01);
 /// ```
 pub fn apply_age_distribution(base_age: f32, distribution: f32) -> f32 {
-    const BUD_AGE: f32 = 0.15; // Middle of bud range (0.0-0.3)
-    const BLOOM_AGE: f32 = 0.55; // Middle of bloom range (0.3-0.8)
+    apply_age_distribution_with_thresholds(
+        base_age,
+        distribution,
+        aging::DEFAULT_BUD_THRESHOLD,
+        aging::DEFAULT_WILT_THRESHOLD,
+    )
+}
+
+/// Apply age distribution control for custom stage thresholds
+///
+/// Same as [`apply_age_distribution`], but the "all buds" and "all blooms"
+/// ages are the middles of the bud and bloom ranges defined by the given
+/// thresholds (see [`FlowerAging`](aging::FlowerAging)) instead of the
+/// default 0.3/0.8 cutoffs.
+///
+/// # Arguments
+/// * `base_age` - The natural age value from pattern calculation (0.0-1.0)
+/// * `distribution` - Age distribution control (0.0-1.0)
+/// * `bud_threshold` - Age at which buds open (bud → bloom)
+/// * `wilt_threshold` - Age at which blooms wilt (bloom → wilt)
+///
+/// # Returns
+/// Modified age value suitable for FlowerAging::select_mesh()
+///
+/// # Example
+/// ```
+/// use floraison_inflorescence::apply_age_distribution_with_thresholds;
+///
+/// // All blooms, with a bloom range of 0.4-0.95
+/// let age = apply_age_distribution_with_thresholds(0.1, 1.0, 0.4, 0.95);
+/// assert!((age - 0.675).abs() < 1e-5);
+/// ```
+pub fn apply_age_distribution_with_thresholds(
+    base_age: f32,
+    distribution: f32,
+    bud_threshold: f32,
+    wilt_threshold: f32,
+) -> f32 {
+    let bud_age = bud_threshold * 0.5; // Middle of bud range
+    let bloom_age = (bud_threshold + wilt_threshold) * 0.5; // Middle of bloom range
 
     if distribution < 0.5 {
-        // 0.0 to 0.5: Interpolate from all buds (0.15) to natural gradient
+        // 0.0 to 0.5: Interpolate from all buds to natural gradient
         let t = distribution * 2.0; // Map 0.0-0.5 to 0.0-1.0
-        BUD_AGE * (1.0 - t) + base_age * t
+        bud_age * (1.0 - t) + base_age * t
     } else {
-        // 0.5 to 1.0: Interpolate from natural gradient to all blooms (0.55)
+        // 0.5 to 1.0: Interpolate from natural gradient to all blooms
         let t = (distribution - 0.5) * 2.0; // Map 0.5-1.0 to 0.0-1.0
-        base_age * (1.0 - t) + BLOOM_AGE * t
+        base_age * (1.0 - t) + bloom_age * t
     }
 }
 
@@ -344,6 +853,18 @@ mod tests {
         assert_eq!(params.branch_count, deserialized.branch_count);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_stem_radius_base_alias() {
+        let mut value = serde_json::to_value(InflorescenceParams::default()).unwrap();
+        let fields = value.as_object_mut().unwrap();
+        fields.remove("stem_radius");
+        fields.insert("stem_radius_base".into(), serde_json::json!(0.12));
+        let params: InflorescenceParams = serde_json::from_value(value).unwrap();
+
+        assert_eq!(params.stem_radius, 0.12);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_pattern_type_serde() {
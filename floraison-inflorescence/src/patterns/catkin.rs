@@ -0,0 +1,162 @@
+//! Catkin pattern generator
+//!
+//! Catkin (ament): Dense, pendulous spike of small sessile flowers.
+//! Blooming pattern: Indeterminate (flowers near the attachment bloom first).
+//!
+//! Examples: Willow, Birch, Hazel
+
+use floraison_core::math::curves::AxisCurve;
+use glam::{Quat, Vec3};
+
+use crate::assembly::generate_curved_points;
+use crate::{apply_age_distribution, BranchPoint, InflorescenceParams};
+
+/// Minimum axis curvature, so the catkin droops even with `axis_curve_amount = 0`
+pub const CATKIN_MIN_DROOP: f32 = 0.5;
+
+/// Catkin axis length relative to `axis_length` (catkins are short and dense)
+pub const CATKIN_LENGTH_RATIO: f32 = 0.5;
+
+/// Flower scale relative to `flower_size_top`/`flower_size_bottom`
+pub const CATKIN_FLOWER_SCALE: f32 = 0.4;
+
+/// Fraction of the axis left bare below the attachment (short peduncle)
+const CATKIN_PEDUNCLE: f32 = 0.1;
+
+/// Linear interpolation between two values
+#[inline]
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Generate the drooping main axis of a catkin
+///
+/// The axis leaves the attachment point at the origin slightly outward
+/// (+Z) and hangs below it, bending along a quadratic curve from
+/// [`generate_curved_points`]. `axis_curve_amount` strengthens the droop
+/// beyond [`CATKIN_MIN_DROOP`].
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters (uses `axis_length` and `axis_curve_amount`)
+///
+/// # Returns
+/// Axis points from the attachment (origin) to the hanging tip
+pub fn generate_axis_points(params: &InflorescenceParams) -> Vec<Vec3> {
+    let length = params.axis_length * CATKIN_LENGTH_RATIO;
+    let hang = Vec3::new(0.0, -0.9, 0.45).normalize();
+
+    // Bow the curve outward and up, perpendicular to the hanging chord
+    let bow = Vec3::new(0.0, 0.45, 0.9).normalize();
+
+    generate_curved_points(
+        Vec3::ZERO,
+        hang * length,
+        params.axis_curve_amount.max(CATKIN_MIN_DROOP),
+        bow,
+        8,
+    )
+}
+
+/// Generate branch points for a catkin pattern
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters defining axis, branch count, angles, etc.
+/// * `axis` - The drooping axis from [`generate_axis_points`]
+///
+/// # Returns
+/// Vector of branch points, each representing a flower attachment location
+///
+/// # Pattern Characteristics
+/// - Like a spike: **sessile** flowers spiraling by `rotation_angle`
+/// - Flowers evenly spaced over the axis below a short bare peduncle
+/// - Small flowers ([`CATKIN_FLOWER_SCALE`] × the interpolated flower size)
+/// - Age decreases from the attachment (oldest) to the tip (youngest) - indeterminate
+pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) -> Vec<BranchPoint> {
+    let mut branches = Vec::with_capacity(params.branch_count);
+
+    for i in 0..params.branch_count {
+        // Normalize position along the flowering part (0.0 at attachment, 1.0 at tip)
+        let s = if params.branch_count > 1 {
+            i as f32 / (params.branch_count - 1) as f32
+        } else {
+            0.5 // Single flower at middle
+        };
+        let t = CATKIN_PEDUNCLE + (1.0 - CATKIN_PEDUNCLE) * s;
+
+        // Sample axis to get position and Frenet frame
+        let sample = axis.sample_at_t(t);
+
+        let flower_scale =
+            lerp(params.flower_size_bottom, params.flower_size_top, s) * CATKIN_FLOWER_SCALE;
+
+        // Sessile flowers spiral around the axis, as on a spike
+        let rotation = params.rotation_angle * i as f32;
+        let angle = lerp(params.angle_bottom, params.angle_top, s);
+        let down_rotation = Quat::from_axis_angle(sample.binormal, -angle.to_radians());
+        let spiral_rotation = Quat::from_axis_angle(sample.tangent, rotation.to_radians());
+        let direction = (spiral_rotation * down_rotation * sample.normal).normalize();
+
+        // Age: indeterminate (attachment = oldest = 1.0, tip = youngest = 0.0)
+        let age = apply_age_distribution(1.0 - s, params.age_distribution);
+
+        branches.push(BranchPoint {
+            position: sample.position,
+            direction,
+            length: 0.0, // Sessile: no pedicel
+            flower_scale,
+            age,
+        });
+    }
+
+    branches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns::spike;
+
+    #[test]
+    fn test_catkin_hangs_below_attachment() {
+        let params = InflorescenceParams::default();
+        let axis_points = generate_axis_points(&params);
+        assert_eq!(axis_points[0], Vec3::ZERO);
+        assert!(axis_points.last().unwrap().y < -0.5 * params.axis_length * CATKIN_LENGTH_RATIO);
+
+        let branches = generate_branch_points(&params, &AxisCurve::new(axis_points));
+        assert_eq!(branches.len(), params.branch_count);
+
+        // Even the topmost flower hangs below the attachment point
+        let top = branches
+            .iter()
+            .map(|b| b.position.y)
+            .fold(f32::NEG_INFINITY, f32::max);
+        assert!(top < 0.0);
+    }
+
+    #[test]
+    fn test_catkin_flowers_tightly_spaced() {
+        let params = InflorescenceParams::default();
+
+        let catkin =
+            generate_branch_points(&params, &AxisCurve::new(generate_axis_points(&params)));
+        let spike = spike::generate_branch_points(
+            &params,
+            &AxisCurve::new(vec![Vec3::ZERO, Vec3::new(0.0, params.axis_length, 0.0)]),
+        );
+
+        let max_gap = |branches: &[BranchPoint]| {
+            branches
+                .windows(2)
+                .map(|pair| pair[0].position.distance(pair[1].position))
+                .fold(0.0, f32::max)
+        };
+        assert!(max_gap(&catkin) < 0.6 * max_gap(&spike));
+
+        // Flowers are small and sessile
+        for branch in &catkin {
+            assert_eq!(branch.length, 0.0);
+            assert!(branch.flower_scale <= CATKIN_FLOWER_SCALE);
+        }
+    }
+}
@@ -6,10 +6,13 @@
 //! Examples: Lilac, Astilbe
 
 use floraison_core::math::curves::AxisCurve;
-use floraison_core::{geometry::mesh::Mesh, Mat4, Quat, Vec3};
+use floraison_core::{geometry::mesh::Mesh, Vec3};
 
 use crate::{assembly, patterns, InflorescenceParams, PatternType};
 
+/// Nesting depth used when `params.recursion_depth` is None
+pub const DEFAULT_RECURSION_DEPTH: usize = 1;
+
 /// Generate compound raceme inflorescence mesh
 ///
 /// This function recursively generates a raceme where each flower position
@@ -32,7 +35,15 @@ pub fn generate_compound_raceme(
     flower_mesh: &Mesh,
     stem_color: Vec3,
 ) -> Mesh {
-    let compound_depth = params.recursion_depth.unwrap_or(1);
+    // Stay within the flower budget, counting the full nested structure
+    let compound = InflorescenceParams {
+        pattern: PatternType::CompoundRaceme,
+        ..params.clone()
+    };
+    let limited = patterns::limit_flower_count(&compound);
+    let params = limited.as_ref();
+
+    let compound_depth = params.recursion_depth.unwrap_or(DEFAULT_RECURSION_DEPTH);
 
     // Base case: simple raceme (change pattern to avoid infinite recursion)
     if compound_depth <= 1 {
@@ -53,47 +64,64 @@ pub fn generate_compound_raceme(
     let primary_branches = patterns::raceme::generate_branch_points(&primary_params, &axis);
 
     // Add main stem
-    let main_stem = assembly::generate_stem_along_axis(&axis_points, 0.08, stem_color);
+    let main_stem = assembly::generate_stem_tapered(
+        &axis_points,
+        params.stem_radius * 1.6,
+        params.stem_radius_top * 1.6,
+        params.stem_profile,
+        params.stem_segments.max(3),
+        stem_color,
+    );
     final_mesh.merge(&main_stem);
 
     // For each primary branch, create sub-raceme
     for branch in &primary_branches {
         // Generate pedicel connecting main axis to sub-inflorescence
         if branch.length > 0.01 {
-            let pedicel = assembly::generate_pedicel(branch, params, 0.05, stem_color);
+            let pedicel =
+                assembly::generate_pedicel(branch, params, params.stem_radius, stem_color);
             final_mesh.merge(&pedicel);
         }
 
         // Scale down parameters for sub-inflorescence
-        let sub_params = InflorescenceParams {
-            axis_length: params.axis_length * 0.4,
-            branch_count: (params.branch_count / 2).max(3),
-            branch_length_top: params.branch_length_top * 0.6,
-            branch_length_bottom: params.branch_length_bottom * 0.6,
-            flower_size_top: params.flower_size_top * 0.7,
-            flower_size_bottom: params.flower_size_bottom * 0.7,
-            recursion_depth: Some(compound_depth - 1),
-            ..params.clone()
-        };
+        let sub_params = sub_inflorescence_params(params, compound_depth);
 
         // Recursive call
-        let mut sub_inflorescence = generate_compound_raceme(&sub_params, flower_mesh, stem_color);
+        let sub_inflorescence = generate_compound_raceme(&sub_params, flower_mesh, stem_color);
 
-        // Transform to branch position/orientation
+        // Transform to branch position/orientation (at the end of the sagged pedicel)
         let scale_factor = 0.5;
-        let transform = Mat4::from_scale_rotation_translation(
-            Vec3::splat(scale_factor),
-            Quat::from_rotation_arc(Vec3::Y, branch.direction),
-            branch.position,
-        );
-        sub_inflorescence.transform(&transform);
-
-        final_mesh.merge(&sub_inflorescence);
+        let transform = assembly::sub_inflorescence_transform(params, branch, scale_factor);
+        final_mesh.merge_transformed(&sub_inflorescence, &transform);
     }
 
     final_mesh
 }
 
+/// Scale down parameters for a sub-raceme one nesting level below `params`
+///
+/// # Arguments
+/// * `params` - Parameters of the current level
+/// * `compound_depth` - Nesting depth of the current level (at least 2)
+///
+/// # Returns
+/// Parameters for each nested sub-inflorescence
+pub(crate) fn sub_inflorescence_params(
+    params: &InflorescenceParams,
+    compound_depth: usize,
+) -> InflorescenceParams {
+    InflorescenceParams {
+        axis_length: params.axis_length * 0.4,
+        branch_count: (params.branch_count / 2).max(3),
+        branch_length_top: params.branch_length_top * 0.6,
+        branch_length_bottom: params.branch_length_bottom * 0.6,
+        flower_size_top: params.flower_size_top * 0.7,
+        flower_size_bottom: params.flower_size_bottom * 0.7,
+        recursion_depth: Some(compound_depth - 1),
+        ..params.clone()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -209,4 +237,74 @@ mod tests {
         // Default depth should be 2
         assert!(mesh.vertex_count() > 0);
     }
+
+    #[test]
+    fn test_compound_raceme_flower_budget() {
+        let flower = create_test_flower();
+        let flower_count = |mesh: &Mesh| {
+            let color = flower.colors[0];
+            mesh.colors.iter().filter(|&&c| c == color).count() / flower.vertex_count()
+        };
+
+        // Deep and wide: tens of thousands of flowers without the cap
+        let params = InflorescenceParams {
+            pattern: PatternType::CompoundRaceme,
+            recursion_depth: Some(8),
+            branch_count: 12,
+            max_flowers: 500,
+            ..Default::default()
+        };
+        assert!(patterns::count_branch_points(&params) > 10_000);
+        let mesh = generate_compound_raceme(&params, &flower, Vec3::ONE);
+        let count = flower_count(&mesh);
+        assert!(count > 0 && count <= 500, "got {} flowers", count);
+
+        // Reasonable configs are unaffected
+        let params = InflorescenceParams {
+            recursion_depth: Some(2),
+            branch_count: 4,
+            ..Default::default()
+        };
+        let uncapped = InflorescenceParams {
+            max_flowers: usize::MAX,
+            ..params.clone()
+        };
+        let mesh = generate_compound_raceme(&params, &flower, Vec3::ONE);
+        assert_eq!(
+            mesh.positions,
+            generate_compound_raceme(&uncapped, &flower, Vec3::ONE).positions
+        );
+    }
+
+    #[test]
+    fn test_compound_raceme_attached_under_gravity_sag() {
+        let params = InflorescenceParams {
+            pattern: PatternType::CompoundRaceme,
+            recursion_depth: Some(2),
+            branch_count: 4,
+            gravity_sag: 0.8,
+            ..Default::default()
+        };
+
+        let axis = AxisCurve::new(assembly::generate_axis_points(&params));
+        for branch in patterns::raceme::generate_branch_points(&params, &axis) {
+            // The sub-inflorescence base moves with the sagging pedicel tip...
+            let base = assembly::sub_inflorescence_transform(&params, &branch, 0.5)
+                .transform_point3(Vec3::ZERO);
+            assert!(base.distance(branch.position) > 0.05);
+
+            // ...and the pedicel ends right there
+            let pedicel =
+                assembly::generate_pedicel(&branch, &params, params.stem_radius, Vec3::ONE);
+            let gap = pedicel
+                .positions
+                .iter()
+                .map(|p| p.distance(base))
+                .fold(f32::INFINITY, f32::min);
+            assert!(
+                gap <= params.stem_radius,
+                "Sub-inflorescence {gap} from its pedicel"
+            );
+        }
+    }
 }
@@ -8,6 +8,7 @@
 use floraison_core::math::curves::AxisCurve;
 use glam::Quat;
 
+use crate::patterns::jitter_pedicel;
 use crate::{apply_age_distribution, BranchPoint, InflorescenceParams};
 
 /// Linear interpolation between two values
@@ -27,14 +28,18 @@ fn lerp(a: f32, b: f32, t: f32) -> f32 {
 ///
 /// # Pattern Characteristics
 /// - Flowers evenly spaced along axis (like raceme)
-/// - Pedicel lengths adjusted so all flowers reach same height (flat top)
+/// - Pedicel lengths adjusted so all flowers reach same height (flat top),
+///   blended with raceme-like lengths by `params.corymb_flatness`
+/// - Nearly horizontal branches can't reach the top and keep their
+///   raceme-like length; downward branches get no flat-top length (0)
 /// - Rotation follows Fibonacci spiral (golden angle)
 /// - Age increases from bottom (oldest) to top (youngest) - indeterminate
 pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) -> Vec<BranchPoint> {
     let mut branches = Vec::with_capacity(params.branch_count);
 
     // Target height: all flowers should reach the top of the axis
-    let target_height = axis.sample_at_t(1.0).position.y;
+    let target_height = axis.position_at_t(1.0).y;
+    let flatness = params.corymb_flatness.clamp(0.0, 1.0);
 
     for i in 0..params.branch_count {
         // Normalize position along axis (0.0 at bottom, 1.0 at top)
@@ -59,17 +64,38 @@ pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) ->
         let spiral_rotation = Quat::from_axis_angle(sample.tangent, rotation.to_radians());
         let direction = (spiral_rotation * down_rotation * sample.normal).normalize();
 
+        // Raceme-like length, used as is at flatness 0
+        let free_length = lerp(params.branch_length_bottom, params.branch_length_top, t);
+
         // Calculate pedicel length to reach target height
         // position.y + direction.y * length = target_height
         // length = (target_height - position.y) / direction.y
         //
         // Clamp direction.y to avoid division by zero when nearly horizontal
-        let length = if direction.y.abs() > 0.01 {
-            ((target_height - sample.position.y) / direction.y).max(0.0)
+        let rise = target_height - sample.position.y;
+        let flat_length = if direction.y.abs() > 0.01 {
+            (rise / direction.y).max(0.0)
         } else {
             // If direction is nearly horizontal, use default length
-            lerp(params.branch_length_bottom, params.branch_length_top, t)
+            free_length
+        };
+        let length = if flatness >= 1.0 {
+            flat_length
+        } else {
+            lerp(free_length, flat_length, flatness)
         };
+        let reaches_top = direction.y > 0.01 && rise >= 0.0;
+
+        let flat_y = sample.position.y + direction.y * length;
+        debug_assert!(
+            flatness < 1.0 || !reaches_top || (flat_y - target_height).abs() < 1e-3,
+            "Flat corymb flower at y={}, expected {}",
+            flat_y,
+            target_height
+        );
+
+        // Natural variation (roughens the flat top)
+        let (direction, length) = jitter_pedicel(params, i, direction, length);
 
         // Branch endpoint
         let position = sample.position + direction * length;
@@ -133,6 +159,89 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_corymb_flatness() {
+        let axis = AxisCurve::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)]);
+        let heights = |flatness: f32| {
+            let params = InflorescenceParams {
+                branch_count: 6,
+                corymb_flatness: flatness,
+                ..Default::default()
+            };
+            let branches = generate_branch_points(&params, &axis);
+            let ys: Vec<f32> = branches.iter().map(|b| b.position.y).collect();
+            let spread = ys.iter().fold(f32::MIN, |a, &b| a.max(b))
+                - ys.iter().fold(f32::MAX, |a, &b| a.min(b));
+            (ys, spread)
+        };
+
+        let (flat, flat_spread) = heights(1.0);
+        assert!(flat_spread < 1e-3, "spread {}", flat_spread);
+        assert!(flat.iter().all(|y| (y - 10.0).abs() < 1e-3));
+
+        let (_, free_spread) = heights(0.0);
+        assert!(free_spread > 5.0, "spread {}", free_spread);
+
+        // Partial flatness lies in between
+        let (_, half_spread) = heights(0.5);
+        assert!(half_spread > flat_spread && half_spread < free_spread);
+    }
+
+    #[test]
+    fn test_corymb_default_matches_flat_top_formula() {
+        // Pre-flatness pedicel lengths: reach the top, never negative,
+        // raceme-like only for nearly horizontal branches
+        let reference = |params: &InflorescenceParams, axis: &AxisCurve| -> Vec<Vec3> {
+            let target_height = axis.position_at_t(1.0).y;
+            (0..params.branch_count)
+                .map(|i| {
+                    let t = i as f32 / (params.branch_count - 1) as f32;
+                    let sample = axis.sample_at_t(t);
+                    let angle = lerp(params.angle_bottom, params.angle_top, t);
+                    let rotation = params.rotation_angle * i as f32;
+                    let down_rotation = Quat::from_axis_angle(sample.binormal, -angle.to_radians());
+                    let spiral_rotation =
+                        Quat::from_axis_angle(sample.tangent, rotation.to_radians());
+                    let direction = (spiral_rotation * down_rotation * sample.normal).normalize();
+                    let length = if direction.y.abs() > 0.01 {
+                        ((target_height - sample.position.y) / direction.y).max(0.0)
+                    } else {
+                        lerp(params.branch_length_bottom, params.branch_length_top, t)
+                    };
+                    sample.position + direction * length
+                })
+                .collect()
+        };
+
+        let straight = AxisCurve::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)]);
+        let bent = AxisCurve::new(vec![
+            Vec3::ZERO,
+            Vec3::new(1.0, 5.0, 0.0),
+            Vec3::new(3.0, 9.0, 1.0),
+            Vec3::new(4.0, 8.0, 2.0),
+        ]);
+        for (params, axis) in [
+            (InflorescenceParams::default(), &straight),
+            (InflorescenceParams::default(), &bent),
+            (
+                // Upward, horizontal and downward branches
+                InflorescenceParams {
+                    branch_count: 9,
+                    angle_bottom: -40.0,
+                    angle_top: 90.0,
+                    ..Default::default()
+                },
+                &straight,
+            ),
+        ] {
+            let positions: Vec<Vec3> = generate_branch_points(&params, axis)
+                .iter()
+                .map(|b| b.position)
+                .collect();
+            assert_eq!(positions, reference(&params, axis));
+        }
+    }
+
     #[test]
     fn test_corymb_length_variation() {
         let params = InflorescenceParams {
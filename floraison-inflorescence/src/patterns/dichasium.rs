@@ -10,6 +10,9 @@ use glam::{Quat, Vec3};
 
 use crate::{apply_age_distribution, BranchPoint, InflorescenceParams};
 
+/// Recursion depth used when `params.recursion_depth` is None (15 nodes: 2^4 - 1)
+pub const DEFAULT_RECURSION_DEPTH: usize = 3;
+
 /// Helper structure for recursive branch building
 #[derive(Debug, Clone)]
 struct BranchNode {
@@ -34,10 +37,11 @@ struct BranchNode {
 /// - **Determinate**: Top/center flowers oldest
 /// - Depth controlled by `params.recursion_depth` (default: 3)
 /// - Branch ratio: child length = parent × ratio (default: 0.7)
-/// - Angle divergence: angle between Y-branches (default: 30°)
+/// - Angle divergence: angle between Y-branches (default: 30°), scaled by
+///   `params.divergence_decay` at every level
 pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) -> Vec<BranchPoint> {
     // Extract parameters with defaults
-    let max_depth = params.recursion_depth.unwrap_or(3); // Default depth of 3 (15 nodes: 2^4 - 1)
+    let max_depth = params.recursion_depth.unwrap_or(DEFAULT_RECURSION_DEPTH);
     let branch_ratio = params.branch_ratio.unwrap_or(0.7);
     let angle_div = params.angle_divergence.unwrap_or(30.0);
 
@@ -54,7 +58,14 @@ pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) ->
     let branching_axis = sample.binormal;
 
     // Build binary tree recursively
-    let nodes = build_tree_recursive(&root, max_depth, branch_ratio, angle_div, branching_axis);
+    let nodes = build_tree_recursive(
+        &root,
+        max_depth,
+        branch_ratio,
+        angle_div,
+        params.divergence_decay,
+        branching_axis,
+    );
 
     // Convert nodes to branch points
     nodes_to_branch_points(nodes, max_depth, params)
@@ -66,6 +77,7 @@ fn build_tree_recursive(
     max_depth: usize,
     branch_ratio: f32,
     angle_divergence: f32,
+    divergence_decay: f32,
     branching_axis: Vec3,
 ) -> Vec<BranchNode> {
     // Base case: reached maximum depth (leaf node)
@@ -79,8 +91,11 @@ fn build_tree_recursive(
     // Use fixed branching axis from Frenet frame (consistent branching plane)
     let perpendicular = branching_axis;
 
-    // Left branch: rotate +angle_divergence around perpendicular
-    let left_rotation = Quat::from_axis_angle(perpendicular, angle_divergence.to_radians());
+    // Spread of this fork, decayed by depth
+    let spread = angle_divergence * divergence_decay.powi(node.depth as i32);
+
+    // Left branch: rotate +spread around perpendicular
+    let left_rotation = Quat::from_axis_angle(perpendicular, spread.to_radians());
     let left_dir = (left_rotation * node.direction).normalize();
 
     let left_child = BranchNode {
@@ -90,8 +105,8 @@ fn build_tree_recursive(
         depth: node.depth + 1,
     };
 
-    // Right branch: rotate -angle_divergence around perpendicular
-    let right_rotation = Quat::from_axis_angle(perpendicular, -angle_divergence.to_radians());
+    // Right branch: rotate -spread around perpendicular
+    let right_rotation = Quat::from_axis_angle(perpendicular, -spread.to_radians());
     let right_dir = (right_rotation * node.direction).normalize();
 
     let right_child = BranchNode {
@@ -108,6 +123,7 @@ fn build_tree_recursive(
         max_depth,
         branch_ratio,
         angle_divergence,
+        divergence_decay,
         branching_axis,
     ));
     result.extend(build_tree_recursive(
@@ -115,6 +131,7 @@ fn build_tree_recursive(
         max_depth,
         branch_ratio,
         angle_divergence,
+        divergence_decay,
         branching_axis,
     ));
 
@@ -273,6 +290,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dichasium_divergence_decay() {
+        let axis = AxisCurve::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)]);
+        let min_tip_distance = |depth: usize, decay: f32| {
+            let params = InflorescenceParams {
+                recursion_depth: Some(depth),
+                angle_divergence: Some(60.0),
+                branch_ratio: Some(0.7),
+                branch_length_top: 1.0,
+                divergence_decay: decay,
+                ..Default::default()
+            };
+            let branches = generate_branch_points(&params, &axis);
+            let mut min = f32::MAX;
+            for (i, a) in branches.iter().enumerate() {
+                for b in &branches[i + 1..] {
+                    min = min.min(a.position.distance(b.position));
+                }
+            }
+            min
+        };
+
+        // Constant spread: deep tips fold onto each other
+        assert!(min_tip_distance(6, 1.0) < 0.01);
+
+        // Narrowing spread keeps tips apart as depth grows
+        for depth in 2..=6 {
+            let distance = min_tip_distance(depth, 0.8);
+            assert!(distance > 0.05, "depth {}: {}", depth, distance);
+        }
+
+        // Decay 1.0 matches the undecayed tree
+        let params = InflorescenceParams {
+            recursion_depth: Some(3),
+            ..Default::default()
+        };
+        let branches = generate_branch_points(&params, &axis);
+        assert_eq!(params.divergence_decay, 1.0);
+        let child = branches[1].direction;
+        let angle = branches[0].direction.angle_between(child).to_degrees();
+        assert!((angle - 30.0).abs() < 1e-3);
+    }
+
     #[test]
     fn test_dichasium_branch_ratio() {
         let params = InflorescenceParams {
@@ -8,7 +8,10 @@
 use floraison_core::math::curves::AxisCurve;
 use glam::{Quat, Vec3};
 
-use crate::{apply_age_distribution, BranchPoint, InflorescenceParams};
+use crate::{apply_age_distribution, BranchPoint, InflorescenceParams, SpiralHandedness};
+
+/// Recursion depth used when `params.recursion_depth` is None (6 nodes: root + 5 branches)
+pub const DEFAULT_RECURSION_DEPTH: usize = 5;
 
 /// Helper structure for recursive branch building
 #[derive(Debug, Clone)]
@@ -36,12 +39,22 @@ struct BranchNode {
 /// - **Determinate**: Top/center flowers oldest
 /// - Depth controlled by `params.recursion_depth` (default: 5)
 /// - Branch ratio: child length = parent × ratio (default: 0.8)
-/// - Spiral angle from `params.rotation_angle` (default: 137.5°)
+/// - Spiral angle from `params.angle_divergence`, falling back to
+///   `params.rotation_angle` (default: 137.5°) when it is unset or reduces
+///   to no turn (0° or a multiple of 360°), coiling in the direction of
+///   `params.spiral_handedness`; the root points along the axis normal, the
+///   helix of following branches starts from the binormal and each branch
+///   turns one more step around the axis
 pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) -> Vec<BranchPoint> {
     // Extract parameters with defaults
-    let max_depth = params.recursion_depth.unwrap_or(5); // Default depth of 5 (6 nodes: root + 5 branches)
+    let max_depth = params.recursion_depth.unwrap_or(DEFAULT_RECURSION_DEPTH);
     let branch_ratio = params.branch_ratio.unwrap_or(0.8);
-    let spiral_angle = params.rotation_angle; // Golden angle by default
+    let spiral_angle = params
+        .angle_divergence
+        .map(|angle| spiral_step(angle, params.spiral_handedness))
+        .filter(|&step| step != 0.0)
+        // Golden angle by default
+        .unwrap_or_else(|| spiral_step(params.rotation_angle, params.spiral_handedness));
 
     // Start from top of axis (determinate)
     let sample = axis.sample_at_t(1.0);
@@ -74,6 +87,32 @@ pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) ->
     nodes_to_branch_points(nodes, max_depth, params)
 }
 
+/// Signed per-branch spiral rotation (degrees) for a coil of the given handedness
+///
+/// Any angle is reduced to its smallest equivalent turn in [0°, 180°] before
+/// applying the sign, e.g. 270° becomes 90°, since larger steps would read as
+/// a turn the other way. Multiples of 360° (including 0°) reduce to 0, which
+/// does not coil at all.
+///
+/// # Arguments
+/// * `angle` - Requested spiral increment in degrees
+/// * `handedness` - Coil direction
+///
+/// # Returns
+/// Rotation around the helix axis between successive branches
+pub fn spiral_step(angle: f32, handedness: SpiralHandedness) -> f32 {
+    let wrapped = angle.abs().rem_euclid(360.0);
+    let magnitude = if wrapped > 180.0 {
+        360.0 - wrapped
+    } else {
+        wrapped
+    };
+    match handedness {
+        SpiralHandedness::CounterClockwise => magnitude,
+        SpiralHandedness::Clockwise => -magnitude,
+    }
+}
+
 /// Recursively build spiral branch structure
 fn build_spiral_recursive(
     node: &BranchNode,
@@ -237,6 +276,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_drepanium_coils_one_way() {
+        let axis = AxisCurve::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)]);
+        let frame = axis.sample_at_t(1.0);
+
+        for (handedness, sign) in [
+            (SpiralHandedness::CounterClockwise, 1.0),
+            (SpiralHandedness::Clockwise, -1.0),
+        ] {
+            let params = InflorescenceParams {
+                recursion_depth: Some(8),
+                angle_divergence: Some(100.0),
+                spiral_handedness: handedness,
+                ..Default::default()
+            };
+            let branches = generate_branch_points(&params, &axis);
+
+            // Azimuth around the axis, measured from the normal toward the binormal
+            let azimuth = |d: Vec3| {
+                d.dot(frame.binormal)
+                    .atan2(d.dot(frame.normal))
+                    .to_degrees()
+            };
+            // Successive branches after the root step around the axis
+            for pair in branches[1..].windows(2) {
+                let turn =
+                    (azimuth(pair[1].direction) - azimuth(pair[0].direction)).rem_euclid(360.0);
+                let signed = if turn > 180.0 { turn - 360.0 } else { turn };
+                assert!(
+                    (signed - sign * 100.0).abs() < 1e-2,
+                    "{:?}: expected a {}° step, got {}",
+                    handedness,
+                    sign * 100.0,
+                    signed
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_zero_divergence_falls_back_to_rotation_angle() {
+        use SpiralHandedness::*;
+        assert_eq!(spiral_step(0.0, CounterClockwise), 0.0);
+        assert_eq!(spiral_step(360.0, CounterClockwise), 0.0);
+
+        let axis = AxisCurve::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)]);
+        let expected = generate_branch_points(
+            &InflorescenceParams {
+                angle_divergence: None,
+                ..Default::default()
+            },
+            &axis,
+        );
+
+        for divergence in [0.0, 360.0] {
+            let params = InflorescenceParams {
+                angle_divergence: Some(divergence),
+                ..Default::default()
+            };
+            let branches = generate_branch_points(&params, &axis);
+            for (b, e) in branches.iter().zip(&expected) {
+                assert_eq!(b.position, e.position, "divergence {}", divergence);
+                assert_eq!(b.direction, e.direction, "divergence {}", divergence);
+            }
+
+            // Successive branches still rotate around the axis
+            for pair in branches[1..].windows(2) {
+                assert!(
+                    pair[0].direction.angle_between(pair[1].direction) > 0.1,
+                    "divergence {}: branches do not coil",
+                    divergence
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_spiral_step_wraps() {
+        use SpiralHandedness::*;
+        assert_eq!(spiral_step(137.5, CounterClockwise), 137.5);
+        assert_eq!(spiral_step(137.5, Clockwise), -137.5);
+        assert_eq!(spiral_step(270.0, CounterClockwise), 90.0);
+        assert_eq!(spiral_step(-90.0, CounterClockwise), 90.0);
+        assert_eq!(spiral_step(450.0, Clockwise), -90.0);
+    }
+
     #[test]
     fn test_drepanium_branch_ratio() {
         let params = InflorescenceParams {
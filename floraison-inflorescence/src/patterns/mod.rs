@@ -1,10 +1,479 @@
 //! Pattern generators for different inflorescence types
 
+pub mod catkin;
 pub mod compound_raceme;
 pub mod compound_umbel;
 pub mod corymb;
 pub mod dichasium;
 pub mod drepanium;
+pub mod panicle;
 pub mod raceme;
+pub mod spadix;
 pub mod spike;
+pub mod thyrse;
 pub mod umbel;
+pub mod verticillaster;
+
+use crate::{InflorescenceParams, PatternType};
+use glam::{Quat, Vec3};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use std::borrow::Cow;
+
+/// Count the flowers (branch points) a pattern produces, without generating geometry
+///
+/// For simple and recursive patterns this equals the length of the pattern's
+/// `generate_branch_points` output. For compound patterns it is the total
+/// number of flowers across all nesting levels.
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters (pattern, branch count, recursion depth, etc.)
+///
+/// # Returns
+/// Number of flowers in the assembled inflorescence
+///
+/// # Example
+/// ```
+/// use floraison_inflorescence::{patterns::count_branch_points, InflorescenceParams, PatternType};
+///
+/// let params = InflorescenceParams {
+///     pattern: PatternType::Dichasium,
+///     recursion_depth: Some(2),
+///     ..Default::default()
+/// };
+/// assert_eq!(count_branch_points(&params), 7);
+/// ```
+pub fn count_branch_points(params: &InflorescenceParams) -> usize {
+    count_up_to(params, usize::MAX)
+}
+
+/// Count flowers like [`count_branch_points`], stopping early above `limit`
+///
+/// Every pattern is counted in a bounded number of steps: nesting levels and
+/// branch orders are walked iteratively and the walk stops as soon as the
+/// running total exceeds `limit` (or saturates).
+///
+/// # Returns
+/// The exact count when it is at most `limit`, otherwise some value above `limit`
+fn count_up_to(params: &InflorescenceParams, limit: usize) -> usize {
+    // Full binary tree of the given depth (saturating for absurd depths)
+    let binary_tree = |depth: usize| {
+        u32::try_from(depth.saturating_add(1))
+            .ok()
+            .and_then(|bits| 1usize.checked_shl(bits))
+            .map_or(usize::MAX, |nodes| nodes - 1)
+    };
+    let over = |total: usize| total > limit || total == usize::MAX;
+
+    match params.pattern {
+        PatternType::Raceme
+        | PatternType::Spike
+        | PatternType::Umbel
+        | PatternType::Corymb
+        | PatternType::Catkin => params.branch_count,
+        PatternType::Spadix => params
+            .branch_count
+            .saturating_mul(spadix::SPADIX_FLOWERS_PER_BRANCH),
+        PatternType::Verticillaster => {
+            let per_node = params.flowers_per_node.unwrap_or(6).max(1);
+            (params.branch_count / per_node).max(1) * per_node
+        }
+        PatternType::Panicle => {
+            if params.branch_count == 0 {
+                return 0;
+            }
+            let depth = params
+                .recursion_depth
+                .unwrap_or(panicle::DEFAULT_RECURSION_DEPTH)
+                .max(1);
+            // Each branch: terminal flower plus laterals at every further order
+            let mut per_branch = 0usize;
+            let mut laterals = 1usize;
+            for _ in 0..depth {
+                per_branch = per_branch.saturating_add(laterals);
+                if over(per_branch) {
+                    break;
+                }
+                laterals = laterals.saturating_mul(panicle::LATERALS_PER_BRANCH);
+            }
+            params.branch_count.saturating_mul(per_branch)
+        }
+        PatternType::Dichasium => binary_tree(
+            params
+                .recursion_depth
+                .unwrap_or(dichasium::DEFAULT_RECURSION_DEPTH),
+        ),
+        PatternType::Drepanium => params
+            .recursion_depth
+            .unwrap_or(drepanium::DEFAULT_RECURSION_DEPTH)
+            .saturating_add(1),
+        PatternType::Thyrse => params.branch_count.saturating_mul(binary_tree(
+            params
+                .recursion_depth
+                .unwrap_or(thyrse::DEFAULT_RECURSION_DEPTH),
+        )),
+        PatternType::CompoundRaceme | PatternType::CompoundUmbel => {
+            let (default_depth, sub_params): (usize, fn(&InflorescenceParams, usize) -> _) =
+                if params.pattern == PatternType::CompoundRaceme {
+                    (
+                        compound_raceme::DEFAULT_RECURSION_DEPTH,
+                        compound_raceme::sub_inflorescence_params,
+                    )
+                } else {
+                    (
+                        compound_umbel::DEFAULT_RECURSION_DEPTH,
+                        compound_umbel::sub_inflorescence_params,
+                    )
+                };
+
+            // Multiply the branch counts of the nesting levels, outermost first
+            let mut depth = params.recursion_depth.unwrap_or(default_depth);
+            let mut level = Cow::Borrowed(params);
+            let mut total = level.branch_count;
+            while depth > 1 && total > 0 && !over(total) {
+                level = Cow::Owned(sub_params(&level, depth));
+                depth -= 1;
+                total = total.saturating_mul(level.branch_count);
+            }
+            total
+        }
+    }
+}
+
+/// Recursion depth a pattern uses when `recursion_depth` is None
+///
+/// # Returns
+/// The pattern's default depth, or None for patterns that don't recurse
+fn default_recursion_depth(pattern: PatternType) -> Option<usize> {
+    match pattern {
+        PatternType::Panicle => Some(panicle::DEFAULT_RECURSION_DEPTH),
+        PatternType::Dichasium => Some(dichasium::DEFAULT_RECURSION_DEPTH),
+        PatternType::Drepanium => Some(drepanium::DEFAULT_RECURSION_DEPTH),
+        PatternType::Thyrse => Some(thyrse::DEFAULT_RECURSION_DEPTH),
+        PatternType::CompoundRaceme => Some(compound_raceme::DEFAULT_RECURSION_DEPTH),
+        PatternType::CompoundUmbel => Some(compound_umbel::DEFAULT_RECURSION_DEPTH),
+        _ => None,
+    }
+}
+
+/// Deepest recursion that could still fit within `max_flowers` flowers
+///
+/// Every nesting level at least doubles the flower count (Drepanium adds
+/// one flower per level), so deeper settings can never fit.
+fn max_useful_depth(pattern: PatternType, max_flowers: usize) -> usize {
+    match pattern {
+        PatternType::Drepanium => max_flowers.saturating_sub(1),
+        _ => (usize::BITS - max_flowers.leading_zeros()) as usize,
+    }
+}
+
+/// Reduce parameters so the flower count stays within `params.max_flowers`
+///
+/// Parameters within budget are returned unchanged (borrowed). Otherwise
+/// `recursion_depth` and `flowers_per_node` are first clamped to values that
+/// could fit, so absurd inputs cost no more than reasonable ones. Then the
+/// recursion depth is lowered one level at a time, and the branch count is
+/// lowered to the largest value that fits, so the result is deterministic
+/// and never allocates more than the cap allows. If even one branch exceeds
+/// the cap (e.g. a spadix with a cap below 4), that minimum is kept.
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters, possibly over budget
+///
+/// # Returns
+/// Parameters producing at most `params.max_flowers` flowers where possible
+///
+/// # Example
+/// ```
+/// use floraison_inflorescence::patterns::{count_branch_points, limit_flower_count};
+/// use floraison_inflorescence::{InflorescenceParams, PatternType};
+///
+/// let params = InflorescenceParams {
+///     pattern: PatternType::Dichasium,
+///     recursion_depth: Some(40),
+///     max_flowers: 100,
+///     ..Default::default()
+/// };
+/// let limited = limit_flower_count(&params);
+/// assert_eq!(limited.recursion_depth, Some(5)); // 63 flowers; depth 6 would be 127
+/// assert!(count_branch_points(&limited) <= 100);
+/// ```
+pub fn limit_flower_count(params: &InflorescenceParams) -> Cow<'_, InflorescenceParams> {
+    let max = params.max_flowers;
+    if count_up_to(params, max) <= max {
+        return Cow::Borrowed(params);
+    }
+
+    let mut limited = params.clone();
+
+    // A single whorl never needs more flowers than the cap
+    limited.flowers_per_node = params.flowers_per_node.map(|n| n.clamp(1, max.max(1)));
+
+    // Prune nesting levels first: they grow the count exponentially
+    if let Some(default_depth) = default_recursion_depth(params.pattern) {
+        let mut depth = params
+            .recursion_depth
+            .unwrap_or(default_depth)
+            .min(max_useful_depth(params.pattern, max));
+        limited.recursion_depth = Some(depth);
+        while depth > 0 && count_up_to(&limited, max) > max {
+            depth -= 1;
+            limited.recursion_depth = Some(depth);
+        }
+    }
+
+    // Then keep the largest branch count that fits (count grows with branch_count)
+    if count_up_to(&limited, max) > max {
+        let (mut fits, mut too_many) = (1, limited.branch_count);
+        while too_many - fits > 1 {
+            let mid = fits + (too_many - fits) / 2;
+            limited.branch_count = mid;
+            if count_up_to(&limited, max) <= max {
+                fits = mid;
+            } else {
+                too_many = mid;
+            }
+        }
+        limited.branch_count = fits;
+    }
+
+    Cow::Owned(limited)
+}
+
+/// Randomly perturb a pedicel's direction and length
+///
+/// Uses a `SmallRng` seeded with `params.jitter_seed + index`, so each branch
+/// gets its own reproducible offsets. The direction tilts by up to
+/// `pedicel_angle_jitter` degrees toward a random side; the length is scaled
+/// by a factor within ±`pedicel_length_jitter`. With both jitters at zero the
+/// inputs are returned exactly.
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters (uses the pedicel jitter fields)
+/// * `index` - Branch index within the pattern
+/// * `direction` - Unit pedicel direction
+/// * `length` - Pedicel length
+///
+/// # Returns
+/// Jittered (direction, length); the direction stays normalized
+///
+/// # Example
+/// ```
+/// use floraison_inflorescence::patterns::jitter_pedicel;
+/// use floraison_inflorescence::InflorescenceParams;
+/// use glam::Vec3;
+///
+/// let exact = InflorescenceParams::default();
+/// assert_eq!(jitter_pedicel(&exact, 3, Vec3::X, 1.0), (Vec3::X, 1.0));
+///
+/// let params = InflorescenceParams {
+///     pedicel_angle_jitter: 10.0,
+///     pedicel_length_jitter: 0.2,
+///     ..Default::default()
+/// };
+/// let (direction, length) = jitter_pedicel(&params, 3, Vec3::X, 1.0);
+/// assert!(direction.angle_between(Vec3::X).to_degrees() <= 10.0 + 1e-3);
+/// assert!((0.8..=1.2).contains(&length));
+/// ```
+pub fn jitter_pedicel(
+    params: &InflorescenceParams,
+    index: usize,
+    direction: Vec3,
+    length: f32,
+) -> (Vec3, f32) {
+    let max_angle = params.pedicel_angle_jitter.max(0.0).to_radians();
+    let max_scale = params.pedicel_length_jitter.clamp(0.0, 1.0);
+    if max_angle == 0.0 && max_scale == 0.0 {
+        return (direction, length);
+    }
+
+    let mut rng = SmallRng::seed_from_u64(params.jitter_seed.wrapping_add(index as u64));
+
+    let direction = if max_angle > 0.0 {
+        // Tilt around an axis perpendicular to the pedicel, spun to a random side
+        let side = rng.gen_range(0.0..std::f32::consts::TAU);
+        let tilt = rng.gen_range(-max_angle..=max_angle);
+        let tilt_axis = Quat::from_axis_angle(direction, side) * direction.any_orthonormal_vector();
+        (Quat::from_axis_angle(tilt_axis, tilt) * direction).normalize()
+    } else {
+        direction
+    };
+
+    let length = if max_scale > 0.0 {
+        length * (1.0 + rng.gen_range(-max_scale..=max_scale))
+    } else {
+        length
+    };
+
+    (direction, length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assembly::{assemble_inflorescence, generate_axis_points};
+    use floraison_core::geometry::mesh::Mesh;
+    use floraison_core::math::curves::AxisCurve;
+    use floraison_core::{Vec2, Vec3};
+
+    const FLOWER_COLOR: Vec3 = Vec3::new(1.0, 0.5, 0.5);
+
+    fn single_vertex_flower() -> Mesh {
+        let mut mesh = Mesh::new();
+        mesh.add_vertex(Vec3::ZERO, Vec3::Y, Vec2::ZERO, FLOWER_COLOR);
+        mesh
+    }
+
+    fn actual_count(params: &InflorescenceParams) -> usize {
+        let axis = AxisCurve::new(generate_axis_points(params));
+        match params.pattern {
+            PatternType::Raceme => raceme::generate_branch_points(params, &axis).len(),
+            PatternType::Umbel => umbel::generate_branch_points(params, &axis).len(),
+            PatternType::Dichasium => dichasium::generate_branch_points(params, &axis).len(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_count_matches_generated_branch_points() {
+        for pattern in [
+            PatternType::Raceme,
+            PatternType::Umbel,
+            PatternType::Dichasium,
+        ] {
+            for (branch_count, depth) in [(1, None), (7, Some(0)), (12, Some(2)), (20, Some(4))] {
+                let params = InflorescenceParams {
+                    pattern,
+                    branch_count,
+                    recursion_depth: depth,
+                    ..Default::default()
+                };
+                assert_eq!(
+                    count_branch_points(&params),
+                    actual_count(&params),
+                    "{:?} with {} branches, depth {:?}",
+                    pattern,
+                    branch_count,
+                    depth
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_count_matches_compound_generators() {
+        let flower = single_vertex_flower();
+        for pattern in [PatternType::CompoundRaceme, PatternType::CompoundUmbel] {
+            for (branch_count, depth) in [(5, Some(1)), (6, Some(2)), (4, Some(3))] {
+                let params = InflorescenceParams {
+                    pattern,
+                    branch_count,
+                    recursion_depth: depth,
+                    ..Default::default()
+                };
+                let mesh = assemble_inflorescence(&params, &flower, Vec3::new(0.2, 0.6, 0.2));
+                let flowers = mesh.colors.iter().filter(|c| **c == FLOWER_COLOR).count();
+                assert_eq!(
+                    count_branch_points(&params),
+                    flowers,
+                    "{:?} with {} branches, depth {:?}",
+                    pattern,
+                    branch_count,
+                    depth
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_count_other_patterns() {
+        let flower = single_vertex_flower();
+        for pattern in [
+            PatternType::Spike,
+            PatternType::Corymb,
+            PatternType::Catkin,
+            PatternType::Spadix,
+            PatternType::Panicle,
+            PatternType::Verticillaster,
+            PatternType::Drepanium,
+            PatternType::Thyrse,
+        ] {
+            let params = InflorescenceParams {
+                pattern,
+                branch_count: 10,
+                ..Default::default()
+            };
+            let mesh = assemble_inflorescence(&params, &flower, Vec3::new(0.2, 0.6, 0.2));
+            let flowers = mesh.colors.iter().filter(|c| **c == FLOWER_COLOR).count();
+            assert_eq!(count_branch_points(&params), flowers, "{:?}", pattern);
+        }
+    }
+
+    #[test]
+    fn test_limit_absurd_inputs_quickly() {
+        for pattern in [
+            PatternType::Panicle,
+            PatternType::Dichasium,
+            PatternType::Drepanium,
+            PatternType::Thyrse,
+            PatternType::CompoundRaceme,
+            PatternType::CompoundUmbel,
+        ] {
+            for depth in [1_000, 100_000, usize::MAX] {
+                let params = InflorescenceParams {
+                    pattern,
+                    recursion_depth: Some(depth),
+                    ..Default::default()
+                };
+                let limited = limit_flower_count(&params);
+                let count = count_branch_points(&limited);
+                assert!(
+                    count > 0 && count <= params.max_flowers,
+                    "{:?} at depth {}: {} flowers",
+                    pattern,
+                    depth,
+                    count
+                );
+            }
+        }
+
+        // Huge whorls are capped too
+        let params = InflorescenceParams {
+            pattern: PatternType::Verticillaster,
+            flowers_per_node: Some(usize::MAX),
+            max_flowers: 100,
+            ..Default::default()
+        };
+        assert!(count_branch_points(&limit_flower_count(&params)) <= 100);
+    }
+
+    #[test]
+    fn test_limit_depth_clamp_matches_stepwise_reduction() {
+        // Clamping up front must not prune depths that still fit
+        for (pattern, depth, max_flowers) in [
+            (PatternType::Dichasium, 30, 100),
+            (PatternType::Panicle, 20, 1_000),
+            (PatternType::Drepanium, 50, 20),
+            (PatternType::CompoundRaceme, 12, 5_000),
+        ] {
+            let params = InflorescenceParams {
+                pattern,
+                recursion_depth: Some(depth),
+                max_flowers,
+                ..Default::default()
+            };
+            let limited = limit_flower_count(&params);
+            let depth = limited.recursion_depth.unwrap();
+            let one_deeper = InflorescenceParams {
+                recursion_depth: Some(depth + 1),
+                ..limited.clone().into_owned()
+            };
+            assert!(count_branch_points(&limited) <= max_flowers);
+            assert!(
+                count_branch_points(&one_deeper) > max_flowers,
+                "{:?}",
+                pattern
+            );
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Panicle pattern generator
+//!
+//! Panicle: Branched raceme where the lateral branches branch again.
+//! Blooming pattern: Indeterminate (bottom flowers bloom first).
+//!
+//! Examples: Oats, Lilac, Baby's breath
+
+use floraison_core::math::curves::AxisCurve;
+use glam::{Quat, Vec3};
+
+use crate::{apply_age_distribution, BranchPoint, InflorescenceParams};
+
+/// Number of lateral sub-branches borne by each branch below the last level
+pub const LATERALS_PER_BRANCH: usize = 3;
+
+/// Branch orders used when `params.recursion_depth` is None
+pub const DEFAULT_RECURSION_DEPTH: usize = 2;
+
+/// Linear interpolation between two values
+#[inline]
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Helper structure for recursive branch building
+#[derive(Debug, Clone)]
+struct PanicleBranch {
+    base: Vec3,
+    direction: Vec3,
+    length: f32,
+    depth: usize,
+    flower_scale: f32,
+    base_age: f32,
+}
+
+/// Generate branch points for a panicle pattern
+///
+/// Every branch ends in a terminal flower whose pedicel spans the whole
+/// branch, so the branch itself is drawn as that pedicel. Branches below the
+/// last level additionally bear lateral sub-branches along their length.
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters defining axis, branch count, angles, etc.
+/// * `axis` - The main axis curve along which primary branches are arranged
+///
+/// # Returns
+/// Vector of branch points, each representing a flower attachment location
+///
+/// # Pattern Characteristics
+/// - `branch_count` primary branches evenly spaced along axis, spiraling by `rotation_angle`
+/// - Primary branch length interpolates from bottom to top (shorter at top by default)
+/// - Depth controlled by `params.recursion_depth` (default: 2); depth 1 is a raceme
+/// - Each branch below the last level bears 3 laterals, each of length parent × ratio
+///   (`params.branch_ratio`, default: 0.5)
+/// - Laterals diverge from their parent by `params.angle_divergence` (default: 40°)
+/// - Age decreases up the axis and toward branch tips - indeterminate
+pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) -> Vec<BranchPoint> {
+    // Extract parameters with defaults
+    let max_depth = params
+        .recursion_depth
+        .unwrap_or(DEFAULT_RECURSION_DEPTH)
+        .max(1);
+    let branch_ratio = params.branch_ratio.unwrap_or(0.5);
+    let angle_div = params.angle_divergence.unwrap_or(40.0);
+
+    let mut branches = Vec::new();
+
+    for i in 0..params.branch_count {
+        // Normalize position along axis (0.0 at bottom, 1.0 at top)
+        let t = if params.branch_count > 1 {
+            i as f32 / (params.branch_count - 1) as f32
+        } else {
+            0.5 // Single branch at middle
+        };
+
+        // Sample axis to get position and Frenet frame
+        let sample = axis.sample_at_t(t);
+
+        // Interpolate parameters from bottom to top
+        let angle = lerp(params.angle_bottom, params.angle_top, t);
+        let length = lerp(params.branch_length_bottom, params.branch_length_top, t);
+        let flower_scale = lerp(params.flower_size_bottom, params.flower_size_top, t);
+
+        // Primary branch direction, as for a raceme pedicel
+        let rotation = params.rotation_angle * i as f32;
+        let down_rotation = Quat::from_axis_angle(sample.binormal, -angle.to_radians());
+        let spiral_rotation = Quat::from_axis_angle(sample.tangent, rotation.to_radians());
+        let direction = (spiral_rotation * down_rotation * sample.normal).normalize();
+
+        let primary = PanicleBranch {
+            base: sample.position,
+            direction,
+            length,
+            depth: 1,
+            flower_scale,
+            // Indeterminate (bottom = oldest = 1.0, top = youngest = 0.0)
+            base_age: 1.0 - t,
+        };
+
+        build_branch_recursive(
+            &primary,
+            max_depth,
+            branch_ratio,
+            angle_div,
+            params,
+            &mut branches,
+        );
+    }
+
+    branches
+}
+
+/// Emit the terminal flower of `branch`, then recurse into its laterals
+fn build_branch_recursive(
+    branch: &PanicleBranch,
+    max_depth: usize,
+    branch_ratio: f32,
+    angle_divergence: f32,
+    params: &InflorescenceParams,
+    out: &mut Vec<BranchPoint>,
+) {
+    // Terminal flower: its pedicel is the branch itself
+    // (tip is youngest on an indeterminate branch)
+    out.push(BranchPoint {
+        position: branch.base + branch.direction * branch.length,
+        direction: branch.direction,
+        length: branch.length,
+        flower_scale: branch.flower_scale,
+        age: apply_age_distribution(branch.base_age * 0.5, params.age_distribution),
+    });
+
+    // Base case: reached maximum depth
+    if branch.depth >= max_depth {
+        return;
+    }
+
+    // Laterals diverge around a perpendicular that spirals about the branch
+    let perpendicular = branch.direction.any_orthonormal_vector();
+
+    for j in 0..LATERALS_PER_BRANCH {
+        // Fraction along the parent branch (0 = base, 1 = tip)
+        let s = (j + 1) as f32 / (LATERALS_PER_BRANCH + 1) as f32;
+
+        let spiral = Quat::from_axis_angle(
+            branch.direction,
+            (params.rotation_angle * (j + branch.depth) as f32).to_radians(),
+        );
+        let bend_axis = spiral * perpendicular;
+        let bend = Quat::from_axis_angle(bend_axis, angle_divergence.to_radians());
+
+        let lateral = PanicleBranch {
+            base: branch.base + branch.direction * (branch.length * s),
+            direction: (bend * branch.direction).normalize(),
+            // Laterals shorten toward the branch tip for a tapering outline
+            length: branch.length * branch_ratio * (1.0 - 0.5 * s),
+            depth: branch.depth + 1,
+            flower_scale: branch.flower_scale * 0.9,
+            // Laterals near the branch base open first
+            base_age: branch.base_age * (1.0 - 0.5 * s),
+        };
+
+        build_branch_recursive(
+            &lateral,
+            max_depth,
+            branch_ratio,
+            angle_divergence,
+            params,
+            out,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns::raceme;
+
+    fn straight_axis() -> AxisCurve {
+        AxisCurve::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)])
+    }
+
+    #[test]
+    fn test_panicle_more_branches_than_raceme() {
+        let params = InflorescenceParams {
+            branch_count: 6,
+            ..Default::default()
+        };
+
+        let panicle = generate_branch_points(&params, &straight_axis());
+        let raceme = raceme::generate_branch_points(&params, &straight_axis());
+
+        // Default depth 2: each primary branch bears a terminal flower + 3 laterals
+        assert_eq!(panicle.len(), 6 * (1 + LATERALS_PER_BRANCH));
+        assert!(panicle.len() > raceme.len());
+    }
+
+    #[test]
+    fn test_panicle_flower_count_grows_with_depth() {
+        let counts: Vec<usize> = (1..=4)
+            .map(|depth| {
+                let params = InflorescenceParams {
+                    branch_count: 5,
+                    recursion_depth: Some(depth),
+                    ..Default::default()
+                };
+                generate_branch_points(&params, &straight_axis()).len()
+            })
+            .collect();
+
+        // Depth 1 is a plain raceme
+        assert_eq!(counts[0], 5);
+        for pair in counts.windows(2) {
+            assert!(pair[1] > pair[0], "Flower count should grow with depth");
+        }
+    }
+
+    #[test]
+    fn test_panicle_branches_shorten_upward() {
+        let params = InflorescenceParams {
+            branch_count: 5,
+            recursion_depth: Some(1),
+            ..Default::default()
+        };
+
+        let branches = generate_branch_points(&params, &straight_axis());
+        for pair in branches.windows(2) {
+            assert!(pair[1].length < pair[0].length);
+        }
+    }
+
+    #[test]
+    fn test_panicle_laterals_attach_to_parent() {
+        let params = InflorescenceParams {
+            branch_count: 1,
+            recursion_depth: Some(2),
+            ..Default::default()
+        };
+
+        let branches = generate_branch_points(&params, &straight_axis());
+        let primary = &branches[0];
+        let primary_base = primary.position - primary.direction * primary.length;
+
+        for lateral in &branches[1..] {
+            assert!((lateral.direction.length() - 1.0).abs() < 1e-5);
+
+            // Lateral base lies on the primary branch segment
+            let base = lateral.position - lateral.direction * lateral.length;
+            let along = (base - primary_base).dot(primary.direction);
+            let closest = primary_base + primary.direction * along;
+            assert!(along > 0.0 && along < primary.length);
+            assert!(base.distance(closest) < 1e-4);
+        }
+    }
+}
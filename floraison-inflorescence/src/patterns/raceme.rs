@@ -8,6 +8,7 @@
 use floraison_core::math::curves::AxisCurve;
 use glam::Quat;
 
+use crate::patterns::jitter_pedicel;
 use crate::{apply_age_distribution, BranchPoint, InflorescenceParams};
 
 /// Linear interpolation between two values
@@ -26,7 +27,7 @@ fn lerp(a: f32, b: f32, t: f32) -> f32 {
 /// Vector of branch points, each representing a flower attachment location
 ///
 /// # Pattern Characteristics
-/// - Flowers evenly spaced along axis
+/// - Flowers spaced along axis according to `internode_spacing` (even by default)
 /// - Pedicel length interpolates from bottom to top
 /// - Down angle interpolates from bottom to top
 /// - Rotation follows Fibonacci spiral (golden angle)
@@ -35,15 +36,16 @@ pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) ->
     let mut branches = Vec::with_capacity(params.branch_count);
 
     for i in 0..params.branch_count {
-        // Normalize position along axis (0.0 at bottom, 1.0 at top)
-        let t = if params.branch_count > 1 {
-            i as f32 / (params.branch_count - 1) as f32
+        // Normalize rank (0.0 at bottom, 1.0 at top), then place it on the axis
+        let (t, axis_t) = if params.branch_count > 1 {
+            let t = i as f32 / (params.branch_count - 1) as f32;
+            (t, params.internode_spacing.position(t))
         } else {
-            0.5 // Single flower at middle
+            (0.5, 0.5) // Single flower at middle
         };
 
         // Sample axis to get position and Frenet frame
-        let sample = axis.sample_at_t(t);
+        let sample = axis.sample_at_t(axis_t);
 
         // Interpolate parameters from bottom to top
         let angle = lerp(params.angle_bottom, params.angle_top, t);
@@ -61,6 +63,9 @@ pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) ->
         // 3. Apply both rotations to the normal vector
         let direction = (spiral_rotation * down_rotation * sample.normal).normalize();
 
+        // Natural variation
+        let (direction, length) = jitter_pedicel(params, i, direction, length);
+
         // Branch endpoint (flower position)
         let position = sample.position + direction * length;
 
@@ -83,6 +88,7 @@ pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) ->
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::SpacingProfile;
     use floraison_core::Vec3;
 
     #[test]
@@ -226,4 +232,78 @@ mod tests {
             "Fibonacci rotation should create different X/Z directions"
         );
     }
+
+    #[test]
+    fn test_raceme_internode_spacing() {
+        let axis = AxisCurve::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)]);
+        let upper_half = |spacing| {
+            let params = InflorescenceParams {
+                branch_count: 10,
+                internode_spacing: spacing,
+                ..Default::default()
+            };
+            // Pedicel bases lie on the axis
+            generate_branch_points(&params, &axis)
+                .iter()
+                .filter(|b| (b.position - b.direction * b.length).y > 5.0)
+                .count()
+        };
+
+        assert_eq!(upper_half(SpacingProfile::Uniform), 5);
+        assert!(upper_half(SpacingProfile::CrowdedTop) > upper_half(SpacingProfile::CrowdedBase));
+        assert!(upper_half(SpacingProfile::CrowdedTop) > 5);
+        assert!(upper_half(SpacingProfile::CrowdedBase) < 5);
+    }
+
+    #[test]
+    fn test_raceme_pedicel_jitter() {
+        let axis = AxisCurve::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)]);
+        let exact = InflorescenceParams {
+            branch_count: 8,
+            jitter_seed: 7,
+            ..Default::default()
+        };
+        let jittered = InflorescenceParams {
+            pedicel_angle_jitter: 15.0,
+            pedicel_length_jitter: 0.3,
+            ..exact.clone()
+        };
+
+        let plain = generate_branch_points(&exact, &axis);
+        let a = generate_branch_points(&jittered, &axis);
+        let b = generate_branch_points(&jittered, &axis);
+
+        // Zero jitter: seed is irrelevant and lengths are the interpolated values
+        let reseeded = generate_branch_points(
+            &InflorescenceParams {
+                jitter_seed: 8,
+                ..exact.clone()
+            },
+            &axis,
+        );
+        for (i, (p, r)) in plain.iter().zip(&reseeded).enumerate() {
+            let t = i as f32 / 7.0;
+            assert_eq!(
+                p.length,
+                lerp(exact.branch_length_bottom, exact.branch_length_top, t)
+            );
+            assert_eq!((p.direction, p.length), (r.direction, r.length));
+        }
+
+        // Same seed: identical perturbations
+        for (x, y) in a.iter().zip(&b) {
+            assert_eq!(
+                (x.position, x.direction, x.length),
+                (y.position, y.direction, y.length)
+            );
+        }
+
+        // Perturbations stay within bounds and move every pedicel
+        for (p, x) in plain.iter().zip(&a) {
+            let tilt = p.direction.angle_between(x.direction).to_degrees();
+            assert!(tilt <= 15.0 + 1e-3);
+            assert!((x.length / p.length - 1.0).abs() <= 0.3 + 1e-5);
+            assert!(tilt > 0.0 || x.length != p.length);
+        }
+    }
 }
@@ -0,0 +1,303 @@
+//! Spadix pattern generator
+//!
+//! Spadix: Thick, fleshy, club-shaped spike densely covered in tiny sessile
+//! flowers, usually subtended by a large bract (the spathe).
+//! Blooming pattern: Indeterminate (bottom flowers bloom first).
+//!
+//! Examples: Peace lily, Calla, Anthurium
+
+use floraison_core::geometry::mesh::Mesh;
+use floraison_core::geometry::sweep::sweep_with_radii;
+use floraison_core::math::bspline::{generate_knot_vector, BSplineSurface};
+use floraison_core::math::curves::AxisCurve;
+use floraison_core::math::vector::rotation_between;
+use glam::{Mat4, Quat, Vec2, Vec3};
+use std::f32::consts::PI;
+
+use crate::{apply_age_distribution, BranchPoint, InflorescenceParams};
+
+/// Fraction of the axis below the spadix (bare peduncle)
+pub const SPADIX_START: f32 = 0.5;
+
+/// Maximum spadix radius relative to `axis_length`
+pub const SPADIX_RADIUS_RATIO: f32 = 0.04;
+
+/// Flowers generated per unit of `branch_count` (the spadix is densely packed)
+pub const SPADIX_FLOWERS_PER_BRANCH: usize = 4;
+
+/// Flower scale relative to `flower_size_top`/`flower_size_bottom`
+pub const SPADIX_FLOWER_SCALE: f32 = 0.15;
+
+/// Default spathe color (creamy white, as in peace lilies)
+pub const SPATHE_COLOR: Vec3 = Vec3::new(0.95, 0.95, 0.88);
+
+/// Number of rings swept along the spadix club
+const SPADIX_RINGS: usize = 24;
+
+/// Spathe tessellation resolution (quads per side)
+const SPATHE_RESOLUTION: usize = 16;
+
+/// Linear interpolation between two values
+#[inline]
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Radius of the spadix club at fraction `s` of its length (0 = base, 1 = tip)
+///
+/// The club widens slightly toward the top and closes with a rounded tip.
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters (uses `axis_length`)
+/// * `s` - Position along the spadix, 0.0 to 1.0
+///
+/// # Returns
+/// Club radius at that position
+pub fn spadix_radius(params: &InflorescenceParams, s: f32) -> f32 {
+    let s = s.clamp(0.0, 1.0);
+    let max_radius = params.axis_length * SPADIX_RADIUS_RATIO;
+    let rounded_tip = ((1.0 - s) / 0.1).sqrt().min(1.0);
+    max_radius * (0.75 + 0.25 * s) * rounded_tip
+}
+
+/// Generate branch points for a spadix pattern
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters defining axis, branch count, angles, etc.
+/// * `axis` - The main axis curve; the spadix covers its upper part
+///
+/// # Returns
+/// Vector of branch points, each representing a flower attachment location
+///
+/// # Pattern Characteristics
+/// - Spadix covers the axis above [`SPADIX_START`]
+/// - `branch_count` × [`SPADIX_FLOWERS_PER_BRANCH`] tiny **sessile** flowers on the club surface
+/// - Flowers spiral by `rotation_angle` and face radially outward
+/// - Age decreases from bottom (oldest) to top (youngest) - indeterminate
+pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) -> Vec<BranchPoint> {
+    let count = params.branch_count * SPADIX_FLOWERS_PER_BRANCH;
+    let mut branches = Vec::with_capacity(count);
+
+    for i in 0..count {
+        // Position along the spadix, kept off the very base and tip
+        let s = (i as f32 + 0.5) / count as f32;
+        let t = lerp(SPADIX_START, 1.0, s);
+
+        // Sample axis to get position and Frenet frame
+        let sample = axis.sample_at_t(t);
+
+        // Radial direction spiraling around the axis
+        let rotation = params.rotation_angle * i as f32;
+        let spiral_rotation = Quat::from_axis_angle(sample.tangent, rotation.to_radians());
+        let direction = (spiral_rotation * sample.normal).normalize();
+
+        let flower_scale =
+            lerp(params.flower_size_bottom, params.flower_size_top, s) * SPADIX_FLOWER_SCALE;
+
+        // Age: indeterminate (bottom = oldest = 1.0, top = youngest = 0.0)
+        let age = apply_age_distribution(1.0 - s, params.age_distribution);
+
+        branches.push(BranchPoint {
+            position: sample.position + direction * spadix_radius(params, s),
+            direction,
+            length: 0.0, // Sessile: no pedicel
+            flower_scale,
+            age,
+        });
+    }
+
+    branches
+}
+
+/// Generate the stem of a spadix: a thin peduncle topped by the thick club
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters (uses `axis_length` and `stem_segments`)
+/// * `axis_points` - Points defining the axis curve
+/// * `stem_radius` - Radius of the peduncle below the spadix
+/// * `color` - RGB color for the stem
+///
+/// # Returns
+/// Mesh of the peduncle and spadix club
+pub fn generate_spadix_stem(
+    params: &InflorescenceParams,
+    axis_points: &[Vec3],
+    stem_radius: f32,
+    color: Vec3,
+) -> Mesh {
+    let axis = AxisCurve::new(axis_points.to_vec());
+
+    // Peduncle (two rings), then the club
+    let mut ts = vec![0.0, SPADIX_START];
+    let mut radii = vec![stem_radius, stem_radius];
+    for k in 0..=SPADIX_RINGS {
+        let s = k as f32 / SPADIX_RINGS as f32;
+        ts.push(lerp(SPADIX_START, 1.0, s));
+        radii.push(spadix_radius(params, s).max(stem_radius * 0.5));
+    }
+
+    let positions: Vec<Vec3> = ts.iter().map(|&t| axis.position_at_t(t)).collect();
+    sweep_with_radii(&radii, &positions, params.stem_segments * 3 / 2, color)
+}
+
+/// Generate the spathe: a large curled bract wrapping the base of the spadix
+///
+/// The spathe is a double-sided cubic B-spline surface standing behind the
+/// spadix (toward -Z in the axis frame). It wraps almost all the way around
+/// the spadix base, opens up into a broad blade and narrows to a pointed tip
+/// above the spadix.
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters (uses `axis_length`)
+/// * `axis` - The main axis curve
+/// * `color` - RGB color for the spathe
+///
+/// # Returns
+/// Mesh of the spathe
+pub fn generate_spathe(params: &InflorescenceParams, axis: &AxisCurve, color: Vec3) -> Mesh {
+    let base = axis.sample_at_t(SPADIX_START);
+    let spadix_length = axis.length() * (1.0 - SPADIX_START);
+    let spathe_length = spadix_length * 1.4;
+    let base_radius = spadix_radius(params, 0.0) * 1.3;
+    let blade_width = spadix_length * 0.35;
+
+    // Control grid in the axis frame: rows across the width (u), columns along the length (v)
+    const ROWS: usize = 5;
+    const COLS: usize = 6;
+    let control_points: Vec<Vec<Vec3>> = (0..ROWS)
+        .map(|a| {
+            let u = a as f32 / (ROWS - 1) as f32;
+            (0..COLS)
+                .map(|b| {
+                    let v = b as f32 / (COLS - 1) as f32;
+
+                    // Wraps around the spadix at the base, opens, then closes to a point
+                    let half_span = lerp(0.85 * PI, 0.35 * PI, v) * (1.0 - v.powi(4));
+                    let theta = (u - 0.5) * 2.0 * half_span;
+                    let rho = base_radius + blade_width * (PI * v * 0.9).sin();
+
+                    // Blade leans back as it rises
+                    let lean = 0.2 * spadix_length * v * v;
+                    Vec3::new(
+                        rho * theta.sin(),
+                        v * spathe_length - 0.05 * spadix_length,
+                        -rho * theta.cos() - lean,
+                    )
+                })
+                .collect()
+        })
+        .collect();
+
+    let surface = BSplineSurface {
+        control_points,
+        degree_u: 3,
+        degree_v: 3,
+        knots_u: generate_knot_vector(ROWS, 3, true),
+        knots_v: generate_knot_vector(COLS, 3, true),
+    };
+
+    // Sample both faces on a uniform grid
+    let res = SPATHE_RESOLUTION;
+    let side = (res + 1) * (res + 1);
+    let mut mesh = Mesh::with_capacity(side * 2, res * res * 12);
+    for back in [false, true] {
+        for i in 0..=res {
+            let u = i as f32 / res as f32;
+            for j in 0..=res {
+                let v = j as f32 / res as f32;
+                let normal = surface.normal(u, v);
+                let normal = if back { -normal } else { normal };
+                mesh.add_vertex(
+                    surface.evaluate_de_boor(u, v),
+                    normal,
+                    Vec2::new(u, v),
+                    color,
+                );
+            }
+        }
+    }
+    for (offset, back) in [(0, false), (side, true)] {
+        for i in 0..res {
+            for j in 0..res {
+                let i0 = (offset + i * (res + 1) + j) as u32;
+                let i1 = i0 + 1;
+                let i2 = i0 + res as u32 + 1;
+                let i3 = i2 + 1;
+                if back {
+                    mesh.add_triangle(i0, i1, i2);
+                    mesh.add_triangle(i1, i3, i2);
+                } else {
+                    mesh.add_triangle(i0, i2, i1);
+                    mesh.add_triangle(i1, i2, i3);
+                }
+            }
+        }
+    }
+
+    // Move from the axis frame to the spadix base
+    let rotation = rotation_between(Vec3::Y, base.tangent);
+    mesh.transform(&Mat4::from_rotation_translation(rotation, base.position));
+    mesh
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn straight_axis() -> AxisCurve {
+        AxisCurve::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)])
+    }
+
+    #[test]
+    fn test_spadix_dense_flowers_on_club() {
+        let params = InflorescenceParams {
+            branch_count: 10,
+            ..Default::default()
+        };
+
+        let branches = generate_branch_points(&params, &straight_axis());
+        assert_eq!(branches.len(), 10 * SPADIX_FLOWERS_PER_BRANCH);
+
+        for branch in &branches {
+            assert_eq!(branch.length, 0.0);
+            assert!(branch.position.y >= SPADIX_START * 10.0);
+            // Flowers sit on the club surface, facing outward
+            let radial = Vec3::new(branch.position.x, 0.0, branch.position.z);
+            assert!(radial.length() > 0.0);
+            assert!(branch.direction.dot(radial.normalize()) > 0.99);
+        }
+    }
+
+    #[test]
+    fn test_spadix_thicker_than_stem() {
+        let params = InflorescenceParams::default();
+        let stem_radius = 0.05;
+        let axis_points = vec![Vec3::ZERO, Vec3::new(0.0, params.axis_length, 0.0)];
+
+        let stem = generate_spadix_stem(&params, &axis_points, stem_radius, Vec3::ONE);
+        let max_radius = stem
+            .positions
+            .iter()
+            .map(|p| Vec2::new(p.x, p.z).length())
+            .fold(0.0, f32::max);
+
+        assert!(max_radius > 4.0 * stem_radius);
+        assert!(spadix_radius(&params, 0.5) > stem_radius);
+    }
+
+    #[test]
+    fn test_spathe_wraps_spadix_base() {
+        let params = InflorescenceParams::default();
+        let axis = straight_axis();
+        let spathe = generate_spathe(&params, &axis, SPATHE_COLOR);
+
+        assert!(spathe.validate().is_ok());
+        let (min, max) = spathe.bounding_box().unwrap();
+
+        // Starts around the spadix base and rises above the spadix tip
+        assert!(min.y < SPADIX_START * 10.0);
+        assert!(max.y > 10.0);
+        // Mostly behind the spadix
+        assert!(min.z < -1.0);
+    }
+}
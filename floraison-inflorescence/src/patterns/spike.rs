@@ -26,7 +26,7 @@ fn lerp(a: f32, b: f32, t: f32) -> f32 {
 /// Vector of branch points, each representing a flower attachment location
 ///
 /// # Pattern Characteristics
-/// - Flowers evenly spaced along axis
+/// - Flowers spaced along axis according to `internode_spacing` (even by default)
 /// - **Sessile**: Flowers attach directly to axis (pedicel length ≈ 0)
 /// - Rotation follows Fibonacci spiral (golden angle)
 /// - Age increases from bottom (oldest) to top (youngest) - indeterminate
@@ -34,15 +34,16 @@ pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) ->
     let mut branches = Vec::with_capacity(params.branch_count);
 
     for i in 0..params.branch_count {
-        // Normalize position along axis (0.0 at bottom, 1.0 at top)
-        let t = if params.branch_count > 1 {
-            i as f32 / (params.branch_count - 1) as f32
+        // Normalize rank (0.0 at bottom, 1.0 at top), then place it on the axis
+        let (t, axis_t) = if params.branch_count > 1 {
+            let t = i as f32 / (params.branch_count - 1) as f32;
+            (t, params.internode_spacing.position(t))
         } else {
-            0.5 // Single flower at middle
+            (0.5, 0.5) // Single flower at middle
         };
 
         // Sample axis to get position and Frenet frame
-        let sample = axis.sample_at_t(t);
+        let sample = axis.sample_at_t(axis_t);
 
         // Interpolate flower scale from bottom to top
         let flower_scale = lerp(params.flower_size_bottom, params.flower_size_top, t);
@@ -0,0 +1,152 @@
+//! Thyrse pattern generator
+//!
+//! Thyrse: Indeterminate main axis bearing small determinate cymes (dichasia)
+//! at each node.
+//! Blooming pattern: Mixed (each cyme blooms center-first).
+//!
+//! Examples: Grape, Horse chestnut, Lilac
+
+use floraison_core::math::curves::AxisCurve;
+use floraison_core::math::vector::rotation_between;
+use glam::{Quat, Vec3};
+
+use crate::patterns::dichasium;
+use crate::{BranchPoint, InflorescenceParams, PatternType};
+
+/// Cyme depth used when `params.recursion_depth` is None (3 flowers per node)
+pub const DEFAULT_RECURSION_DEPTH: usize = 1;
+
+/// Linear interpolation between two values
+#[inline]
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Generate branch points for a thyrse pattern
+///
+/// Each cyme is generated by the [`dichasium`] generator in its own frame,
+/// then rotated onto the lateral direction and moved to its node.
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters defining axis, branch count, angles, etc.
+/// * `axis` - The main axis curve along which cymes are arranged
+///
+/// # Returns
+/// Vector of branch points, each representing a flower attachment location
+///
+/// # Pattern Characteristics
+/// - `branch_count` nodes evenly spaced along axis, spiraling by `rotation_angle`
+/// - Each node bears a dichasial cyme of depth `params.recursion_depth` (default: 1),
+///   i.e. `2^(depth+1) - 1` flowers
+/// - Cyme peduncle length, down angle and flower size interpolate from bottom to top
+/// - `branch_ratio` and `angle_divergence` shape the cymes as for a dichasium
+/// - Ages follow each cyme's determinate order (central flower oldest)
+pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) -> Vec<BranchPoint> {
+    let cyme_depth = params.recursion_depth.unwrap_or(DEFAULT_RECURSION_DEPTH);
+
+    // Cymes grow from the top of a reference axis, then get re-oriented per node
+    let cyme_origin = Vec3::Y;
+    let cyme_axis = AxisCurve::new(vec![Vec3::ZERO, cyme_origin]);
+
+    let mut branches = Vec::new();
+
+    for i in 0..params.branch_count {
+        // Normalize position along axis (0.0 at bottom, 1.0 at top)
+        let t = if params.branch_count > 1 {
+            i as f32 / (params.branch_count - 1) as f32
+        } else {
+            0.5 // Single cyme at middle
+        };
+
+        // Sample axis to get position and Frenet frame
+        let sample = axis.sample_at_t(t);
+
+        // Interpolate parameters from bottom to top
+        let angle = lerp(params.angle_bottom, params.angle_top, t);
+        let length = lerp(params.branch_length_bottom, params.branch_length_top, t);
+        let flower_scale = lerp(params.flower_size_bottom, params.flower_size_top, t);
+
+        // Lateral direction, as for a raceme pedicel
+        let rotation = params.rotation_angle * i as f32;
+        let down_rotation = Quat::from_axis_angle(sample.binormal, -angle.to_radians());
+        let spiral_rotation = Quat::from_axis_angle(sample.tangent, rotation.to_radians());
+        let direction = (spiral_rotation * down_rotation * sample.normal).normalize();
+
+        // Small cyme: peduncle of the interpolated length, flowers sized for this node
+        let cyme_params = InflorescenceParams {
+            pattern: PatternType::Dichasium,
+            recursion_depth: Some(cyme_depth),
+            branch_length_top: length,
+            flower_size_top: flower_scale,
+            ..params.clone()
+        };
+        let cyme = dichasium::generate_branch_points(&cyme_params, &cyme_axis);
+
+        // The cyme's root branch starts at the reference origin; carry it to the node
+        let orientation = rotation_between(cyme[0].direction, direction);
+        branches.extend(cyme.into_iter().map(|branch| BranchPoint {
+            position: sample.position + orientation * (branch.position - cyme_origin),
+            direction: (orientation * branch.direction).normalize(),
+            ..branch
+        }));
+    }
+
+    branches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns::raceme;
+
+    fn straight_axis() -> AxisCurve {
+        AxisCurve::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)])
+    }
+
+    #[test]
+    fn test_thyrse_more_flowers_than_raceme() {
+        let params = InflorescenceParams {
+            branch_count: 6,
+            ..Default::default()
+        };
+
+        let thyrse = generate_branch_points(&params, &straight_axis());
+        let raceme = raceme::generate_branch_points(&params, &straight_axis());
+
+        // Default cyme depth 1: 3 flowers per node
+        assert_eq!(thyrse.len(), 6 * 3);
+        assert!(thyrse.len() > raceme.len());
+    }
+
+    #[test]
+    fn test_thyrse_cyme_depth() {
+        let params = InflorescenceParams {
+            branch_count: 4,
+            recursion_depth: Some(2),
+            ..Default::default()
+        };
+
+        // Binary cyme of depth 2: 7 flowers per node
+        assert_eq!(
+            generate_branch_points(&params, &straight_axis()).len(),
+            4 * 7
+        );
+    }
+
+    #[test]
+    fn test_thyrse_cymes_attach_to_axis() {
+        let params = InflorescenceParams {
+            branch_count: 5,
+            ..Default::default()
+        };
+
+        let branches = generate_branch_points(&params, &straight_axis());
+
+        // Each cyme's root (first of every 3) has its peduncle base on the axis
+        for root in branches.chunks(3).map(|cyme| &cyme[0]) {
+            let base = root.position - root.direction * root.length;
+            assert!(base.x.abs() < 1e-4 && base.z.abs() < 1e-4);
+            assert!((root.direction.length() - 1.0).abs() < 1e-5);
+        }
+    }
+}
@@ -8,8 +8,18 @@
 use floraison_core::math::curves::AxisCurve;
 use glam::Quat;
 
+use crate::patterns::jitter_pedicel;
 use crate::{apply_age_distribution, BranchPoint, InflorescenceParams};
 
+/// Fraction of `branch_length_top` removed from the outermost pedicel at `umbel_dome` = 1.0
+pub const UMBEL_DOME_SHORTENING: f32 = 0.5;
+
+/// Linear interpolation between two values
+#[inline]
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
 /// Generate branch points for an umbel pattern
 ///
 /// # Arguments
@@ -24,9 +34,14 @@ use crate::{apply_age_distribution, BranchPoint, InflorescenceParams};
 /// - Spread out in umbrella shape
 /// - Rotation angle determines angular spacing
 /// - Down angle determines spread
+/// - With `params.umbel_dome` > 0, branches are ranked from the centre to the
+///   rim of the head (sunflower packing: rank = sqrt((i + 0.5) / n)); central
+///   ones rise toward the axis and rim pedicels shorten by up to
+///   [`UMBEL_DOME_SHORTENING`], forming a dome
 /// - **Determinate**: All flowers same age (bloom together)
 pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) -> Vec<BranchPoint> {
     let mut branches = Vec::with_capacity(params.branch_count);
+    let dome = params.umbel_dome.clamp(0.0, 1.0);
 
     // All branches from top of axis
     let sample = axis.sample_at_t(1.0);
@@ -35,13 +50,21 @@ pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) ->
         // Rotation around axis
         let rotation = params.rotation_angle * i as f32;
 
+        // Position in the head: 0 = centre, 1 = rim
+        let rank = ((i as f32 + 0.5) / params.branch_count as f32).sqrt();
+        let angle = lerp(params.angle_top, 90.0, dome * (1.0 - rank));
+        let length = params.branch_length_top * (1.0 - UMBEL_DOME_SHORTENING * dome * rank);
+
         // Compute direction (down and rotated around axis)
-        let down_rotation = Quat::from_axis_angle(sample.binormal, -params.angle_top.to_radians());
+        let down_rotation = Quat::from_axis_angle(sample.binormal, -angle.to_radians());
         let spiral_rotation = Quat::from_axis_angle(sample.tangent, rotation.to_radians());
         let direction = (spiral_rotation * down_rotation * sample.normal).normalize();
 
+        // Natural variation
+        let (direction, length) = jitter_pedicel(params, i, direction, length);
+
         // Branch endpoint
-        let position = sample.position + direction * params.branch_length_top;
+        let position = sample.position + direction * length;
 
         // Age: determinate (all flowers same age, bloom together)
         let base_age = 1.0;
@@ -50,7 +73,7 @@ pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) ->
         branches.push(BranchPoint {
             position,
             direction,
-            length: params.branch_length_top,
+            length,
             flower_scale: params.flower_size_top,
             age,
         });
@@ -116,6 +139,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_umbel_dome() {
+        let axis = AxisCurve::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)]);
+        let params = InflorescenceParams {
+            branch_count: 12,
+            branch_length_top: 2.0,
+            angle_top: 30.0,
+            umbel_dome: 0.8,
+            ..Default::default()
+        };
+        let branches = generate_branch_points(&params, &axis);
+
+        // Every flower, including the rim, sits above the attachment point
+        for branch in &branches {
+            assert!(
+                branch.position.y > 10.0,
+                "flower at y={}",
+                branch.position.y
+            );
+        }
+
+        // Centre rises above the rim, with shorter rim pedicels
+        let (centre, rim) = (&branches[0], branches.last().unwrap());
+        assert!(centre.position.y > rim.position.y + 0.5);
+        assert!(rim.length < centre.length);
+
+        // No dome: flat, uniform head
+        let flat = generate_branch_points(
+            &InflorescenceParams {
+                umbel_dome: 0.0,
+                ..params
+            },
+            &axis,
+        );
+        for branch in &flat {
+            assert!((branch.position.y - flat[0].position.y).abs() < 1e-5);
+            assert_eq!(branch.length, 2.0);
+        }
+    }
+
     #[test]
     fn test_umbel_same_age() {
         let params = InflorescenceParams {
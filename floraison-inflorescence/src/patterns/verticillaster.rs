@@ -0,0 +1,166 @@
+//! Verticillaster pattern generator
+//!
+//! Verticillaster: Dense false whorls of flowers at nodes up the axis.
+//! Blooming pattern: Indeterminate (bottom whorls bloom first).
+//!
+//! Examples: Mint, Sage, Motherwort
+
+use floraison_core::math::curves::AxisCurve;
+use glam::Quat;
+
+use crate::{apply_age_distribution, BranchPoint, InflorescenceParams};
+
+/// Linear interpolation between two values
+#[inline]
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Generate branch points for a verticillaster pattern
+///
+/// # Arguments
+/// * `params` - Inflorescence parameters defining axis, branch count, angles, etc.
+/// * `axis` - The main axis curve along which whorls are arranged
+///
+/// # Returns
+/// Vector of branch points, each representing a flower attachment location
+///
+/// # Pattern Characteristics
+/// - `params.flowers_per_node` flowers (default: 6) evenly spaced in a ring at each node
+/// - Node count = `branch_count / flowers_per_node` (at least 1), so the total
+///   flower count is rounded down to whole whorls
+/// - Nodes evenly spaced along axis
+/// - Successive whorls rotated by `rotation_angle`
+/// - Pedicel length, down angle and flower size interpolate from bottom to top
+/// - Age decreases from bottom whorl (oldest) to top whorl (youngest) - indeterminate
+pub fn generate_branch_points(params: &InflorescenceParams, axis: &AxisCurve) -> Vec<BranchPoint> {
+    let per_node = params.flowers_per_node.unwrap_or(6).max(1);
+    let node_count = (params.branch_count / per_node).max(1);
+
+    let mut branches = Vec::with_capacity(node_count * per_node);
+
+    for node in 0..node_count {
+        // Normalize node position along axis (0.0 at bottom, 1.0 at top)
+        let t = if node_count > 1 {
+            node as f32 / (node_count - 1) as f32
+        } else {
+            0.5 // Single whorl at middle
+        };
+
+        // Sample axis to get position and Frenet frame
+        let sample = axis.sample_at_t(t);
+
+        // Interpolate parameters from bottom to top (shared by the whole whorl)
+        let angle = lerp(params.angle_bottom, params.angle_top, t);
+        let length = lerp(params.branch_length_bottom, params.branch_length_top, t);
+        let flower_scale = lerp(params.flower_size_bottom, params.flower_size_top, t);
+
+        // Age: indeterminate (bottom whorl = oldest = 1.0, top whorl = youngest = 0.0)
+        let age = apply_age_distribution(1.0 - t, params.age_distribution);
+
+        // Tilt away from the axis once per whorl
+        let down_rotation = Quat::from_axis_angle(sample.binormal, -angle.to_radians());
+
+        for k in 0..per_node {
+            // Even ring, with the whole whorl rotated relative to the one below
+            let rotation = params.rotation_angle * node as f32 + 360.0 * k as f32 / per_node as f32;
+            let spiral_rotation = Quat::from_axis_angle(sample.tangent, rotation.to_radians());
+            let direction = (spiral_rotation * down_rotation * sample.normal).normalize();
+
+            branches.push(BranchPoint {
+                position: sample.position + direction * length,
+                direction,
+                length,
+                flower_scale,
+                age,
+            });
+        }
+    }
+
+    branches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use floraison_core::Vec3;
+
+    fn straight_axis() -> AxisCurve {
+        AxisCurve::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)])
+    }
+
+    #[test]
+    fn test_verticillaster_counts() {
+        let params = InflorescenceParams {
+            branch_count: 24,
+            flowers_per_node: Some(6),
+            ..Default::default()
+        };
+
+        let branches = generate_branch_points(&params, &straight_axis());
+        assert_eq!(branches.len(), 24);
+
+        // Partial whorls are dropped
+        let params = InflorescenceParams {
+            branch_count: 26,
+            flowers_per_node: Some(6),
+            ..Default::default()
+        };
+        assert_eq!(generate_branch_points(&params, &straight_axis()).len(), 24);
+    }
+
+    #[test]
+    fn test_verticillaster_discrete_heights() {
+        let params = InflorescenceParams {
+            branch_count: 20,
+            flowers_per_node: Some(5),
+            ..Default::default()
+        };
+
+        let branches = generate_branch_points(&params, &straight_axis());
+
+        // Group attachment heights (pedicel bases on the axis)
+        let mut heights: Vec<f32> = Vec::new();
+        for branch in &branches {
+            let base = branch.position - branch.direction * branch.length;
+            if !heights.iter().any(|h| (h - base.y).abs() < 1e-3) {
+                heights.push(base.y);
+            }
+        }
+
+        // 4 nodes, each shared by 5 flowers
+        assert_eq!(heights.len(), 4);
+        for chunk in branches.chunks(5) {
+            let base_y = chunk[0].position.y - chunk[0].direction.y * chunk[0].length;
+            for branch in chunk {
+                let y = branch.position.y - branch.direction.y * branch.length;
+                assert!((y - base_y).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_verticillaster_whorl_rotation() {
+        let params = InflorescenceParams {
+            branch_count: 8,
+            flowers_per_node: Some(4),
+            rotation_angle: 45.0,
+            angle_top: 0.0,
+            angle_bottom: 0.0,
+            ..Default::default()
+        };
+
+        let branches = generate_branch_points(&params, &straight_axis());
+
+        // Horizontal pedicels: compare azimuths of the first flower of each whorl
+        let azimuth = |b: &BranchPoint| b.direction.z.atan2(b.direction.x);
+        let delta = (azimuth(&branches[4]) - azimuth(&branches[0])).abs();
+        let delta = delta.min(std::f32::consts::TAU - delta);
+        assert!((delta - 45f32.to_radians()).abs() < 1e-3);
+
+        // Flowers within a whorl are a quarter turn apart
+        let within = (azimuth(&branches[1]) - azimuth(&branches[0])).abs();
+        let within = within.min(std::f32::consts::TAU - within);
+        assert!((within - std::f32::consts::FRAC_PI_2).abs() < 1e-3);
+    }
+}
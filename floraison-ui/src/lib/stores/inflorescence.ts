@@ -11,8 +11,13 @@ export type PatternType =
 	| 'Spike'
 	| 'Umbel'
 	| 'Corymb'
+	| 'Panicle'
+	| 'Verticillaster'
+	| 'Catkin'
+	| 'Spadix'
 	| 'Dichasium'
 	| 'Drepanium'
+	| 'Thyrse'
 	| 'CompoundRaceme'
 	| 'CompoundUmbel';
 
@@ -82,6 +87,8 @@ export function isRecursivePattern(pattern: PatternType): boolean {
 	return (
 		pattern === 'Dichasium' ||
 		pattern === 'Drepanium' ||
+		pattern === 'Panicle' ||
+		pattern === 'Thyrse' ||
 		pattern === 'CompoundRaceme' ||
 		pattern === 'CompoundUmbel'
 	);
@@ -108,6 +115,18 @@ export function getRecursiveDefaults(pattern: PatternType): {
 				branch_ratio: 0.8,
 				angle_divergence: 137.5 // Uses rotation_angle by default, but can override
 			};
+		case 'Panicle':
+			return {
+				recursion_depth: 2,
+				branch_ratio: 0.5,
+				angle_divergence: 40.0
+			};
+		case 'Thyrse':
+			return {
+				recursion_depth: 1,
+				branch_ratio: 0.7,
+				angle_divergence: 30.0
+			};
 		case 'CompoundRaceme':
 			return {
 				recursion_depth: 1,
@@ -194,8 +194,13 @@ function randomInflorescenceParams(): InflorescenceParams {
 		'Spike',
 		'Umbel',
 		'Corymb',
+		'Panicle',
+		'Verticillaster',
+		'Catkin',
+		'Spadix',
 		'Dichasium',
 		'Drepanium',
+		'Thyrse',
 		'CompoundRaceme',
 		'CompoundUmbel'
 	];
@@ -204,6 +209,8 @@ function randomInflorescenceParams(): InflorescenceParams {
 	const isRecursive =
 		pattern === 'Dichasium' ||
 		pattern === 'Drepanium' ||
+		pattern === 'Panicle' ||
+		pattern === 'Thyrse' ||
 		pattern === 'CompoundRaceme' ||
 		pattern === 'CompoundUmbel';
 
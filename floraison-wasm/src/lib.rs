@@ -3,9 +3,20 @@
 //! WebAssembly bindings for the Floraison flower generator.
 //! Exposes the Rust implementation to JavaScript/TypeScript.
 
-use floraison_components::assembly::{generate_flower, FlowerParams};
-use floraison_core::geometry::mesh::Mesh;
-use floraison_inflorescence::{aging::FlowerAging, assembly, InflorescenceParams};
+use floraison_components::assembly::{
+    generate_flower, generate_flower_seeded, generate_flower_with_ranges, ComponentType,
+    FlowerParams,
+};
+use floraison_components::petal::{self, PetalParams};
+use floraison_core::geometry::{gltf::mesh_to_glb, mesh::Mesh, obj::mesh_to_obj};
+use floraison_inflorescence::{
+    aging::{FlowerAging, FlowerStage},
+    assembly, patterns, InflorescenceParams,
+};
+use serde::{Deserialize, Serialize};
+use std::cell::{OnceCell, RefCell};
+use std::collections::HashMap;
+use std::ops::Range;
 use wasm_bindgen::prelude::*;
 
 /// Initialize the WASM module
@@ -18,7 +29,11 @@ pub fn init() {
 /// Flower generator exposed to JavaScript
 #[wasm_bindgen]
 pub struct FlowerGenerator {
-    // Internal state will be added as we implement features
+    /// Stage flowers from the last `generate_inflorescence` call, reused while
+    /// the flower JSON and stage transforms stay the same
+    stage_cache: Option<StageCache>,
+    /// Number of times stage flowers have been generated (cache misses)
+    stage_generations: usize,
 }
 
 impl Default for FlowerGenerator {
@@ -27,28 +42,229 @@ impl Default for FlowerGenerator {
     }
 }
 
+impl FlowerGenerator {
+    /// Parse inflorescence and flower JSON and build the inflorescence mesh,
+    /// reusing cached stage flowers when possible
+    fn inflorescence_from_json(
+        &mut self,
+        inflo_params_json: &str,
+        flower_params_json: &str,
+    ) -> Result<Mesh, JsValue> {
+        // Parse inflorescence parameters
+        let inflo_params: InflorescenceParams =
+            serde_json::from_str(inflo_params_json).map_err(|e| {
+                JsValue::from_str(&format!("Failed to parse inflorescence parameters: {}", e))
+            })?;
+        let options: InflorescenceOptions =
+            serde_json::from_str(inflo_params_json).map_err(|e| {
+                JsValue::from_str(&format!("Failed to parse inflorescence options: {}", e))
+            })?;
+
+        // Reuse the stage flowers unless the flower or its stage transforms changed
+        let transforms = (options.aging.bud(), options.aging.wilt());
+        let cached = self.stage_cache.as_ref().is_some_and(|cache| {
+            cache.flower_params_json == flower_params_json && cache.transforms == transforms
+        });
+        if !cached {
+            let flower_params = parse_flower_params(flower_params_json)?;
+            self.stage_cache = Some(StageCache {
+                flower_params_json: flower_params_json.to_string(),
+                transforms,
+                stages: FlowerStages::new(&flower_params, &transforms.0, &transforms.1),
+            });
+            self.stage_generations += 1;
+        }
+        let stages = &self.stage_cache.as_ref().unwrap().stages;
+
+        let stem_color = floraison_core::Vec3::from_array(options.stem_color);
+        Ok(inflorescence_mesh(&inflo_params, stages, stem_color))
+    }
+}
+
 #[wasm_bindgen]
 impl FlowerGenerator {
     /// Create a new flower generator
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
-        Self {}
+        Self {
+            stage_cache: None,
+            stage_generations: 0,
+        }
     }
 
     /// Generate a flower from JSON parameters
     /// Returns mesh data that can be used to create Three.js geometry
     pub fn generate_flower(&self, params_json: &str) -> Result<MeshData, JsValue> {
-        // Parse JSON parameters
-        let params: FlowerParams = serde_json::from_str(params_json)
-            .map_err(|e| JsValue::from_str(&format!("Failed to parse parameters: {}", e)))?;
-
-        // Generate flower mesh
-        let mesh = generate_flower(&params);
+        // Parse JSON parameters and generate flower mesh
+        let mesh = flower_mesh_from_json(params_json)?;
 
         // Convert to WASM mesh data
         Ok(MeshData::from_mesh(&mesh))
     }
 
+    /// Generate a flower from JSON parameters as non-indexed mesh data
+    ///
+    /// Every triangle corner gets its own vertex (triangle soup) and
+    /// `is_indexed()` is false, for WebGL setups that cannot use index buffers.
+    pub fn generate_flower_nonindexed(&self, params_json: &str) -> Result<MeshData, JsValue> {
+        let mesh = flower_mesh_from_json(params_json)?;
+        Ok(MeshData::from_mesh_nonindexed(&mesh))
+    }
+
+    /// Check flower parameters without generating anything
+    ///
+    /// Deserializes the JSON and runs range checks (resolutions and segment
+    /// counts ≥ 2, lengths and sizes > 0), so forms can report problems before
+    /// generation panics or produces a degenerate mesh.
+    ///
+    /// # Arguments
+    /// * `params_json` - JSON string containing FlowerParams
+    ///
+    /// # Returns
+    /// Ok if the parameters are usable, otherwise an error listing every
+    /// violation, one per line
+    pub fn validate_params(&self, params_json: &str) -> Result<(), JsValue> {
+        let params = parse_flower_params(params_json)?;
+        violations_to_result(flower_param_violations(&params))
+    }
+
+    /// Check inflorescence parameters without generating anything
+    ///
+    /// Like `validate_params`, for the inflorescence JSON (lengths > 0,
+    /// `branch_count` ≥ 1, `age_distribution` in [0, 1], ...).
+    ///
+    /// # Arguments
+    /// * `inflo_params_json` - JSON string containing InflorescenceParams
+    ///
+    /// # Returns
+    /// Ok if the parameters are usable, otherwise an error listing every
+    /// violation, one per line
+    pub fn validate_inflorescence_params(&self, inflo_params_json: &str) -> Result<(), JsValue> {
+        let inflo_params: InflorescenceParams =
+            serde_json::from_str(inflo_params_json).map_err(|e| {
+                JsValue::from_str(&format!("Failed to parse inflorescence parameters: {}", e))
+            })?;
+        violations_to_result(inflorescence_param_violations(&inflo_params))
+    }
+
+    /// Generate several flowers in one call
+    ///
+    /// Parsing and generation happen entirely on the Rust side, avoiding a
+    /// JS/WASM round trip per flower (e.g. for grid previews).
+    ///
+    /// # Arguments
+    /// * `params_json_array` - JSON array of FlowerParams objects
+    ///
+    /// # Returns
+    /// Array of MeshData, in the same order as the input. If any element
+    /// fails to parse, the error names its index and nothing is generated.
+    pub fn generate_many(&self, params_json_array: &str) -> Result<js_sys::Array, JsValue> {
+        let meshes =
+            flower_meshes_from_json_array(params_json_array).map_err(|e| JsValue::from_str(&e))?;
+
+        Ok(meshes
+            .iter()
+            .map(|mesh| JsValue::from(MeshData::from_mesh(mesh)))
+            .collect())
+    }
+
+    /// Generate a flower from JSON parameters with an explicit jitter seed
+    ///
+    /// The same parameters and seed always produce identical geometry, so a
+    /// seed can be shared to reproduce a jittered flower. The seed overrides
+    /// both `diagram.jitter_seed` and any `seed` in the JSON; it has no visible
+    /// effect unless the diagram has jitter enabled.
+    ///
+    /// # Arguments
+    /// * `params_json` - JSON string containing FlowerParams
+    /// * `seed` - Jitter seed
+    pub fn generate_flower_with_seed(
+        &self,
+        params_json: &str,
+        seed: u32,
+    ) -> Result<MeshData, JsValue> {
+        let mut params = parse_flower_params(params_json)?;
+        params.diagram.jitter_seed = seed as u64;
+        Ok(MeshData::from_mesh(&generate_flower(&params)))
+    }
+
+    /// Generate a flower from JSON parameters and export it as Wavefront OBJ text
+    ///
+    /// Vertex colors are appended to the `v` lines. The string can be saved
+    /// directly as a `.obj` file.
+    pub fn export_obj(&self, params_json: &str) -> Result<String, JsValue> {
+        let mesh = flower_mesh_from_json(params_json)?;
+        Ok(mesh_to_obj(&mesh))
+    }
+
+    /// Generate a flower from JSON parameters and export it as binary glTF
+    ///
+    /// The bytes can be saved directly as a `.glb` file.
+    pub fn export_glb(&self, params_json: &str) -> Result<js_sys::Uint8Array, JsValue> {
+        let mesh = flower_mesh_from_json(params_json)?;
+        Ok(js_sys::Uint8Array::from(&mesh_to_glb(&mesh)[..]))
+    }
+
+    /// Generate a flower from JSON parameters, including per-component index ranges
+    ///
+    /// The returned mesh data exposes `component_ranges()`, a JSON array parallel to
+    /// the component instances in the mesh, e.g.
+    /// `[{"component":"Receptacle","start":0,"end":576}, ...]`.
+    /// `start`/`end` are offsets into the index buffer, suitable for draw groups.
+    pub fn generate_flower_with_ranges(&self, params_json: &str) -> Result<MeshData, JsValue> {
+        let params = parse_flower_params(params_json)?;
+
+        let (mesh, ranges) = generate_flower_with_ranges(&params);
+
+        let ranges: Vec<ComponentRange> = ranges
+            .into_iter()
+            .map(|(component, range)| ComponentRange {
+                component,
+                start: range.start,
+                end: range.end,
+            })
+            .collect();
+        let ranges_json = serde_json::to_string(&ranges)
+            .map_err(|e| JsValue::from_str(&format!("Failed to serialize ranges: {}", e)))?;
+
+        let mut data = MeshData::from_mesh(&mesh);
+        data.component_ranges = ranges_json;
+        Ok(data)
+    }
+
+    /// Generate a flower from JSON parameters, labeling every vertex with its component
+    ///
+    /// The returned mesh data exposes `component_ids()`, a Uint8Array parallel to
+    /// the vertices holding each vertex's `ComponentType` (receptacle = 0,
+    /// pistil = 1, stamen = 2, petal = 3, sepal = 4). After a raycast, the id of
+    /// any vertex of the hit face tells which component was clicked.
+    pub fn generate_flower_tagged(&self, params_json: &str) -> Result<MeshData, JsValue> {
+        let params = parse_flower_params(params_json)?;
+        Ok(tagged_flower_data(&params))
+    }
+
+    /// Get the deformed B-spline control grid of a petal from JSON `PetalParams`
+    ///
+    /// Returns control points as a flat Float32Array with stride 3, laid out
+    /// row-major: 9 rows (base to tip) of 5 columns (left to right edge).
+    /// Useful for drawing the control cage alongside the petal mesh.
+    pub fn petal_control_grid(
+        &self,
+        petal_params_json: &str,
+    ) -> Result<js_sys::Float32Array, JsValue> {
+        let params: PetalParams = serde_json::from_str(petal_params_json)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse petal parameters: {}", e)))?;
+
+        let (_, grid) = petal::generate_with_grid(&params);
+        let points: Vec<f32> = grid
+            .iter()
+            .flatten()
+            .flat_map(|v| [v.x, v.y, v.z])
+            .collect();
+
+        Ok(js_sys::Float32Array::from(&points[..]))
+    }
+
     /// Generate a lily flower with default parameters
     pub fn generate_lily(&self) -> Result<MeshData, JsValue> {
         let params = FlowerParams::lily();
@@ -70,8 +286,41 @@ impl FlowerGenerator {
         Ok(MeshData::from_mesh(&mesh))
     }
 
+    /// Generate a tulip flower with default parameters
+    pub fn generate_tulip(&self) -> Result<MeshData, JsValue> {
+        let params = FlowerParams::tulip();
+        let mesh = generate_flower(&params);
+        Ok(MeshData::from_mesh(&mesh))
+    }
+
+    /// Generate an orchid flower with default parameters
+    pub fn generate_orchid(&self) -> Result<MeshData, JsValue> {
+        let params = FlowerParams::orchid();
+        let mesh = generate_flower(&params);
+        Ok(MeshData::from_mesh(&mesh))
+    }
+
+    /// Generate a poppy flower with default parameters
+    pub fn generate_poppy(&self) -> Result<MeshData, JsValue> {
+        let params = FlowerParams::poppy();
+        let mesh = generate_flower(&params);
+        Ok(MeshData::from_mesh(&mesh))
+    }
+
     /// Generate an inflorescence (multi-flower structure) from JSON parameters
     ///
+    /// Besides the `InflorescenceParams` fields, the inflorescence JSON may
+    /// contain an optional `stem_color` (`[r, g, b]`, default green) and an
+    /// optional `aging` object tuning how bud and wilt flowers are
+    /// derived from the bloom parameters, e.g.
+    /// `{"aging": {"bud": {"petal_scale": 0.3}, "wilt": {"color_factor": 0.6}}}`.
+    /// Each stage accepts `petal_scale`, `base_width_scale`, `curl_scale`,
+    /// `curl_delta`, `droop_delta`, `twist_scale` and `color_factor`; omitted
+    /// values keep their defaults (see `StageTransform::BUD`/`StageTransform::WILT`).
+    ///
+    /// The bud/bloom/wilt flower meshes are cached: when the flower JSON and
+    /// stage transforms match the previous call, only the layout is rebuilt.
+    ///
     /// # Arguments
     /// * `inflo_params_json` - JSON string containing InflorescenceParams
     /// * `flower_params_json` - JSON string containing FlowerParams for individual flowers
@@ -79,63 +328,499 @@ impl FlowerGenerator {
     /// # Returns
     /// Mesh data for the complete inflorescence structure
     pub fn generate_inflorescence(
-        &self,
+        &mut self,
         inflo_params_json: &str,
         flower_params_json: &str,
     ) -> Result<MeshData, JsValue> {
-        // Parse inflorescence parameters
+        let mesh = self.inflorescence_from_json(inflo_params_json, flower_params_json)?;
+
+        // Convert to WASM mesh data
+        Ok(MeshData::from_mesh(&mesh))
+    }
+
+    /// Generate an inflorescence as non-indexed mesh data (triangle soup)
+    ///
+    /// Same as `generate_inflorescence`, but every triangle corner gets its
+    /// own vertex and `is_indexed()` is false, for WebGL setups that cannot
+    /// use index buffers.
+    ///
+    /// # Arguments
+    /// * `inflo_params_json` - JSON string containing InflorescenceParams
+    /// * `flower_params_json` - JSON string containing FlowerParams for individual flowers
+    pub fn generate_inflorescence_nonindexed(
+        &mut self,
+        inflo_params_json: &str,
+        flower_params_json: &str,
+    ) -> Result<MeshData, JsValue> {
+        let mesh = self.inflorescence_from_json(inflo_params_json, flower_params_json)?;
+        Ok(MeshData::from_mesh_nonindexed(&mesh))
+    }
+
+    /// Count the flowers an inflorescence will produce, without generating geometry
+    ///
+    /// Accounts for the `max_flowers` cap applied during generation.
+    ///
+    /// # Arguments
+    /// * `inflo_params_json` - JSON string containing InflorescenceParams
+    ///
+    /// # Returns
+    /// Number of flowers in the assembled inflorescence
+    pub fn count_inflorescence_flowers(&self, inflo_params_json: &str) -> Result<usize, JsValue> {
         let inflo_params: InflorescenceParams =
             serde_json::from_str(inflo_params_json).map_err(|e| {
                 JsValue::from_str(&format!("Failed to parse inflorescence parameters: {}", e))
             })?;
 
-        // Parse flower parameters
-        let flower_params: FlowerParams = serde_json::from_str(flower_params_json)
-            .map_err(|e| JsValue::from_str(&format!("Failed to parse flower parameters: {}", e)))?;
+        Ok(patterns::count_branch_points(
+            &patterns::limit_flower_count(&inflo_params),
+        ))
+    }
+}
+
+/// Wasm-only options read from the flower JSON alongside `FlowerParams`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct FlowerOptions {
+    /// Jitter seed overriding `diagram.jitter_seed`, for shareable flowers
+    seed: Option<u32>,
+}
+
+/// Deserialize `FlowerParams` from a JSON value
+///
+/// An optional top-level `seed` replaces `diagram.jitter_seed`.
+fn flower_params_from_value(value: &serde_json::Value) -> serde_json::Result<FlowerParams> {
+    let mut params = FlowerParams::deserialize(value)?;
+    let options = FlowerOptions::deserialize(value)?;
+
+    if let Some(seed) = options.seed {
+        params.diagram.jitter_seed = seed as u64;
+    }
+    Ok(params)
+}
+
+/// Parse JSON `FlowerParams`, as accepted by the flower generation methods
+fn parse_flower_params(params_json: &str) -> Result<FlowerParams, JsValue> {
+    serde_json::from_str(params_json)
+        .and_then(|value| flower_params_from_value(&value))
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse parameters: {}", e)))
+}
+
+/// Collects range violations as human-readable messages
+#[derive(Debug, Default)]
+struct Violations(Vec<String>);
+
+impl Violations {
+    /// Require `value > 0`
+    fn positive(&mut self, name: &str, value: f32) {
+        if value.is_nan() || value <= 0.0 {
+            self.0.push(format!("{} must be > 0 (got {})", name, value));
+        }
+    }
+
+    /// Require `value >= 0`
+    fn non_negative(&mut self, name: &str, value: f32) {
+        if value.is_nan() || value < 0.0 {
+            self.0
+                .push(format!("{} must be >= 0 (got {})", name, value));
+        }
+    }
+
+    /// Require `value` in `[0, 1]`
+    fn unit(&mut self, name: &str, value: f32) {
+        if !(0.0..=1.0).contains(&value) {
+            self.0
+                .push(format!("{} must be in [0, 1] (got {})", name, value));
+        }
+    }
+
+    /// Require `value >= min`
+    fn at_least(&mut self, name: &str, value: usize, min: usize) {
+        if value < min {
+            self.0
+                .push(format!("{} must be >= {} (got {})", name, min, value));
+        }
+    }
+
+    /// Require an optional per-component list to have one entry per component
+    fn per_component(&mut self, name: &str, values: Option<&Vec<f32>>, count: usize) {
+        if let Some(values) = values.filter(|values| values.len() != count) {
+            self.0.push(format!(
+                "{} must have {} entries, one per component (got {})",
+                name,
+                count,
+                values.len()
+            ));
+        }
+    }
+}
+
+/// Range checks for flower parameters
+///
+/// # Returns
+/// One message per violation (empty if the parameters are valid)
+fn flower_param_violations(params: &FlowerParams) -> Vec<String> {
+    let mut v = Violations::default();
+
+    let receptacle = &params.receptacle;
+    v.positive("receptacle.height", receptacle.height);
+    v.non_negative("receptacle.base_radius", receptacle.base_radius);
+    v.at_least("receptacle.segments", receptacle.segments, 2);
+    v.at_least("receptacle.profile_samples", receptacle.profile_samples, 2);
+
+    let pistil = &params.pistil;
+    v.positive("pistil.length", pistil.length);
+    v.positive("pistil.base_radius", pistil.base_radius);
+    v.at_least("pistil.segments", pistil.segments, 2);
+
+    let stamen = &params.stamen;
+    v.positive("stamen.filament_length", stamen.filament_length);
+    v.positive("stamen.filament_radius", stamen.filament_radius);
+    v.positive("stamen.anther_length", stamen.anther_length);
+    v.at_least("stamen.segments", stamen.segments, 2);
+
+    let petal = &params.petal;
+    v.positive("petal.length", petal.length);
+    v.positive("petal.width", petal.width);
+    v.non_negative("petal.base_width", petal.base_width);
+    v.at_least("petal.resolution", petal.resolution, 2);
+    v.unit("petal.tip_lobe_depth", petal.tip_lobe_depth);
+
+    let diagram = &params.diagram;
+    for (kind, whorls) in [
+        ("petal_whorls", &diagram.petal_whorls),
+        ("stamen_whorls", &diagram.stamen_whorls),
+        ("pistil_whorls", &diagram.pistil_whorls),
+        ("sepal_whorls", &diagram.sepal_whorls),
+    ] {
+        for (i, whorl) in whorls.iter().enumerate() {
+            let name = |field| format!("diagram.{}[{}].{}", kind, i, field);
+            v.per_component(
+                &name("angle_overrides"),
+                whorl.angle_overrides.as_ref(),
+                whorl.count,
+            );
+            v.per_component(
+                &name("scale_overrides"),
+                whorl.scale_overrides.as_ref(),
+                whorl.count,
+            );
+        }
+    }
+
+    v.0
+}
+
+/// Range checks for inflorescence parameters
+///
+/// # Returns
+/// One message per violation (empty if the parameters are valid)
+fn inflorescence_param_violations(params: &InflorescenceParams) -> Vec<String> {
+    let mut v = Violations::default();
+
+    v.positive("axis_length", params.axis_length);
+    v.at_least("branch_count", params.branch_count, 1);
+    v.non_negative("branch_length_top", params.branch_length_top);
+    v.non_negative("branch_length_bottom", params.branch_length_bottom);
+    v.positive("flower_size_top", params.flower_size_top);
+    v.positive("flower_size_bottom", params.flower_size_bottom);
+    v.unit("age_distribution", params.age_distribution);
+    v.positive("stem_radius", params.stem_radius);
+    v.at_least("stem_segments", params.stem_segments, 3);
+
+    v.0
+}
+
+/// Turn a violation list into a JS result
+fn violations_to_result(violations: Vec<String>) -> Result<(), JsValue> {
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(JsValue::from_str(&format!(
+            "Invalid parameters:\n{}",
+            violations.join("\n")
+        )))
+    }
+}
+
+/// Generate a flower and convert it to mesh data with per-vertex component ids
+fn tagged_flower_data(params: &FlowerParams) -> MeshData {
+    let (mesh, ranges) = generate_flower_with_ranges(params);
+    let mut data = MeshData::from_mesh(&mesh);
+    data.component_ids = vertex_component_ids(&mesh, &ranges);
+    data
+}
+
+/// Label every vertex with the component owning its triangles
+///
+/// Components never share vertices after merging, so each vertex gets
+/// exactly one label. Vertices referenced by no triangle keep 0.
+fn vertex_component_ids(mesh: &Mesh, ranges: &[(ComponentType, Range<u32>)]) -> Vec<u8> {
+    let mut ids = vec![0; mesh.vertex_count()];
+    for (component, range) in ranges {
+        let indices = &mesh.indices[range.start as usize..range.end as usize];
+        for &index in indices {
+            ids[index as usize] = *component as u8;
+        }
+    }
+    ids
+}
+
+/// Parse a JSON array of `FlowerParams` and generate one mesh per element
+///
+/// All elements are parsed before any mesh is generated; the error message
+/// names the first element that failed.
+fn flower_meshes_from_json_array(params_json_array: &str) -> Result<Vec<Mesh>, String> {
+    let values: Vec<serde_json::Value> = serde_json::from_str(params_json_array)
+        .map_err(|e| format!("Failed to parse parameter array: {}", e))?;
+
+    let params = values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            flower_params_from_value(value)
+                .map_err(|e| format!("Failed to parse parameters at index {}: {}", index, e))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(params.iter().map(generate_flower).collect())
+}
 
+/// Parse JSON `FlowerParams` and generate the flower mesh
+fn flower_mesh_from_json(params_json: &str) -> Result<Mesh, JsValue> {
+    let params = parse_flower_params(params_json)?;
+    Ok(generate_flower(&params))
+}
+
+/// Cached stage flowers with the inputs they were generated from
+struct StageCache {
+    flower_params_json: String,
+    transforms: (StageTransform, StageTransform),
+    stages: FlowerStages,
+}
+
+/// Bud, bloom and wilt flower parameters with their meshes
+struct FlowerStages {
+    bud_params: FlowerParams,
+    bloom_params: FlowerParams,
+    wilt_params: FlowerParams,
+    aging: FlowerAging,
+    /// Jittered flowers by stage and branch index, generated on first use
+    jittered: RefCell<HashMap<(FlowerStage, usize), Mesh>>,
+}
+
+impl FlowerStages {
+    /// Derive the stage parameters from `flower_params` and generate their meshes
+    fn new(flower_params: &FlowerParams, bud: &StageTransform, wilt: &StageTransform) -> Self {
         // Generate distinct meshes for each age stage
-        let bloom_params = create_bloom_params(&flower_params);
-        let bud_params = create_bud_params(&flower_params);
-        let wilt_params = create_wilt_params(&flower_params);
+        let bloom_params = create_bloom_params(flower_params);
+        let bud_params = create_bud_params(flower_params, bud);
+        let wilt_params = create_wilt_params(flower_params, wilt);
 
         let bud_mesh = generate_flower(&bud_params);
         let bloom_mesh = generate_flower(&bloom_params);
         let wilt_mesh = generate_flower(&wilt_params);
 
-        // Create aging struct with stage-specific meshes
-        let aging = FlowerAging {
-            bud_mesh,
-            bloom_mesh,
-            wilt_mesh: Some(wilt_mesh),
+        Self {
+            bud_params,
+            bloom_params,
+            wilt_params,
+            // Create aging struct with stage-specific meshes
+            aging: FlowerAging::with_wilt(bud_mesh, bloom_mesh, wilt_mesh),
+            jittered: RefCell::default(),
+        }
+    }
+
+    /// Flower parameters of the stage [`FlowerAging::select_mesh`] picks for `age`
+    ///
+    /// # Returns
+    /// `None` for the fruit stage, which has no flower parameters
+    fn params_for(&self, age: f32) -> Option<&FlowerParams> {
+        match self.aging.stage(age) {
+            FlowerStage::Bud => Some(&self.bud_params),
+            FlowerStage::Bloom => Some(&self.bloom_params),
+            FlowerStage::Wilt => Some(&self.wilt_params),
+            FlowerStage::Fruit => None,
+        }
+    }
+
+    /// Stage flower for a branch, jittered with the branch index as seed offset
+    ///
+    /// Jittered flowers are generated once per stage and branch index and
+    /// reused by later inflorescences built from these stages. Stages
+    /// without flower parameters (fruit) use the cached stage mesh.
+    fn jittered_flower(&self, index: usize, age: f32) -> Mesh {
+        let Some(params) = self.params_for(age) else {
+            return self.aging.select_mesh(age).clone();
         };
 
-        // Stem color (green)
-        let stem_color = floraison_core::Vec3::new(0.3, 0.6, 0.3);
+        self.jittered
+            .borrow_mut()
+            .entry((self.aging.stage(age), index))
+            .or_insert_with(|| generate_flower_seeded(params, index as u64))
+            .clone()
+    }
+}
 
-        // Generate inflorescence mesh
-        let inflo_mesh =
-            assembly::assemble_inflorescence_with_aging(&inflo_params, &aging, stem_color);
+/// Build the inflorescence mesh with bud/bloom/wilt flowers
+fn inflorescence_mesh(
+    inflo_params: &InflorescenceParams,
+    stages: &FlowerStages,
+    stem_color: floraison_core::Vec3,
+) -> Mesh {
+    let aging = &stages.aging;
 
-        // Convert to WASM mesh data
-        Ok(MeshData::from_mesh(&inflo_mesh))
+    // Generate inflorescence mesh. With jitter enabled, every flower gets
+    // its branch index as seed offset so no two flowers look identical.
+    if stages.bloom_params.diagram.has_jitter() {
+        assembly::assemble_inflorescence_with_flowers(
+            inflo_params,
+            &aging.bloom_mesh,
+            stem_color,
+            |index, branch| stages.jittered_flower(index, branch.age),
+        )
+    } else {
+        assembly::assemble_inflorescence_with_aging(inflo_params, aging, stem_color)
+    }
+}
+
+/// Stem color used when the inflorescence JSON has no `stem_color`
+const DEFAULT_STEM_COLOR: [f32; 3] = [0.3, 0.6, 0.3];
+
+/// Wasm-only options read from the inflorescence JSON alongside `InflorescenceParams`
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct InflorescenceOptions {
+    /// RGB stem color
+    stem_color: [f32; 3],
+    /// Per-stage overrides of the bud/wilt parameter transforms
+    aging: AgingOverrides,
+}
+
+impl Default for InflorescenceOptions {
+    fn default() -> Self {
+        Self {
+            stem_color: DEFAULT_STEM_COLOR,
+            aging: AgingOverrides::default(),
+        }
+    }
+}
+
+/// Optional overrides for the bud and wilt stage transforms
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct AgingOverrides {
+    bud: StageOverrides,
+    wilt: StageOverrides,
+}
+
+impl AgingOverrides {
+    /// Bud transform with overrides applied
+    fn bud(&self) -> StageTransform {
+        self.bud.resolve(StageTransform::BUD)
+    }
+
+    /// Wilt transform with overrides applied
+    fn wilt(&self) -> StageTransform {
+        self.wilt.resolve(StageTransform::WILT)
+    }
+}
+
+/// Stage transform fields supplied in JSON (None = keep the stage default)
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct StageOverrides {
+    petal_scale: Option<f32>,
+    base_width_scale: Option<f32>,
+    curl_scale: Option<f32>,
+    curl_delta: Option<f32>,
+    droop_delta: Option<f32>,
+    twist_scale: Option<f32>,
+    color_factor: Option<f32>,
+}
+
+impl StageOverrides {
+    /// Fill unspecified fields from `defaults`
+    fn resolve(&self, defaults: StageTransform) -> StageTransform {
+        StageTransform {
+            petal_scale: self.petal_scale.unwrap_or(defaults.petal_scale),
+            base_width_scale: self.base_width_scale.unwrap_or(defaults.base_width_scale),
+            curl_scale: self.curl_scale.unwrap_or(defaults.curl_scale),
+            curl_delta: self.curl_delta.unwrap_or(defaults.curl_delta),
+            droop_delta: self.droop_delta.unwrap_or(defaults.droop_delta),
+            twist_scale: self.twist_scale.unwrap_or(defaults.twist_scale),
+            color_factor: self.color_factor.unwrap_or(defaults.color_factor),
+        }
+    }
+}
+
+/// Petal and color changes deriving a bud or wilt flower from the bloom parameters
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct StageTransform {
+    /// Petal length and width multiplier
+    petal_scale: f32,
+    /// Petal base width multiplier
+    base_width_scale: f32,
+    /// Petal curl multiplier (applied before `curl_delta`)
+    curl_scale: f32,
+    /// Added petal curl
+    curl_delta: f32,
+    /// Added petal droop
+    droop_delta: f32,
+    /// Petal twist multiplier
+    twist_scale: f32,
+    /// Petal, pistil and stamen color multiplier (< 1.0 darkens)
+    color_factor: f32,
+}
+
+impl StageTransform {
+    /// Default bud transform: half-size, nearly closed petals without twist
+    const BUD: Self = Self {
+        petal_scale: 0.5,
+        base_width_scale: 0.6,
+        curl_scale: 0.2, // Minimal curl (more closed)
+        curl_delta: 0.0,
+        droop_delta: 0.0,
+        twist_scale: 0.0, // No twist in buds
+        color_factor: 1.0,
+    };
+
+    /// Default wilt transform: slightly smaller, drooping, darkened petals
+    const WILT: Self = Self {
+        petal_scale: 0.9, // Slightly smaller
+        base_width_scale: 1.0,
+        curl_scale: 1.0,
+        curl_delta: 0.3,   // More downward curl
+        droop_delta: 0.35, // Petals sag down from their base
+        twist_scale: 1.2,  // Slightly more twisted
+        color_factor: 0.8, // Aging/browning effect
+    };
+
+    /// Apply the transform to petal shape and component colors
+    fn apply(&self, params: &mut FlowerParams) {
+        params.petal.length *= self.petal_scale;
+        params.petal.width *= self.petal_scale;
+        params.petal.base_width *= self.base_width_scale;
+        params.petal.curl = params.petal.curl * self.curl_scale + self.curl_delta;
+        params.petal.droop += self.droop_delta;
+        params.petal.twist *= self.twist_scale;
+
+        params.petal.color *= self.color_factor;
+        params.pistil.color *= self.color_factor;
+        params.stamen.color *= self.color_factor;
     }
 }
 
 /// Create bud-stage flower parameters (closed, small, immature)
 ///
 /// Modifies base parameters to create a flower in bud stage:
-/// - Petals are smaller (50% length/width) and less curled
-/// - Reproductive parts are shorter and smaller
-/// - No ruffle or twist
-fn create_bud_params(base: &FlowerParams) -> FlowerParams {
+/// - Petals are transformed by `transform` (by default 50% length/width, less curled, no twist)
+/// - Reproductive parts are shorter and smaller, with closed anthers
+/// - No ruffle
+fn create_bud_params(base: &FlowerParams, transform: &StageTransform) -> FlowerParams {
     let mut bud = base.clone();
 
     // Smaller, closed petals
-    bud.petal.length *= 0.5;
-    bud.petal.width *= 0.5;
-    bud.petal.base_width *= 0.6;
-    bud.petal.curl *= 0.2; // Minimal curl (more closed)
-    bud.petal.twist = 0.0; // No twist in buds
+    transform.apply(&mut bud);
     bud.petal.ruffle_freq = 0.0; // No ruffle in buds
     bud.petal.ruffle_amp = 0.0;
 
@@ -146,55 +831,39 @@ fn create_bud_params(base: &FlowerParams) -> FlowerParams {
     bud.stamen.anther_length *= 0.7;
     bud.stamen.anther_width *= 0.7;
     bud.stamen.anther_height *= 0.7;
+    bud.stamen.anther_split = 0.0; // Anthers open from bloom onward
 
     bud
 }
 
 /// Create bloom-stage flower parameters (full size, open, mature)
 ///
-/// Returns the base parameters unchanged - this is the reference stage.
+/// Returns the base parameters unchanged - this is the reference stage. Buds
+/// force `stamen.anther_split` closed, so anthers only open from bloom onward.
 fn create_bloom_params(base: &FlowerParams) -> FlowerParams {
     base.clone() // Bloom uses base params unchanged
 }
 
 /// Create wilt-stage flower parameters (drooping, faded, aging)
 ///
-/// Modifies base parameters to create a flower in wilt stage:
-/// - Petals droop more (increased curl)
+/// Modifies base parameters by `transform`; by default:
+/// - Petals droop from their base and curl more
 /// - Slightly smaller and more twisted
 /// - Colors darkened to simulate aging
-fn create_wilt_params(base: &FlowerParams) -> FlowerParams {
-    use floraison_core::Vec3;
-
+fn create_wilt_params(base: &FlowerParams, transform: &StageTransform) -> FlowerParams {
     let mut wilt = base.clone();
-
-    // Drooping petals (more downward curl)
-    wilt.petal.length *= 0.9; // Slightly smaller
-    wilt.petal.curl += 0.3; // More downward curl
-    wilt.petal.twist *= 1.2; // Slightly more twisted
-
-    // Darkened color (aging/browning effect)
-    wilt.petal.color = Vec3::new(
-        wilt.petal.color.x * 0.8,
-        wilt.petal.color.y * 0.8,
-        wilt.petal.color.z * 0.8,
-    );
-
-    // Darken reproductive parts too
-    wilt.pistil.color = Vec3::new(
-        wilt.pistil.color.x * 0.8,
-        wilt.pistil.color.y * 0.8,
-        wilt.pistil.color.z * 0.8,
-    );
-    wilt.stamen.color = Vec3::new(
-        wilt.stamen.color.x * 0.8,
-        wilt.stamen.color.y * 0.8,
-        wilt.stamen.color.z * 0.8,
-    );
-
+    transform.apply(&mut wilt);
     wilt
 }
 
+/// Index range occupied by a single component instance (serialized to JSON)
+#[derive(Serialize)]
+struct ComponentRange {
+    component: ComponentType,
+    start: u32,
+    end: u32,
+}
+
 /// Mesh data structure for passing to JavaScript
 #[wasm_bindgen]
 pub struct MeshData {
@@ -202,7 +871,15 @@ pub struct MeshData {
     normals: Vec<f32>,
     uvs: Vec<f32>,
     colors: Vec<f32>,
+    /// Computed on the first `tangents()` call, since only normal-mapped
+    /// materials need them
+    tangents: OnceCell<Vec<f32>>,
     indices: Vec<u32>,
+    /// For non-indexed data, the source vertex each expanded vertex came from
+    source_indices: Vec<u32>,
+    indexed: bool,
+    component_ranges: String,
+    component_ids: Vec<u8>,
 }
 
 impl MeshData {
@@ -232,9 +909,100 @@ impl MeshData {
             normals,
             uvs,
             colors,
+            tangents: OnceCell::new(),
             indices,
+            source_indices: Vec::new(),
+            indexed: true,
+            component_ranges: String::from("[]"),
+            component_ids: Vec::new(),
+        }
+    }
+
+    /// Convert a Mesh to flat, non-indexed arrays (triangle soup)
+    ///
+    /// Every index is expanded into its own vertex, so the attribute arrays hold
+    /// `indices.len()` vertices and `indices()` is empty. The geometry is
+    /// identical to [`MeshData::from_mesh`]; this is for WebGL setups that
+    /// cannot use index buffers.
+    pub fn from_mesh_nonindexed(mesh: &Mesh) -> Self {
+        let count = mesh.indices.len();
+
+        let mut positions = Vec::with_capacity(count * 3);
+        let mut normals = Vec::with_capacity(count * 3);
+        let mut uvs = Vec::with_capacity(count * 2);
+        let mut colors = Vec::with_capacity(count * 3);
+
+        for &index in &mesh.indices {
+            let i = index as usize;
+            positions.extend_from_slice(&mesh.positions[i].to_array());
+            normals.extend_from_slice(&mesh.normals[i].to_array());
+            uvs.extend_from_slice(&mesh.uvs[i].to_array());
+            colors.extend_from_slice(&mesh.colors[i].to_array());
+        }
+
+        Self {
+            positions,
+            normals,
+            uvs,
+            colors,
+            tangents: OnceCell::new(),
+            indices: Vec::new(),
+            source_indices: mesh.indices.clone(),
+            indexed: false,
+            component_ranges: String::from("[]"),
+            component_ids: Vec::new(),
         }
     }
+
+    /// Flat vertex tangents (stride 4), computed on first use
+    ///
+    /// Non-indexed data gets the tangents of the indexed source mesh, so
+    /// expanded vertices stay smooth across shared triangle corners.
+    fn tangent_values(&self) -> &[f32] {
+        self.tangents.get_or_init(|| {
+            let vertex_count = self.positions.len() / 3;
+            let (indices, source) = if self.indexed {
+                (&self.indices, (0..vertex_count).collect::<Vec<_>>())
+            } else {
+                let source = self.source_indices.iter().map(|&i| i as usize).collect();
+                (&self.source_indices, source)
+            };
+
+            // Rebuild the attributes tangents depend on in source vertex order
+            let source_count = source.iter().max().map_or(0, |&max| max + 1);
+            let mut mesh = Mesh::new();
+            mesh.positions = vec![floraison_core::Vec3::ZERO; source_count];
+            mesh.normals = vec![floraison_core::Vec3::ZERO; source_count];
+            mesh.uvs = vec![floraison_core::Vec2::ZERO; source_count];
+            for (k, &s) in source.iter().enumerate() {
+                mesh.positions[s] = floraison_core::Vec3::from_slice(&self.positions[k * 3..]);
+                mesh.normals[s] = floraison_core::Vec3::from_slice(&self.normals[k * 3..]);
+                mesh.uvs[s] = floraison_core::Vec2::from_slice(&self.uvs[k * 2..]);
+            }
+            mesh.indices = indices.clone();
+
+            let tangents = mesh.compute_tangents();
+            source
+                .iter()
+                .flat_map(|&s| tangents[s].to_array())
+                .collect()
+        })
+    }
+
+    /// Axis-aligned bounds of the stored positions as (min, max), if any
+    fn bounds(&self) -> Option<([f32; 3], [f32; 3])> {
+        let mut points = self.positions.chunks_exact(3);
+        let first = points.next()?;
+        let mut min = [first[0], first[1], first[2]];
+        let mut max = min;
+        for p in points {
+            for axis in 0..3 {
+                min[axis] = min[axis].min(p[axis]);
+                max[axis] = max[axis].max(p[axis]);
+            }
+        }
+        Some((min, max))
+    }
 }
 
 #[wasm_bindgen]
@@ -259,8 +1027,443 @@ impl MeshData {
         js_sys::Float32Array::from(&self.colors[..])
     }
 
+    /// Get vertex tangents as Float32Array (stride 4, w = handedness)
+    ///
+    /// Tangents are computed on the first call and cached.
+    pub fn tangents(&self) -> js_sys::Float32Array {
+        js_sys::Float32Array::from(self.tangent_values())
+    }
+
     /// Get triangle indices as Uint32Array
     pub fn indices(&self) -> js_sys::Uint32Array {
         js_sys::Uint32Array::from(&self.indices[..])
     }
+
+    /// Whether `indices()` should be used for drawing
+    ///
+    /// `false` for data from `generate_flower_nonindexed` and
+    /// `generate_inflorescence_nonindexed`, in which case
+    /// every three consecutive vertices form one triangle.
+    pub fn is_indexed(&self) -> bool {
+        self.indexed
+    }
+
+    /// Get per-component index ranges as a JSON string
+    ///
+    /// Empty array (`[]`) unless the mesh came from `generate_flower_with_ranges`.
+    pub fn component_ranges(&self) -> String {
+        self.component_ranges.clone()
+    }
+
+    /// Get per-vertex `ComponentType` ids as Uint8Array
+    ///
+    /// Empty unless the mesh came from `generate_flower_tagged`.
+    pub fn component_ids(&self) -> js_sys::Uint8Array {
+        js_sys::Uint8Array::from(&self.component_ids[..])
+    }
+
+    /// Number of vertices in the attribute arrays
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len() / 3
+    }
+
+    /// Number of triangles, for indexed and non-indexed data alike
+    pub fn triangle_count(&self) -> usize {
+        if self.indexed {
+            self.indices.len() / 3
+        } else {
+            self.vertex_count() / 3
+        }
+    }
+
+    /// Minimum corner of the bounding box as `[x, y, z]` (zeros if empty)
+    pub fn bounds_min(&self) -> js_sys::Float32Array {
+        let (min, _) = self.bounds().unwrap_or_default();
+        js_sys::Float32Array::from(&min[..])
+    }
+
+    /// Maximum corner of the bounding box as `[x, y, z]` (zeros if empty)
+    pub fn bounds_max(&self) -> js_sys::Float32Array {
+        let (_, max) = self.bounds().unwrap_or_default();
+        js_sys::Float32Array::from(&max[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_formats() {
+        let params_json = serde_json::to_string(&FlowerParams::lily()).unwrap();
+
+        let obj = FlowerGenerator::new().export_obj(&params_json).unwrap();
+        assert!(obj.starts_with("v "));
+
+        // export_glb wraps these bytes in a Uint8Array
+        let glb = mesh_to_glb(&flower_mesh_from_json(&params_json).unwrap());
+        assert_eq!(&glb[0..4], b"glTF");
+    }
+
+    #[test]
+    fn test_custom_bud_petal_scale() {
+        let lily = FlowerParams::lily();
+        let size = |params: &FlowerParams| {
+            let (min, max) = generate_flower(params).bounding_box().unwrap();
+            (max - min).length()
+        };
+
+        let defaults: InflorescenceOptions =
+            serde_json::from_str(r#"{"branch_count": 5}"#).unwrap();
+        assert_eq!(defaults.aging.bud(), StageTransform::BUD);
+        assert_eq!(defaults.aging.wilt(), StageTransform::WILT);
+
+        let custom: InflorescenceOptions =
+            serde_json::from_str(r#"{"aging": {"bud": {"petal_scale": 0.1}}}"#).unwrap();
+        let custom_bud = custom.aging.bud();
+        assert_eq!(custom_bud.petal_scale, 0.1);
+        assert_eq!(custom_bud.curl_scale, StageTransform::BUD.curl_scale);
+
+        let default_size = size(&create_bud_params(&lily, &defaults.aging.bud()));
+        let custom_size = size(&create_bud_params(&lily, &custom_bud));
+        assert!(custom_size < default_size * 0.8);
+
+        // The extra key doesn't disturb InflorescenceParams parsing
+        let mut json = serde_json::to_value(InflorescenceParams::default()).unwrap();
+        json["aging"] = serde_json::json!({"bud": {"petal_scale": 0.1}});
+        assert!(serde_json::from_value::<InflorescenceParams>(json).is_ok());
+    }
+
+    #[test]
+    fn test_stem_color_option() {
+        let defaults: InflorescenceOptions = serde_json::from_str("{}").unwrap();
+        assert_eq!(defaults.stem_color, DEFAULT_STEM_COLOR);
+
+        let options: InflorescenceOptions =
+            serde_json::from_str(r#"{"stem_color": [1.0, 0.0, 0.0]}"#).unwrap();
+        let aging = &options.aging;
+        let stages = FlowerStages::new(&FlowerParams::lily(), &aging.bud(), &aging.wilt());
+        let mesh = inflorescence_mesh(
+            &InflorescenceParams::default(),
+            &stages,
+            floraison_core::Vec3::from_array(options.stem_color),
+        );
+
+        let red = floraison_core::Vec3::X;
+        let green = floraison_core::Vec3::from_array(DEFAULT_STEM_COLOR);
+        assert!(mesh.colors.contains(&red));
+        assert!(!mesh.colors.contains(&green));
+    }
+
+    #[test]
+    fn test_jittered_stages_match_select_mesh() {
+        let mut params = FlowerParams::lily();
+        params.diagram.position_jitter = 0.1;
+        let aging = AgingOverrides::default();
+        let mut stages = FlowerStages::new(&params, &aging.bud(), &aging.wilt());
+        stages.aging = stages.aging.clone().with_fruit(Mesh::new(), 0.95);
+
+        for step in 0..=20 {
+            let age = step as f32 / 20.0;
+            let expected = stages.aging.select_mesh(age);
+            match stages.params_for(age) {
+                // Seed offset 0 reproduces the cached stage mesh
+                Some(stage_params) => {
+                    assert_eq!(generate_flower(stage_params).positions, expected.positions)
+                }
+                None => assert_eq!(stages.aging.stage(age), FlowerStage::Fruit),
+            }
+            assert_eq!(
+                stages.jittered_flower(0, age).positions,
+                expected.positions,
+                "age {}",
+                age
+            );
+        }
+    }
+
+    #[test]
+    fn test_jittered_flowers_cached() {
+        let mut params = FlowerParams::lily();
+        params.diagram.angle_jitter = 10.0;
+        let aging = AgingOverrides::default();
+        let stages = FlowerStages::new(&params, &aging.bud(), &aging.wilt());
+        let inflo = InflorescenceParams::default();
+        let stem_color = floraison_core::Vec3::from_array(DEFAULT_STEM_COLOR);
+
+        let first = inflorescence_mesh(&inflo, &stages, stem_color);
+        let cached = stages.jittered.borrow().len();
+        assert_eq!(cached, inflo.branch_count);
+
+        let second = inflorescence_mesh(&inflo, &stages, stem_color);
+        assert_eq!(stages.jittered.borrow().len(), cached);
+        assert_eq!(first.positions, second.positions);
+    }
+
+    #[test]
+    fn test_seed_reproducible() {
+        let mut params = FlowerParams::lily();
+        params.diagram.position_jitter = 0.1;
+        params.diagram.angle_jitter = 10.0;
+        params.diagram.size_jitter = 0.1;
+        let seeded_json = |seed: u32| {
+            let mut json = serde_json::to_value(&params).unwrap();
+            json["seed"] = seed.into();
+            json.to_string()
+        };
+
+        let first = flower_mesh_from_json(&seeded_json(7)).unwrap();
+        let again = flower_mesh_from_json(&seeded_json(7)).unwrap();
+        let other = flower_mesh_from_json(&seeded_json(8)).unwrap();
+        assert_eq!(first.positions, again.positions);
+        assert_ne!(first.positions, other.positions);
+
+        // Same as setting the diagram seed directly
+        params.diagram.jitter_seed = 7;
+        assert_eq!(first.positions, generate_flower(&params).positions);
+    }
+
+    #[test]
+    fn test_generate_many() {
+        let params = [
+            FlowerParams::lily(),
+            FlowerParams::tulip(),
+            FlowerParams::daisy(),
+        ];
+        let json = serde_json::to_string(&params).unwrap();
+
+        let meshes = flower_meshes_from_json_array(&json).unwrap();
+        assert_eq!(meshes.len(), 3);
+        for (mesh, params) in meshes.iter().zip(&params) {
+            assert_eq!(mesh.positions, generate_flower(params).positions);
+        }
+
+        // A bad element reports its index
+        let lily = serde_json::to_value(FlowerParams::lily()).unwrap();
+        let json = serde_json::json!([lily, {"petal": 1}]).to_string();
+        let err = flower_meshes_from_json_array(&json).unwrap_err();
+        assert!(err.contains("index 1"), "{}", err);
+
+        assert!(flower_meshes_from_json_array("{}").is_err());
+    }
+
+    #[test]
+    fn test_validate_flower_params() {
+        for params in [
+            FlowerParams::lily(),
+            FlowerParams::tulip(),
+            FlowerParams::daisy(),
+        ] {
+            assert!(flower_param_violations(&params).is_empty());
+        }
+
+        let mut params = FlowerParams::lily();
+        params.petal.length = -1.0;
+        params.petal.resolution = 0;
+        params.pistil.length = 0.0;
+        params.stamen.segments = 1;
+        let violations = flower_param_violations(&params);
+        assert_eq!(violations.len(), 4, "{:?}", violations);
+        assert!(violations[0].starts_with("pistil.length"));
+        assert!(violations
+            .iter()
+            .any(|v| v.contains("petal.resolution must be >= 2")));
+
+        params.petal.width = f32::NAN;
+        assert_eq!(flower_param_violations(&params).len(), 5);
+
+        params.petal.tip_lobe_depth = 1.5;
+        assert!(flower_param_violations(&params)
+            .iter()
+            .any(|v| v == "petal.tip_lobe_depth must be in [0, 1] (got 1.5)"));
+    }
+
+    #[test]
+    fn test_validate_override_lengths() {
+        let mut params = FlowerParams::lily();
+        let whorl = &mut params.diagram.petal_whorls[0];
+        whorl.angle_overrides = Some(vec![0.0; whorl.count]);
+        assert!(flower_param_violations(&params).is_empty());
+
+        params.diagram.petal_whorls[0].scale_overrides = Some(vec![1.0, 2.0]);
+        let count = params.diagram.petal_whorls[0].count;
+        assert_eq!(
+            flower_param_violations(&params),
+            [format!(
+                "diagram.petal_whorls[0].scale_overrides must have {} entries, one per component (got 2)",
+                count
+            )]
+        );
+
+        // Still generates instead of trapping
+        assert!(generate_flower(&params).vertex_count() > 0);
+    }
+
+    #[test]
+    fn test_validate_inflorescence_params() {
+        assert!(inflorescence_param_violations(&InflorescenceParams::default()).is_empty());
+
+        let params = InflorescenceParams {
+            branch_count: 0,
+            axis_length: -2.0,
+            age_distribution: 1.5,
+            ..Default::default()
+        };
+        let violations = inflorescence_param_violations(&params);
+        assert_eq!(
+            violations,
+            [
+                "axis_length must be > 0 (got -2)",
+                "branch_count must be >= 1 (got 0)",
+                "age_distribution must be in [0, 1] (got 1.5)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inflorescence_reuses_stage_flowers() {
+        let mut generator = FlowerGenerator::new();
+        let flower_json = serde_json::to_string(&FlowerParams::lily()).unwrap();
+        let inflo_json = |branch_count: usize, aging: serde_json::Value| {
+            let mut json = serde_json::to_value(InflorescenceParams {
+                branch_count,
+                ..Default::default()
+            })
+            .unwrap();
+            json["aging"] = aging;
+            json.to_string()
+        };
+        let default_aging = serde_json::json!({});
+
+        let first = generator
+            .generate_inflorescence(&inflo_json(5, default_aging.clone()), &flower_json)
+            .unwrap();
+        assert_eq!(generator.stage_generations, 1);
+
+        // Layout-only change: stage flowers are reused
+        let second = generator
+            .generate_inflorescence(&inflo_json(8, default_aging.clone()), &flower_json)
+            .unwrap();
+        assert_eq!(generator.stage_generations, 1);
+        assert!(second.vertex_count() > first.vertex_count());
+
+        // Changed stage transforms or flower JSON regenerate them
+        let custom_aging = serde_json::json!({"bud": {"petal_scale": 0.1}});
+        generator
+            .generate_inflorescence(&inflo_json(8, custom_aging), &flower_json)
+            .unwrap();
+        assert_eq!(generator.stage_generations, 2);
+
+        let tulip_json = serde_json::to_string(&FlowerParams::tulip()).unwrap();
+        generator
+            .generate_inflorescence(&inflo_json(8, default_aging), &tulip_json)
+            .unwrap();
+        assert_eq!(generator.stage_generations, 3);
+    }
+
+    #[test]
+    fn test_component_ids() {
+        let data = tagged_flower_data(&FlowerParams::lily());
+        assert_eq!(data.component_ids.len(), data.positions.len() / 3);
+
+        let mut distinct = data.component_ids.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        assert_eq!(
+            distinct,
+            [
+                ComponentType::Receptacle as u8,
+                ComponentType::Pistil as u8,
+                ComponentType::Stamen as u8,
+                ComponentType::Petal as u8,
+            ]
+        );
+
+        // Untagged mesh data has no ids
+        assert!(MeshData::from_mesh(&generate_flower(&FlowerParams::lily()))
+            .component_ids
+            .is_empty());
+    }
+
+    #[test]
+    fn test_mesh_stats() {
+        let mesh = generate_flower(&FlowerParams::lily());
+        let indexed = MeshData::from_mesh(&mesh);
+        let flat = MeshData::from_mesh_nonindexed(&mesh);
+
+        assert_eq!(indexed.vertex_count(), mesh.vertex_count());
+        assert_eq!(indexed.triangle_count(), indexed.indices.len() / 3);
+        assert_eq!(flat.triangle_count(), indexed.triangle_count());
+
+        let (min, max) = mesh.bounding_box().unwrap();
+        assert_eq!(indexed.bounds(), Some((min.to_array(), max.to_array())));
+        assert_eq!(MeshData::from_mesh(&Mesh::new()).bounds(), None);
+    }
+
+    #[test]
+    fn test_nonindexed_exports() {
+        let mut generator = FlowerGenerator::new();
+        let flower_json = serde_json::to_string(&FlowerParams::lily()).unwrap();
+        let inflo_json = serde_json::to_string(&InflorescenceParams::default()).unwrap();
+
+        let indexed = generator.generate_flower(&flower_json).unwrap();
+        let flat = generator.generate_flower_nonindexed(&flower_json).unwrap();
+        assert!(indexed.is_indexed() && !flat.is_indexed());
+        assert_eq!(flat.vertex_count(), indexed.indices.len());
+        assert_eq!(flat.triangle_count(), indexed.triangle_count());
+
+        let indexed = generator
+            .generate_inflorescence(&inflo_json, &flower_json)
+            .unwrap();
+        let flat = generator
+            .generate_inflorescence_nonindexed(&inflo_json, &flower_json)
+            .unwrap();
+        assert!(!flat.is_indexed());
+        assert!(flat.indices.is_empty());
+        assert_eq!(flat.vertex_count(), indexed.indices.len());
+        assert_eq!(flat.triangle_count(), indexed.triangle_count());
+    }
+
+    #[test]
+    fn test_tangents_computed_on_demand() {
+        let mesh = generate_flower(&FlowerParams::lily());
+        let expected = mesh.compute_tangents();
+
+        let indexed = MeshData::from_mesh(&mesh);
+        assert!(indexed.tangents.get().is_none());
+        let flat_expected: Vec<f32> = expected.iter().flat_map(|t| t.to_array()).collect();
+        assert_eq!(indexed.tangent_values(), flat_expected);
+
+        // Expanded vertices carry the smooth tangents of their source vertex
+        let flat = MeshData::from_mesh_nonindexed(&mesh);
+        assert!(flat.tangents.get().is_none());
+        let expanded: Vec<f32> = mesh
+            .indices
+            .iter()
+            .flat_map(|&i| expected[i as usize].to_array())
+            .collect();
+        assert_eq!(flat.tangent_values(), expanded);
+    }
+
+    #[test]
+    fn test_nonindexed_expands_indices() {
+        let mesh = generate_flower(&FlowerParams::lily());
+        let indexed = MeshData::from_mesh(&mesh);
+        let flat = MeshData::from_mesh_nonindexed(&mesh);
+
+        assert!(indexed.indexed);
+        assert!(!flat.indexed);
+        assert!(flat.indices.is_empty());
+
+        let count = mesh.indices.len();
+        assert_eq!(flat.positions.len(), count * 3);
+        assert_eq!(flat.normals.len(), count * 3);
+        assert_eq!(flat.uvs.len(), count * 2);
+        assert_eq!(flat.colors.len(), count * 3);
+        assert_eq!(flat.tangent_values().len(), count * 4);
+
+        // Third triangle corner matches the indexed vertex it came from
+        let i = mesh.indices[2] as usize;
+        assert_eq!(flat.positions[6..9], indexed.positions[i * 3..i * 3 + 3]);
+    }
 }